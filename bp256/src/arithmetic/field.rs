@@ -95,6 +95,14 @@ impl FieldElement {
         self.to_canonical().to_be_byte_array()
     }
 
+    /// Returns the canonical [`U256`] representation of this [`FieldElement`].
+    ///
+    /// This is the inverse of [`FieldElement::from_uint`], and is the same as
+    /// [`FieldElement::to_canonical`].
+    pub const fn to_uint(self) -> U256 {
+        self.to_canonical()
+    }
+
     /// Translate [`FieldElement`] out of the Montgomery domain, returning a
     /// [`U256`] in canonical form.
     #[inline]
@@ -316,4 +324,34 @@ mod tests {
     impl_field_invert_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
     impl_primefield_tests!(FieldElement, T);
+
+    #[test]
+    fn uint_round_trip() {
+        use crate::U256;
+
+        let modulus =
+            U256::from_be_hex("a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377");
+        assert_eq!(modulus, super::MODULUS);
+
+        for uint in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_u64(0x1234_5678_9abc_def0),
+            modulus.wrapping_sub(&U256::ONE),
+        ] {
+            let element = FieldElement::from_uint(uint).unwrap();
+            assert_eq!(element.to_uint(), uint);
+        }
+
+        assert_eq!(
+            FieldElement::from_uint(U256::ONE).unwrap(),
+            FieldElement::ONE
+        );
+        assert_eq!(FieldElement::ONE.to_uint(), U256::ONE);
+        assert_eq!(FieldElement::ZERO.to_uint(), U256::ZERO);
+
+        // Values which are not reduced are rejected
+        assert!(bool::from(FieldElement::from_uint(modulus).is_none()));
+        assert!(bool::from(FieldElement::from_uint(U256::MAX).is_none()));
+    }
 }
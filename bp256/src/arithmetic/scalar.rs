@@ -99,6 +99,14 @@ impl Scalar {
         self.0.to_be_byte_array()
     }
 
+    /// Returns the canonical [`U256`] representation of this [`Scalar`].
+    ///
+    /// This is the inverse of [`Scalar::from_uint`], and is the same as
+    /// [`Scalar::to_canonical`].
+    pub const fn to_uint(self) -> U256 {
+        self.to_canonical()
+    }
+
     /// Translate [`Scalar`] out of the Montgomery domain, returning a
     /// [`U256`] in canonical form.
     #[inline]
@@ -419,4 +427,31 @@ mod tests {
     impl_field_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
+
+    #[test]
+    fn uint_round_trip() {
+        use crate::U256;
+
+        let modulus =
+            U256::from_be_hex("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7");
+        assert_eq!(modulus, crate::ORDER);
+
+        for uint in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_u64(0x1234_5678_9abc_def0),
+            modulus.wrapping_sub(&U256::ONE),
+        ] {
+            let element = Scalar::from_uint(uint).unwrap();
+            assert_eq!(element.to_uint(), uint);
+        }
+
+        assert_eq!(Scalar::from_uint(U256::ONE).unwrap(), Scalar::ONE);
+        assert_eq!(Scalar::ONE.to_uint(), U256::ONE);
+        assert_eq!(Scalar::ZERO.to_uint(), U256::ZERO);
+
+        // Values which are not reduced are rejected
+        assert!(bool::from(Scalar::from_uint(modulus).is_none()));
+        assert!(bool::from(Scalar::from_uint(U256::MAX).is_none()));
+    }
 }
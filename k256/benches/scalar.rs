@@ -5,7 +5,11 @@ use criterion::{
 };
 use hex_literal::hex;
 use k256::{
-    elliptic_curve::{group::ff::PrimeField, ops::LinearCombination, ops::MulByGenerator},
+    elliptic_curve::{
+        group::ff::{Field, PrimeField},
+        ops::LinearCombination,
+        ops::MulByGenerator,
+    },
     ProjectivePoint, Scalar,
 };
 
@@ -103,6 +107,17 @@ fn bench_scalar_negate<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
 fn bench_scalar_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     group.bench_function("invert", |b| b.iter(|| black_box(x).invert()));
+
+    // Fermat inversion: x^(n - 2)
+    let n_minus_2 = [
+        0xbfd2_5e8c_d036_413f,
+        0xbaae_dce6_af48_a03b,
+        0xffff_ffff_ffff_fffe,
+        0xffff_ffff_ffff_ffff,
+    ];
+    group.bench_function("invert (Fermat)", |b| {
+        b.iter(|| black_box(x).pow_vartime(black_box(n_minus_2)))
+    });
 }
 
 fn bench_scalar(c: &mut Criterion) {
@@ -13,9 +13,12 @@ mod dev;
 
 pub use field::FieldElement;
 
+#[cfg(feature = "expose-field")]
+pub use field::NormalizedFieldElement;
+
 use self::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
 use crate::Secp256k1;
-use elliptic_curve::CurveArithmetic;
+use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 
 impl CurveArithmetic for Secp256k1 {
     type AffinePoint = AffinePoint;
@@ -23,6 +26,10 @@ impl CurveArithmetic for Secp256k1 {
     type Scalar = Scalar;
 }
 
+impl PrimeCurveArithmetic for Secp256k1 {
+    type CurveGroup = ProjectivePoint;
+}
+
 const CURVE_EQUATION_B_SINGLE: u32 = 7u32;
 
 #[rustfmt::skip]
@@ -55,4 +62,29 @@ mod tests {
         // Sanity check
         assert!(!key.to_bytes().iter().all(|b| *b == 0))
     }
+
+    /// Exercise the group through generic `PrimeCurveArithmetic` bounds only.
+    fn prime_curve_arithmetic<C: elliptic_curve::PrimeCurveArithmetic>() {
+        use elliptic_curve::{
+            group::{
+                prime::{PrimeCurve, PrimeCurveAffine},
+                Curve, Group,
+            },
+            Field,
+        };
+
+        let g = C::CurveGroup::generator();
+        assert_eq!(
+            g.to_affine(),
+            <C::CurveGroup as PrimeCurve>::Affine::generator()
+        );
+        assert_eq!(g * -C::Scalar::ONE + g, C::CurveGroup::identity());
+        assert_eq!((g + g).to_affine(), g.double().to_affine());
+        assert!(bool::from((g * C::Scalar::ZERO).is_identity()));
+    }
+
+    #[test]
+    fn secp256k1_prime_curve_arithmetic() {
+        prime_curve_arithmetic::<crate::Secp256k1>();
+    }
 }
@@ -3,10 +3,13 @@
 #![allow(clippy::op_ref)]
 
 use super::{FieldElement, ProjectivePoint, CURVE_EQUATION_B};
-use crate::{CompressedPoint, EncodedPoint, FieldBytes, PublicKey, Scalar, Secp256k1};
+use crate::{
+    CompressedPoint, EncodedPoint, FieldBytes, NonZeroScalar, PublicKey, Scalar, Secp256k1,
+};
 use core::ops::{Mul, Neg};
 use elliptic_curve::{
     group::{prime::PrimeCurveAffine, GroupEncoding},
+    ops::MulByGenerator,
     point::{AffineCoordinates, DecompactPoint, DecompressPoint},
     sec1::{self, FromEncodedPoint, ToEncodedPoint},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
@@ -80,6 +83,118 @@ impl AffinePoint {
     pub(crate) const fn new(x: FieldElement, y: FieldElement) -> Self {
         Self { x, y, infinity: 0 }
     }
+
+    /// Returns the additive identity of the group: the point at infinity.
+    ///
+    /// This is the same as [`AffinePoint::IDENTITY`], and is provided so callers
+    /// don't need to import [`PrimeCurveAffine`].
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    /// Is this point the identity point (i.e. the point at infinity)?
+    ///
+    /// The identity has no affine coordinates, so this is tracked by a separate
+    /// flag rather than by the value of `x` or `y`.
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
+    }
+
+    /// Compute the public key point `d * G` for the secret scalar `d`.
+    ///
+    /// This multiplies with [`MulByGenerator::mul_by_generator`], which uses the
+    /// fixed-base comb table when `precomputed-tables` is enabled, followed by a
+    /// single conversion to affine coordinates.
+    pub fn from_secret_scalar(d: &NonZeroScalar) -> Self {
+        ProjectivePoint::mul_by_generator(d).to_affine()
+    }
+
+    /// Returns the SEC1 tag byte of this point's compressed encoding: `0x02` if
+    /// `y` is even, `0x03` if `y` is odd, or `0x00` for the identity.
+    ///
+    /// Computed in constant time.
+    pub fn sec1_tag(&self) -> u8 {
+        let tag = 0x02 | self.y.normalize().is_odd().unwrap_u8();
+        u8::conditional_select(&tag, &0x00, self.is_identity())
+    }
+
+    /// Compress this point into its `x`-coordinate and the parity of its
+    /// `y`-coordinate, which [`DecompressPoint::decompress`] takes back.
+    ///
+    /// This is the compressed SEC1 encoding without the tag byte, with the
+    /// parity as a [`Choice`] rather than folded into `0x02`/`0x03`. The
+    /// identity compresses to an all-zero `x` with even parity, which does not
+    /// decompress, since no point on secp256k1 has `x = 0`.
+    pub fn compress(&self) -> (FieldBytes, Choice) {
+        (self.x.to_bytes(), self.y.normalize().is_odd())
+    }
+
+    /// Serialize this point as the 64-byte concatenation of its big endian
+    /// `x` and `y` coordinates, i.e. an uncompressed SEC1 point without the
+    /// leading `0x04` tag.
+    ///
+    /// This is the encoding which is hashed to derive Ethereum addresses.
+    /// The identity point serializes as all zeroes.
+    pub fn to_untagged_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.x.to_bytes());
+        bytes[32..].copy_from_slice(&self.y.to_bytes());
+        bytes
+    }
+
+    /// Parse a point from the 64-byte concatenation of its big endian `x` and
+    /// `y` coordinates (see [`AffinePoint::to_untagged_bytes`]).
+    ///
+    /// Returns `None` if the coordinates are not valid field elements or the
+    /// point is not on the curve. The identity point cannot be represented.
+    pub fn from_untagged_bytes(bytes: &[u8; 64]) -> CtOption<Self> {
+        let mut x = FieldBytes::default();
+        let mut y = FieldBytes::default();
+        x.copy_from_slice(&bytes[..32]);
+        y.copy_from_slice(&bytes[32..]);
+        Self::from_encoded_point(&EncodedPoint::from_affine_coordinates(&x, &y, false))
+    }
+
+    /// Serialize this point in the SEC1 "hybrid" form: the uncompressed
+    /// encoding, but with a tag of `0x06` if `y` is even or `0x07` if `y` is
+    /// odd.
+    ///
+    /// This form is obsolete and is only provided for interoperability with
+    /// legacy systems. The identity point serializes as all zeroes.
+    pub fn to_hybrid_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = 0x06 | self.y.normalize().is_odd().unwrap_u8();
+        bytes[1..].copy_from_slice(&self.to_untagged_bytes());
+        bytes[0].conditional_assign(&0x00, self.is_identity());
+        bytes
+    }
+
+    /// Parse a point in the SEC1 "hybrid" form (see
+    /// [`AffinePoint::to_hybrid_bytes`]).
+    ///
+    /// Returns `None` if the tag is not `0x06` or `0x07`, if the parity it
+    /// encodes does not match that of `y`, or if the point is not on the
+    /// curve. The identity point cannot be represented.
+    pub fn from_hybrid_bytes(bytes: &[u8; 65]) -> CtOption<Self> {
+        let mut untagged = [0u8; 64];
+        untagged.copy_from_slice(&bytes[1..]);
+
+        Self::from_untagged_bytes(&untagged).and_then(|point| {
+            let expected_tag = 0x06 | point.y.normalize().is_odd().unwrap_u8();
+            CtOption::new(point, bytes[0].ct_eq(&expected_tag))
+        })
+    }
+
+    /// Decompress both points with the given `x`-coordinate.
+    ///
+    /// Returns the point with an even `y`-coordinate followed by the point
+    /// with an odd `y`-coordinate (which is its negation), or `None` if `x` is
+    /// not the `x`-coordinate of a point on the curve.
+    pub fn decompress_both(x_bytes: &FieldBytes) -> Option<(Self, Self)> {
+        let even = Option::<Self>::from(Self::decompress(x_bytes, Choice::from(0)))?;
+        let odd = Self::new(even.x, even.y.negate(1).normalize());
+        Some((even, odd))
+    }
 }
 
 impl PrimeCurveAffine for AffinePoint {
@@ -88,7 +203,7 @@ impl PrimeCurveAffine for AffinePoint {
 
     /// Returns the identity of the group: the point at infinity.
     fn identity() -> Self {
-        Self::IDENTITY
+        Self::identity()
     }
 
     /// Returns the base point of secp256k1.
@@ -98,7 +213,7 @@ impl PrimeCurveAffine for AffinePoint {
 
     /// Is this point the identity point?
     fn is_identity(&self) -> Choice {
-        Choice::from(self.infinity)
+        self.is_identity()
     }
 
     /// Convert to curve representation.
@@ -173,22 +288,29 @@ impl Neg for AffinePoint {
     type Output = AffinePoint;
 
     fn neg(self) -> Self::Output {
-        AffinePoint {
+        let negated = AffinePoint {
             x: self.x,
             y: self.y.negate(1).normalize_weak(),
             infinity: self.infinity,
-        }
+        };
+
+        // Negating `y = 0` may leave a non-canonical zero behind: select the
+        // canonical identity instead, without branching on the infinity flag.
+        AffinePoint::conditional_select(&negated, &Self::IDENTITY, Choice::from(self.infinity))
     }
 }
 
 impl DecompressPoint<Secp256k1> for AffinePoint {
     fn decompress(x_bytes: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
         FieldElement::from_bytes(x_bytes).and_then(|x| {
-            let alpha = (x * &x * &x) + &CURVE_EQUATION_B;
+            let alpha = x.cube() + &CURVE_EQUATION_B;
             let beta = alpha.sqrt();
 
             beta.map(|beta| {
                 let beta = beta.normalize(); // Need to normalize for is_odd() to be consistent
+
+                // Select the root with the requested parity without branching, so
+                // decompression is constant-time with respect to `y_is_odd`.
                 let y = FieldElement::conditional_select(
                     &beta.negate(1),
                     &beta,
@@ -259,7 +381,7 @@ impl FromEncodedPoint<Secp256k1> for AffinePoint {
                     y.and_then(|y| {
                         // Check that the point is on the curve
                         let lhs = (y * &y).negate(1);
-                        let rhs = x * &x * &x + &CURVE_EQUATION_B;
+                        let rhs = x.cube() + &CURVE_EQUATION_B;
                         let point = Self::new(x, y);
                         CtOption::new(point, (lhs + &rhs).normalizes_to_zero())
                     })
@@ -361,13 +483,31 @@ impl<'de> Deserialize<'de> for AffinePoint {
     }
 }
 
+/// Produces a point by multiplying the generator by an arbitrary [`Scalar`], so every input
+/// yields a point on the curve (including the identity, for a zero scalar).
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AffinePoint {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(
+            (ProjectivePoint::GENERATOR * <Scalar as arbitrary::Arbitrary>::arbitrary(u)?)
+                .to_affine(),
+        )
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Scalar as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::AffinePoint;
-    use crate::EncodedPoint;
+    use super::{AffinePoint, FieldElement};
+    use crate::{EncodedPoint, FieldBytes, ProjectivePoint, Scalar};
     use elliptic_curve::{
         group::{prime::PrimeCurveAffine, GroupEncoding},
+        point::AffineCoordinates,
         sec1::{FromEncodedPoint, ToEncodedPoint},
+        subtle::{Choice, ConditionallySelectable},
     };
     use hex_literal::hex;
 
@@ -378,6 +518,57 @@ mod tests {
     const COMPRESSED_BASEPOINT: &[u8] =
         &hex!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
 
+    #[test]
+    fn hybrid_round_trip() {
+        let generator = AffinePoint::GENERATOR;
+        let mut hybrid = [0u8; 65];
+        hybrid[0] = 0x06;
+        hybrid[1..].copy_from_slice(&UNCOMPRESSED_BASEPOINT[1..]);
+
+        assert_eq!(generator.to_hybrid_bytes(), hybrid);
+        assert_eq!(AffinePoint::from_hybrid_bytes(&hybrid).unwrap(), generator);
+
+        let neg_generator = -generator;
+        let neg_hybrid = neg_generator.to_hybrid_bytes();
+        assert_eq!(neg_hybrid[0], 0x07);
+        assert_eq!(
+            AffinePoint::from_hybrid_bytes(&neg_hybrid).unwrap(),
+            neg_generator
+        );
+
+        assert_eq!(AffinePoint::IDENTITY.to_hybrid_bytes(), [0u8; 65]);
+    }
+
+    #[test]
+    fn hybrid_rejects_invalid() {
+        let mut hybrid = AffinePoint::GENERATOR.to_hybrid_bytes();
+
+        // Mismatched parity
+        hybrid[0] = 0x07;
+        assert!(bool::from(
+            AffinePoint::from_hybrid_bytes(&hybrid).is_none()
+        ));
+
+        // Non-hybrid tags
+        for tag in [0x00, 0x02, 0x03, 0x04, 0x05] {
+            hybrid[0] = tag;
+            assert!(bool::from(
+                AffinePoint::from_hybrid_bytes(&hybrid).is_none()
+            ));
+        }
+
+        // Not on the curve
+        hybrid[0] = 0x06;
+        hybrid[64] ^= 0x02;
+        assert!(bool::from(
+            AffinePoint::from_hybrid_bytes(&hybrid).is_none()
+        ));
+
+        assert!(bool::from(
+            AffinePoint::from_hybrid_bytes(&[0u8; 65]).is_none()
+        ));
+    }
+
     #[test]
     fn uncompressed_round_trip() {
         let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
@@ -424,6 +615,244 @@ mod tests {
     fn affine_negation() {
         let basepoint = AffinePoint::GENERATOR;
         assert_eq!((-(-basepoint)), basepoint);
+
+        // Negation keeps x and negates y
+        let neg = -basepoint;
+        assert_ne!(neg, basepoint);
+        assert_eq!(neg.x.normalize(), basepoint.x.normalize());
+        assert_eq!((neg.y + &basepoint.y).normalize(), FieldElement::ZERO);
+        assert_eq!(
+            ProjectivePoint::from(neg) + basepoint,
+            ProjectivePoint::IDENTITY
+        );
+
+        // The identity is its own negation, with the same representation
+        let neg_identity = -AffinePoint::IDENTITY;
+        assert!(bool::from(neg_identity.is_identity()));
+        assert_eq!(neg_identity, AffinePoint::IDENTITY);
+        assert_eq!(neg_identity.y.normalize(), FieldElement::ZERO);
+        assert_eq!(
+            neg_identity.to_encoded_point(true),
+            AffinePoint::IDENTITY.to_encoded_point(true)
+        );
+    }
+
+    #[test]
+    fn identity() {
+        assert!(bool::from(AffinePoint::identity().is_identity()));
+        assert!(!bool::from(AffinePoint::GENERATOR.is_identity()));
+        assert_eq!(AffinePoint::identity(), AffinePoint::IDENTITY);
+        assert!(bool::from(
+            (ProjectivePoint::GENERATOR * Scalar::ZERO)
+                .to_affine()
+                .is_identity()
+        ));
+    }
+
+    #[test]
+    fn conditional_select() {
+        let a = AffinePoint::GENERATOR;
+        let b = (ProjectivePoint::GENERATOR * Scalar::from(3u32)).to_affine();
+        let identity = AffinePoint::IDENTITY;
+
+        assert_eq!(AffinePoint::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(AffinePoint::conditional_select(&a, &b, Choice::from(1)), b);
+
+        // The infinity flag is selected along with the coordinates
+        let selected = AffinePoint::conditional_select(&a, &identity, Choice::from(1));
+        assert!(bool::from(selected.is_identity()));
+        let selected = AffinePoint::conditional_select(&identity, &a, Choice::from(1));
+        assert!(!bool::from(selected.is_identity()));
+        assert_eq!(selected, a);
+    }
+
+    #[test]
+    fn generator_y_parity() {
+        // Gᵧ = 483ada77 ... fb10d4b8, which is even
+        let basepoint = AffinePoint::GENERATOR;
+        assert!(!bool::from(basepoint.y_is_odd()));
+        assert!(bool::from((-basepoint).y_is_odd()));
+        assert_eq!(basepoint.to_bytes()[0], 0x02);
+        assert_eq!((-basepoint).to_bytes()[0], 0x03);
+    }
+
+    #[test]
+    fn sec1_tag() {
+        let basepoint = AffinePoint::GENERATOR;
+        assert_eq!(basepoint.sec1_tag(), COMPRESSED_BASEPOINT[0]);
+        assert_eq!(basepoint.sec1_tag(), basepoint.to_bytes()[0]);
+        assert_eq!((-basepoint).sec1_tag(), 0x03);
+        assert_eq!(AffinePoint::IDENTITY.sec1_tag(), 0x00);
+
+        for i in 1u32..32 {
+            let point = (ProjectivePoint::GENERATOR * Scalar::from(i)).to_affine();
+            assert_eq!(point.sec1_tag(), point.to_encoded_point(true).as_bytes()[0]);
+        }
+    }
+
+    #[test]
+    fn decompress_selects_root_by_parity() {
+        use elliptic_curve::{point::DecompressPoint, subtle::Choice};
+
+        let point = (ProjectivePoint::GENERATOR * Scalar::from(7u32)).to_affine();
+        let x = point.x();
+
+        let even = AffinePoint::decompress(&x, Choice::from(0)).unwrap();
+        let odd = AffinePoint::decompress(&x, Choice::from(1)).unwrap();
+
+        assert!(!bool::from(even.y_is_odd()));
+        assert!(bool::from(odd.y_is_odd()));
+        assert_eq!(even, -odd);
+        assert!(point == even || point == odd);
+    }
+
+    #[test]
+    fn decompress_rejects_non_canonical_x() {
+        use elliptic_curve::point::{DecompactPoint, DecompressPoint};
+
+        const P: [u8; 32] =
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        const P_PLUS_ONE: [u8; 32] =
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30");
+
+        // x = 1 is on the curve, so p + 1 must not be silently reduced to it
+        let mut one = FieldBytes::default();
+        one[31] = 1;
+        assert!(bool::from(
+            AffinePoint::decompress(&one, Choice::from(0)).is_some()
+        ));
+
+        for x in [P, P_PLUS_ONE] {
+            let x = FieldBytes::from(x);
+            for y_is_odd in [0, 1] {
+                assert!(bool::from(
+                    AffinePoint::decompress(&x, Choice::from(y_is_odd)).is_none()
+                ));
+            }
+            assert!(bool::from(AffinePoint::decompact(&x).is_none()));
+            assert!(AffinePoint::decompress_both(&x).is_none());
+        }
+    }
+
+    #[test]
+    fn compress_round_trip() {
+        use elliptic_curve::point::DecompressPoint;
+
+        for k in [1u32, 2, 3, 7, 1000] {
+            let point = (ProjectivePoint::GENERATOR * Scalar::from(k)).to_affine();
+            for point in [point, -point] {
+                let (x, y_is_odd) = point.compress();
+                assert_eq!(x, point.x());
+                assert_eq!(bool::from(y_is_odd), bool::from(point.y_is_odd()));
+                assert_eq!(AffinePoint::decompress(&x, y_is_odd).unwrap(), point);
+            }
+        }
+
+        let (x, y_is_odd) = AffinePoint::IDENTITY.compress();
+        assert_eq!(x, FieldBytes::default());
+        assert!(bool::from(AffinePoint::decompress(&x, y_is_odd).is_none()));
+    }
+
+    #[test]
+    fn decompress_both() {
+        let point = (ProjectivePoint::GENERATOR * Scalar::from(7u32)).to_affine();
+        let (even, odd) = AffinePoint::decompress_both(&point.x()).unwrap();
+
+        assert!(!bool::from(even.y_is_odd()));
+        assert!(bool::from(odd.y_is_odd()));
+        assert_eq!(even, -odd);
+        assert_eq!(even.x(), odd.x());
+        assert!(point == even || point == odd);
+
+        // x = 5 is not the x-coordinate of any point on secp256k1
+        let mut x = FieldBytes::default();
+        x[31] = 5;
+        assert!(AffinePoint::decompress_both(&x).is_none());
+    }
+
+    #[test]
+    fn encoded_point_coordinates() {
+        use crate::Coordinates;
+
+        let identity = EncodedPoint::identity();
+        assert!(matches!(identity.coordinates(), Coordinates::Identity));
+
+        let compressed = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+        match compressed.coordinates() {
+            Coordinates::Compressed { x, y_is_odd } => {
+                assert_eq!(&x[..], &COMPRESSED_BASEPOINT[1..]);
+                assert!(!y_is_odd);
+            }
+            other => panic!("unexpected coordinates: {:?}", other),
+        }
+
+        let uncompressed = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+        match uncompressed.coordinates() {
+            Coordinates::Uncompressed { x, y } => {
+                assert_eq!(&x[..], &UNCOMPRESSED_BASEPOINT[1..33]);
+                assert_eq!(&y[..], &UNCOMPRESSED_BASEPOINT[33..]);
+            }
+            other => panic!("unexpected coordinates: {:?}", other),
+        }
+
+        // Coordinates are extracted without checking that they're on the curve
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x03;
+        bytes[32] = 5;
+        let off_curve = EncodedPoint::from_bytes(bytes).unwrap();
+        assert!(matches!(
+            off_curve.coordinates(),
+            Coordinates::Compressed { y_is_odd: true, .. }
+        ));
+        assert!(bool::from(
+            AffinePoint::from_encoded_point(&off_curve).is_none()
+        ));
+    }
+
+    #[test]
+    fn untagged_round_trip() {
+        let point = AffinePoint::from_encoded_point(
+            &EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap(),
+        )
+        .unwrap();
+
+        let bytes = point.to_untagged_bytes();
+        assert_eq!(&bytes[..], &UNCOMPRESSED_BASEPOINT[1..]);
+        assert_eq!(AffinePoint::from_untagged_bytes(&bytes).unwrap(), point);
+
+        let point = (ProjectivePoint::GENERATOR * Scalar::from(42u32)).to_affine();
+        let bytes = point.to_untagged_bytes();
+        assert_eq!(AffinePoint::from_untagged_bytes(&bytes).unwrap(), point);
+    }
+
+    #[test]
+    fn untagged_rejects_off_curve() {
+        let mut bytes = AffinePoint::GENERATOR.to_untagged_bytes();
+        bytes[63] ^= 1;
+        assert!(bool::from(
+            AffinePoint::from_untagged_bytes(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            AffinePoint::from_untagged_bytes(&[0; 64]).is_none()
+        ));
+        assert_eq!(AffinePoint::IDENTITY.to_untagged_bytes(), [0; 64]);
+    }
+
+    #[test]
+    fn from_secret_scalar() {
+        use crate::NonZeroScalar;
+        use rand_core::OsRng;
+
+        let one = NonZeroScalar::new(Scalar::ONE).unwrap();
+        for d in [one, NonZeroScalar::random(&mut OsRng)] {
+            assert_eq!(
+                AffinePoint::from_secret_scalar(&d),
+                (ProjectivePoint::GENERATOR * *d).to_affine()
+            );
+        }
+
+        let d = NonZeroScalar::new(-Scalar::ONE).unwrap();
+        assert_eq!(AffinePoint::from_secret_scalar(&d), -AffinePoint::GENERATOR);
     }
 
     #[test]
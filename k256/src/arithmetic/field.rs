@@ -31,13 +31,20 @@ cfg_if! {
     }
 }
 
-use crate::FieldBytes;
+#[cfg(feature = "expose-field")]
+mod normalized;
+
+#[cfg(feature = "expose-field")]
+pub use normalized::NormalizedFieldElement;
+
+use crate::{FieldBytes, FIELD_MODULUS_HEX};
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use elliptic_curve::{
-    ff::{self, Field, PrimeField},
+    bigint::{ArrayEncoding, Odd, U256},
+    ff::{Field, PrimeField},
     ops::Invert,
     rand_core::RngCore,
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
@@ -47,6 +54,9 @@ use elliptic_curve::{
 #[cfg(test)]
 use num_bigint::{BigUint, ToBigUint};
 
+/// Field modulus `p`, used by the safegcd inversion.
+const ODD_MODULUS: Odd<U256> = Odd::<U256>::from_be_hex(FIELD_MODULUS_HEX);
+
 /// An element in the finite field used for curve coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement(FieldElementImpl);
@@ -104,6 +114,28 @@ impl FieldElement {
         Self(FieldElementImpl::from_u64(w))
     }
 
+    /// Reduce a 48-byte big endian integer modulo p.
+    ///
+    /// This is the `L = 48` wide reduction used by RFC 9380's `hash_to_field`, whose output
+    /// is statistically close to uniform. The result is fully normalized.
+    pub fn from_bytes_wide(bytes: &[u8; 48]) -> Self {
+        // 0x0000000000000001000000000000000000000000000000000000000000000000
+        const F_2_192: FieldElement = FieldElement::from_bytes_unchecked(&[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ]);
+
+        let mut hi = [0u8; 32];
+        let mut lo = [0u8; 32];
+        hi[8..].copy_from_slice(&bytes[..24]);
+        lo[8..].copy_from_slice(&bytes[24..]);
+
+        let d0 = FieldElement::from_bytes_unchecked(&hi);
+        let d1 = FieldElement::from_bytes_unchecked(&lo);
+        (d0 * F_2_192 + d1).normalize()
+    }
+
     /// Returns the SEC1 encoding of this field element.
     pub fn to_bytes(self) -> FieldBytes {
         self.0.normalize().to_bytes()
@@ -159,6 +191,32 @@ impl FieldElement {
         Self(self.0.square())
     }
 
+    /// Returns self * self * self.
+    ///
+    /// Brings the magnitude to 1 (but doesn't normalize the result).
+    /// The magnitude of the argument should be <= 8.
+    pub fn cube(&self) -> Self {
+        self.square().mul(self)
+    }
+
+    /// Raises self to the power `exp`, using a square-and-multiply chain.
+    ///
+    /// Intended for small, public exponents: the running time depends on the
+    /// bit length of `exp`, but not on the value of `self`.
+    ///
+    /// Brings the magnitude to 1 (but doesn't normalize the result).
+    /// The magnitude of the argument should be <= 8.
+    pub fn pow_small(&self, exp: u32) -> Self {
+        let mut res = Self::ONE;
+        for i in (0..(u32::BITS - exp.leading_zeros())).rev() {
+            res = res.square();
+            if (exp >> i) & 1 == 1 {
+                res = res.mul(self);
+            }
+        }
+        res
+    }
+
     /// Raises the scalar to the power `2^k`
     fn pow2k(&self, k: usize) -> Self {
         let mut x = *self;
@@ -201,6 +259,21 @@ impl FieldElement {
         CtOption::new(res, !self.normalizes_to_zero())
     }
 
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    ///
+    /// This uses the Bernstein-Yang "safegcd" algorithm (<https://eprint.iacr.org/2019/266>),
+    /// which is faster than the exponentiation used by [`FieldElement::invert`]. It is only
+    /// intended for inputs which are not secret: although it currently runs in constant time,
+    /// this is not guaranteed.
+    ///
+    /// The result is normalized.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let x = U256::from_be_byte_array(self.to_bytes());
+        CtOption::from(x.inv_odd_mod(&ODD_MODULUS))
+            .and_then(|inv| Self::from_bytes(&inv.to_be_byte_array()))
+            .and_then(|inv| CtOption::new(inv, !self.normalizes_to_zero()))
+    }
+
     /// Returns the square root of self mod p, or `None` if no square root exists.
     /// The result has magnitude 1, but is not normalized.
     pub fn sqrt(&self) -> CtOption<Self> {
@@ -216,9 +289,61 @@ impl FieldElement {
         */
 
         // The binary representation of (p + 1)/4 has 3 blocks of 1s, with lengths in
-        // { 2, 22, 223 }. Use an addition chain to calculate 2^n - 1 for each block:
-        // 1, [2], 3, 6, 9, 11, [22], 44, 88, 176, 220, [223]
+        // { 2, 22, 223 }.
+        let (x2, x22, x223) = self.pow_blocks();
 
+        // The final result is then assembled using a sliding window over the blocks.
+        let res = x223.pow2k(23).mul(&x22).pow2k(6).mul(&x2).pow2k(2);
+
+        let is_root = (res.mul(&res).negate(1) + self).normalizes_to_zero();
+
+        // Only return Some if it's the square root.
+        CtOption::new(res, is_root)
+    }
+
+    /// Computes `(is_square, sqrt(u / v))` without inverting `v`.
+    ///
+    /// This follows the semantics of [`Field::sqrt_ratio`], which uses the field's
+    /// `ROOT_OF_UNITY = -1` as the non-square:
+    ///
+    /// - `(true, sqrt(u / v))` if `u` and `v` are non-zero and `u / v` is a square,
+    /// - `(true, 0)` if `u` is zero,
+    /// - `(false, 0)` if `u` is non-zero and `v` is zero,
+    /// - `(false, sqrt(-u / v))` otherwise (-1 being the non-square `ROOT_OF_UNITY`).
+    ///
+    /// This is *not* the RFC 9380 `sqrt_ratio` used by the secp256k1 SSWU map, which
+    /// returns `sqrt(Z * u / v)` with `Z = -11` for a non-square `u / v`.
+    ///
+    /// The returned root is normalized.
+    pub fn sqrt_ratio(u: &Self, v: &Self) -> (Choice, Self) {
+        // y1 = u * v * (u * v^3)^((p - 3) / 4)
+        let uv = u.mul(v);
+        let uv3 = v.square().mul(&uv);
+
+        // (p - 3)/4 has the same blocks of 1s as (p + 1)/4, but with the final window
+        // changed from 0b1100 to 0b1011.
+        let (x2, x22, x223) = uv3.pow_blocks();
+        let y1 = x223
+            .pow2k(23)
+            .mul(&x22)
+            .pow2k(6)
+            .mul(&uv3.square())
+            .pow2k(2)
+            .mul(&x2)
+            .mul(&uv);
+
+        // y1^2 * v == u iff u / v is a square; otherwise y1^2 * v == -u, so y1 is
+        // sqrt(-u / v) as required by `Field::sqrt_ratio`.
+        let is_square = (y1.square().mul(v).negate(1) + u).normalizes_to_zero();
+
+        (is_square, y1.normalize())
+    }
+
+    /// Returns `(self^(2^2 - 1), self^(2^22 - 1), self^(2^223 - 1))`, the blocks of 1s in
+    /// the exponents used by [`FieldElement::sqrt`] and [`FieldElement::sqrt_ratio`].
+    fn pow_blocks(&self) -> (Self, Self, Self) {
+        // Use an addition chain to calculate 2^n - 1 for each block:
+        // 1, [2], 3, 6, 9, 11, [22], 44, 88, 176, 220, [223]
         let x2 = self.pow2k(1).mul(self);
         let x3 = x2.pow2k(1).mul(self);
         let x6 = x3.pow2k(3).mul(&x3);
@@ -231,13 +356,7 @@ impl FieldElement {
         let x220 = x176.pow2k(44).mul(&x44);
         let x223 = x220.pow2k(3).mul(&x3);
 
-        // The final result is then assembled using a sliding window over the blocks.
-        let res = x223.pow2k(23).mul(&x22).pow2k(6).mul(&x2).pow2k(2);
-
-        let is_root = (res.mul(&res).negate(1) + self).normalizes_to_zero();
-
-        // Only return Some if it's the square root.
-        CtOption::new(res, is_root)
+        (x2, x22, x223)
     }
 
     #[cfg(test)]
@@ -252,6 +371,10 @@ impl Invert for FieldElement {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl Field for FieldElement {
@@ -288,15 +411,14 @@ impl Field for FieldElement {
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-        ff::helpers::sqrt_ratio_generic(num, div)
+        FieldElement::sqrt_ratio(num, div)
     }
 }
 
 impl PrimeField for FieldElement {
     type Repr = FieldBytes;
 
-    const MODULUS: &'static str =
-        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+    const MODULUS: &'static str = FIELD_MODULUS_HEX;
     const NUM_BITS: u32 = 256;
     const CAPACITY: u32 = 255;
     const TWO_INV: Self = Self(FieldElementImpl::from_bytes_unchecked(&[
@@ -555,6 +677,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parity() {
+        assert!(bool::from(FieldElement::ONE.is_odd()));
+        assert!(bool::from(FieldElement::from_u64(2).is_even()));
+
+        // -1 = p - 1 is even since the modulus is odd
+        let minus_one = FieldElement::ONE.negate(1).normalize();
+        assert!(bool::from(minus_one.is_even()));
+        assert!(!bool::from(minus_one.is_odd()));
+    }
+
     #[test]
     fn root_of_unity_constant() {
         // ROOT_OF_UNITY^{2^s} mod m == 1
@@ -627,6 +760,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn from_bytes_rejects_non_canonical() {
+        use hex_literal::hex;
+
+        const P: [u8; 32] =
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+
+        let mut bytes = P;
+        assert!(bool::from(
+            FieldElement::from_bytes(&bytes.into()).is_none()
+        ));
+
+        bytes[31] += 1;
+        assert!(bool::from(
+            FieldElement::from_bytes(&bytes.into()).is_none()
+        ));
+
+        bytes[31] -= 2;
+        let p_minus_one = FieldElement::from_bytes(&bytes.into()).unwrap();
+        assert_eq!(p_minus_one, (-FieldElement::ONE).normalize());
+    }
+
+    #[test]
+    fn random_is_canonical_and_distinct() {
+        let modulus = FieldElement::modulus_as_biguint();
+        let mut samples = [[0u8; 32]; 1000];
+
+        for sample in samples.iter_mut() {
+            let fe = FieldElement::random(&mut OsRng);
+            assert!(fe.to_biguint().unwrap() < modulus);
+            *sample = fe.to_bytes().into();
+        }
+
+        samples.sort_unstable();
+        assert!(samples.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
     #[test]
     fn to_bytes() {
         assert_eq!(FieldElement::ZERO.to_bytes(), [0; 32]);
@@ -675,6 +845,27 @@ mod tests {
         assert_eq!(neg_two.negate(3).normalize(), two.normalize());
     }
 
+    #[test]
+    fn cube() {
+        let x = FieldElement::random(&mut OsRng);
+        assert_eq!(x.cube().normalize(), (x * &x * &x).normalize());
+        assert_eq!(FieldElement::ZERO.cube().normalize(), FieldElement::ZERO);
+        assert_eq!(FieldElement::ONE.cube().normalize(), FieldElement::ONE);
+    }
+
+    #[test]
+    fn pow_small() {
+        let x = FieldElement::random(&mut OsRng);
+        assert_eq!(x.pow_small(0).normalize(), FieldElement::ONE);
+        assert_eq!(x.pow_small(1).normalize(), x.normalize());
+        assert_eq!(x.pow_small(2).normalize(), x.square().normalize());
+        assert_eq!(x.pow_small(3).normalize(), x.cube().normalize());
+        assert_eq!(
+            x.pow_small(13).normalize(),
+            x.pow_vartime(&[13, 0, 0, 0]).normalize()
+        );
+    }
+
     #[test]
     fn invert() {
         assert!(bool::from(FieldElement::ZERO.invert().is_none()));
@@ -687,6 +878,26 @@ mod tests {
         assert_eq!((two * &inv_two).normalize(), one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(FieldElement::ZERO.invert_vartime().is_none()));
+        assert!(bool::from(
+            (FieldElement::ONE + &FieldElement::ONE.negate(1))
+                .invert_vartime()
+                .is_none()
+        ));
+
+        let one = FieldElement::ONE;
+        assert_eq!(one.invert_vartime().unwrap(), one.normalize());
+
+        for _ in 0..100 {
+            let x = FieldElement::random(&mut OsRng);
+            let inv = x.invert_vartime().unwrap();
+            assert_eq!((x * &inv).normalize(), one.normalize());
+            assert_eq!(inv, x.invert().unwrap().normalize());
+        }
+    }
+
     #[test]
     fn batch_invert_array() {
         let k: FieldElement = FieldElement::random(&mut OsRng);
@@ -720,6 +931,37 @@ mod tests {
         assert_eq!(four.sqrt().unwrap().normalize(), two.normalize());
     }
 
+    #[test]
+    fn sqrt_ratio() {
+        let zero = FieldElement::ZERO;
+        let one = FieldElement::ONE;
+        let four = FieldElement::from_u64(4);
+        let nine = FieldElement::from_u64(9);
+
+        // Edge cases from the `Field::sqrt_ratio` contract
+        let (is_square, root) = FieldElement::sqrt_ratio(&zero, &zero);
+        assert!(bool::from(is_square));
+        assert_eq!(root, zero);
+        let (is_square, root) = FieldElement::sqrt_ratio(&zero, &four);
+        assert!(bool::from(is_square));
+        assert_eq!(root, zero);
+        let (is_square, root) = FieldElement::sqrt_ratio(&one, &zero);
+        assert!(!bool::from(is_square));
+        assert_eq!(root, zero);
+
+        // 9/4 = (3/2)^2
+        let (is_square, root) = FieldElement::sqrt_ratio(&nine, &four);
+        let three_halves =
+            (FieldElement::from_u64(3) * &FieldElement::from_u64(2).invert().unwrap()).normalize();
+        assert!(bool::from(is_square));
+        assert!(root == three_halves || root == three_halves.negate(1).normalize());
+
+        // -1 is not a square since p = 3 mod 4, so sqrt(-(-1) / 1) = 1 is returned
+        let (is_square, root) = FieldElement::sqrt_ratio(&one.negate(1), &one);
+        assert!(!bool::from(is_square));
+        assert_eq!(root.square().normalize(), one);
+    }
+
     #[test]
     #[cfg_attr(
         debug_assertions,
@@ -762,6 +1004,22 @@ mod tests {
 
     proptest! {
 
+        #[test]
+        fn fuzzy_sqrt_ratio(a in field_element(), b in field_element()) {
+            let (is_square, root) = FieldElement::sqrt_ratio(&a, &b);
+            let (expected_is_square, _) = elliptic_curve::ff::helpers::sqrt_ratio_generic(&a, &b);
+            prop_assert_eq!(is_square.unwrap_u8(), expected_is_square.unwrap_u8());
+
+            if !bool::from(b.normalizes_to_zero()) {
+                let ratio = (root.square() * &b).normalize();
+                if bool::from(is_square) {
+                    prop_assert_eq!(ratio, a);
+                } else {
+                    prop_assert_eq!(ratio, a.negate(1).normalize());
+                }
+            }
+        }
+
         #[test]
         fn fuzzy_add(
             a in field_element(),
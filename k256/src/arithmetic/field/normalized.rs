@@ -0,0 +1,393 @@
+//! Field elements which are always kept fully normalized.
+//!
+//! The internal [`FieldElement`] type uses lazy reduction: arithmetic results carry a
+//! "magnitude" which callers must track and bound themselves. [`NormalizedFieldElement`]
+//! wraps it and fully normalizes after every operation, so that its operator impls can be
+//! used without reasoning about magnitudes at all.
+
+use super::FieldElement;
+use crate::FieldBytes;
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use elliptic_curve::{
+    ff::{Field, PrimeField},
+    ops::Invert,
+    rand_core::RngCore,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    zeroize::DefaultIsZeroes,
+};
+
+/// An element in the finite field used for curve coordinates.
+///
+/// All operations return fully normalized results. For the lower-level lazily reduced
+/// representation used internally by this crate, see [`crate::hazmat::FieldElement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NormalizedFieldElement(FieldElement);
+
+impl NormalizedFieldElement {
+    /// Zero element.
+    pub const ZERO: Self = Self(FieldElement::ZERO);
+
+    /// Multiplicative identity.
+    pub const ONE: Self = Self(FieldElement::ONE);
+
+    /// Determine if this field element is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Determine if this field element is even in the SEC1 sense: `self mod 2 == 0`.
+    pub fn is_even(&self) -> Choice {
+        self.0.is_even()
+    }
+
+    /// Determine if this field element is odd in the SEC1 sense: `self mod 2 == 1`.
+    pub fn is_odd(&self) -> Choice {
+        self.0.is_odd()
+    }
+
+    /// Attempts to parse the given byte array as an SEC1-encoded field element.
+    ///
+    /// Returns None if the byte array does not contain a big-endian integer in the range
+    /// [0, p).
+    pub fn from_bytes(bytes: &FieldBytes) -> CtOption<Self> {
+        FieldElement::from_bytes(bytes).map(Self)
+    }
+
+    /// Convert a `u64` to a field element.
+    pub const fn from_u64(w: u64) -> Self {
+        Self(FieldElement::from_u64(w))
+    }
+
+    /// Reduce 48 bytes of uniformly random "output keying material" modulo p.
+    ///
+    /// This is the wide reduction used by RFC 9380's `hash_to_field` with `L = 48`, and yields
+    /// a field element statistically close to uniform.
+    pub fn from_okm(data: &[u8; 48]) -> Self {
+        Self(FieldElement::from_bytes_wide(data))
+    }
+
+    /// Returns the SEC1 encoding of this field element.
+    pub fn to_bytes(self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        Self(self.0.double().normalize())
+    }
+
+    /// Returns `self * self`.
+    pub fn square(&self) -> Self {
+        Self(self.0.square().normalize())
+    }
+
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    pub fn invert(&self) -> CtOption<Self> {
+        self.0.invert().map(Self::from)
+    }
+
+    /// Returns the square root of self mod p, or `None` if no square root exists.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        self.0.sqrt().map(Self::from)
+    }
+}
+
+impl From<FieldElement> for NormalizedFieldElement {
+    fn from(fe: FieldElement) -> Self {
+        Self(fe.normalize())
+    }
+}
+
+impl From<NormalizedFieldElement> for FieldElement {
+    fn from(fe: NormalizedFieldElement) -> FieldElement {
+        fe.0
+    }
+}
+
+impl From<u64> for NormalizedFieldElement {
+    fn from(k: u64) -> Self {
+        Self::from_u64(k)
+    }
+}
+
+impl Field for NormalizedFieldElement {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self(FieldElement::random(rng))
+    }
+
+    #[must_use]
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    #[must_use]
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        let (is_square, root) = FieldElement::sqrt_ratio(&num.0, &div.0);
+        (is_square, root.into())
+    }
+}
+
+impl PrimeField for NormalizedFieldElement {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str = FieldElement::MODULUS;
+    const NUM_BITS: u32 = FieldElement::NUM_BITS;
+    const CAPACITY: u32 = FieldElement::CAPACITY;
+    const TWO_INV: Self = Self(FieldElement::TWO_INV);
+    const MULTIPLICATIVE_GENERATOR: Self = Self(FieldElement::MULTIPLICATIVE_GENERATOR);
+    const S: u32 = FieldElement::S;
+    const ROOT_OF_UNITY: Self = Self(FieldElement::ROOT_OF_UNITY);
+    const ROOT_OF_UNITY_INV: Self = Self(FieldElement::ROOT_OF_UNITY_INV);
+    const DELTA: Self = Self(FieldElement::DELTA);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(&repr)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl Invert for NormalizedFieldElement {
+    type Output = CtOption<Self>;
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+}
+
+impl ConditionallySelectable for NormalizedFieldElement {
+    #[inline(always)]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(FieldElement::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for NormalizedFieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl DefaultIsZeroes for NormalizedFieldElement {}
+
+impl Eq for NormalizedFieldElement {}
+
+impl PartialEq for NormalizedFieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+/// Implement a binary operator (and its assigning form) in terms of an inherent `FieldElement`
+/// expression, normalizing the result.
+macro_rules! impl_binop {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, |$a:ident, $b:ident| $expr:expr) => {
+        impl $op<&NormalizedFieldElement> for &NormalizedFieldElement {
+            type Output = NormalizedFieldElement;
+
+            fn $op_fn(self, rhs: &NormalizedFieldElement) -> NormalizedFieldElement {
+                let ($a, $b) = (&self.0, &rhs.0);
+                NormalizedFieldElement(($expr).normalize())
+            }
+        }
+
+        impl $op<&NormalizedFieldElement> for NormalizedFieldElement {
+            type Output = NormalizedFieldElement;
+
+            fn $op_fn(self, rhs: &NormalizedFieldElement) -> NormalizedFieldElement {
+                $op::$op_fn(&self, rhs)
+            }
+        }
+
+        impl $op<NormalizedFieldElement> for NormalizedFieldElement {
+            type Output = NormalizedFieldElement;
+
+            fn $op_fn(self, rhs: NormalizedFieldElement) -> NormalizedFieldElement {
+                $op::$op_fn(&self, &rhs)
+            }
+        }
+
+        impl $assign<NormalizedFieldElement> for NormalizedFieldElement {
+            fn $assign_fn(&mut self, rhs: NormalizedFieldElement) {
+                *self = $op::$op_fn(&*self, &rhs);
+            }
+        }
+
+        impl $assign<&NormalizedFieldElement> for NormalizedFieldElement {
+            fn $assign_fn(&mut self, rhs: &NormalizedFieldElement) {
+                *self = $op::$op_fn(&*self, rhs);
+            }
+        }
+    };
+}
+
+// Both operands are normalized (magnitude 1), so these stay within the magnitude bounds of
+// the underlying implementation.
+impl_binop!(Add, add, AddAssign, add_assign, |a, b| *a + b);
+impl_binop!(Sub, sub, SubAssign, sub_assign, |a, b| *a + &b.negate(1));
+impl_binop!(Mul, mul, MulAssign, mul_assign, |a, b| a * b);
+
+impl Neg for NormalizedFieldElement {
+    type Output = NormalizedFieldElement;
+
+    fn neg(self) -> NormalizedFieldElement {
+        -&self
+    }
+}
+
+impl Neg for &NormalizedFieldElement {
+    type Output = NormalizedFieldElement;
+
+    fn neg(self) -> NormalizedFieldElement {
+        NormalizedFieldElement(self.0.negate(1).normalize())
+    }
+}
+
+impl Sum for NormalizedFieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
+    }
+}
+
+impl<'a> Sum<&'a NormalizedFieldElement> for NormalizedFieldElement {
+    fn sum<I: Iterator<Item = &'a NormalizedFieldElement>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for NormalizedFieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
+    }
+}
+
+impl<'a> Product<&'a NormalizedFieldElement> for NormalizedFieldElement {
+    fn product<I: Iterator<Item = &'a NormalizedFieldElement>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, NormalizedFieldElement};
+    use elliptic_curve::ff::Field;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    #[test]
+    fn ops_match_raw() {
+        for _ in 0..100 {
+            let a = FieldElement::random(&mut OsRng);
+            let b = FieldElement::random(&mut OsRng);
+            let (x, y) = (
+                NormalizedFieldElement::from(a),
+                NormalizedFieldElement::from(b),
+            );
+
+            assert_eq!(FieldElement::from(x + y), (a + &b).normalize());
+            assert_eq!(FieldElement::from(x - y), (a + &b.negate(1)).normalize());
+            assert_eq!(FieldElement::from(x * y), (a * &b).normalize());
+            assert_eq!(FieldElement::from(-x), a.negate(1).normalize());
+            assert_eq!(FieldElement::from(x.square()), a.square().normalize());
+            assert_eq!(FieldElement::from(x.double()), a.double().normalize());
+        }
+    }
+
+    #[test]
+    fn ct_eq() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let x = NormalizedFieldElement::random(&mut OsRng);
+        let y = x + NormalizedFieldElement::ONE;
+        assert!(bool::from(x.ct_eq(&x)));
+        assert!(!bool::from(x.ct_eq(&y)));
+
+        // Equal values computed differently compare equal
+        assert!(bool::from((y - NormalizedFieldElement::ONE).ct_eq(&x)));
+    }
+
+    /// `expand_message_xmd` outputs and the resulting `u` values from the secp256k1
+    /// `hash_to_field` test vectors in RFC 9380 Appendix J.8.1.
+    #[test]
+    fn from_okm_rfc9380() {
+        const VECTORS: &[([u8; 48], [u8; 32])] = &[
+            (
+                hex!("7c259c0bd968fb3665ab4b077be0405c6b0f9910dd2ba71c78f2ee9e88b19d79a76dfb585454fc0a6b84bf50e82a9097"),
+                hex!("6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3"),
+            ),
+            (
+                hex!("e2356fc874ae444d2d532bec420a70d11ae6c212e08fe1a5937f620216f42fa4cc570e091d1d04f274606826cf181075"),
+                hex!("1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16"),
+            ),
+            (
+                hex!("e8347cad48ab4e319d7b275520ea81cf128aab5d3679a1f7601e3bdeac9a51d0c54dffd054274edb248855e61190c462"),
+                hex!("128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61"),
+            ),
+            (
+                hex!("a7bb61ecba8e400a9a76d5ae014e87ff5897b65da3b595a813d0fdcbce0d316f766ceeeb6ff84cdeccd69be0e7b399d1"),
+                hex!("5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00"),
+            ),
+            (
+                hex!("9a76ce79d4668a8e5743eb780adf4aceea67a7c02f2cd5d8b87715c0cf5984dad1506dcbbc15cc3302f4684890031c8b"),
+                hex!("ea67a7c02f2cd5d8b87715c169d055a22520f74daeb080e6180958380e2f98b9"),
+            ),
+            (
+                hex!("b3561ae3333ddbb0bcc8733a76e610907434d0d1a500d38380d1f960a8abfac80db2dba1da1a117a63340573e073a488"),
+                hex!("7434d0d1a500d38380d1f9615c021857ac8d546925f5f2355319d823a478da18"),
+            ),
+        ];
+
+        for (okm, u) in VECTORS {
+            assert_eq!(NormalizedFieldElement::from_okm(okm).to_bytes(), *u);
+        }
+
+        // All ones reduces to (2^384 - 1) mod p
+        assert_eq!(
+            NormalizedFieldElement::from_okm(&[0xff; 48]).to_bytes(),
+            hex!("000000000000000000000001000003d0ffffffffffffffffffffffffffffffff")
+        );
+    }
+
+    /// Long chains of operations which would overflow the magnitude bounds of the raw
+    /// representation are fine, since every result is normalized.
+    #[test]
+    fn long_chains() {
+        let x = NormalizedFieldElement::random(&mut OsRng);
+
+        let mut sum = NormalizedFieldElement::ZERO;
+        for _ in 0..1000 {
+            sum += x;
+        }
+        assert_eq!(sum, x * NormalizedFieldElement::from(1000u64));
+
+        let mut diff = sum;
+        for _ in 0..1000 {
+            diff = -(diff - x);
+        }
+        assert_eq!(diff, sum);
+    }
+}
@@ -5,8 +5,7 @@ use elliptic_curve::group::cofactor::CofactorGroup;
 use elliptic_curve::hash2curve::{
     FromOkm, GroupDigest, Isogeny, IsogenyCoefficients, MapToCurve, OsswuMap, OsswuMapParams, Sgn0,
 };
-use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use elliptic_curve::Field;
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, CtOption};
 
 use crate::{AffinePoint, ProjectivePoint, Scalar, Secp256k1};
 
@@ -20,24 +19,9 @@ impl FromOkm for FieldElement {
     type Length = U48;
 
     fn from_okm(data: &Array<u8, Self::Length>) -> Self {
-        // 0x0000000000000001000000000000000000000000000000000000000000000000
-        const F_2_192: FieldElement = FieldElement::from_bytes_unchecked(&[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00,
-        ]);
-        let d0 = FieldElement::from_bytes_unchecked(&[
-            0, 0, 0, 0, 0, 0, 0, 0, data[0], data[1], data[2], data[3], data[4], data[5], data[6],
-            data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
-            data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23],
-        ]);
-        let d1 = FieldElement::from_bytes_unchecked(&[
-            0, 0, 0, 0, 0, 0, 0, 0, data[24], data[25], data[26], data[27], data[28], data[29],
-            data[30], data[31], data[32], data[33], data[34], data[35], data[36], data[37],
-            data[38], data[39], data[40], data[41], data[42], data[43], data[44], data[45],
-            data[46], data[47],
-        ]);
-        d0 * F_2_192 + d1
+        let mut bytes = [0u8; 48];
+        bytes.copy_from_slice(data);
+        Self::from_bytes_wide(&bytes)
     }
 }
 
@@ -102,18 +86,12 @@ impl OsswuMap for FieldElement {
         tv2 = gxd * Self::PARAMS.map_b; // B * gxd
         gx1 += tv2; // gx1 + tv2
 
-        let mut tv4 = gxd.square(); // gxd^2
-        tv2 = gx1 * gxd; // gx1 * gxd
-        tv4 *= tv2;
-
-        let y1 = tv4.pow_vartime(Self::PARAMS.c1) * tv2; // tv4^C1 * tv2
+        // e2 = is_square(gx1 / gxd), y1 = sqrt(gx1 / gxd) or sqrt(Z * gx1 / gxd)
+        let (e2, y1) = Self::sqrt_ratio_3mod4(&gx1, &gxd);
         let x2n = tv3 * x1n; // tv3 * x1n
 
-        let y2 = y1 * Self::PARAMS.c2 * tv1 * self; // y1 * c2 * tv1 * u
-
-        tv2 = y1.square() * gxd; //y1^2 * gxd
-
-        let e2 = tv2.normalize().ct_eq(&gx1.normalize());
+        // g(x2) = Z^3 * u^6 * g(x1), so sqrt(g(x2)) = Z * u^3 * sqrt(Z * g(x1))
+        let y2 = tv3 * self * y1; // tv3 * u * y1
 
         // if e2 , x = x1, else x = x2
         let mut x = Self::conditional_select(&x2n, &x1n, e2);
@@ -128,6 +106,31 @@ impl OsswuMap for FieldElement {
     }
 }
 
+impl FieldElement {
+    /// `sqrt_ratio` as specified in RFC 9380 Appendix F.2.1.2, for the secp256k1 SSWU map's
+    /// `Z = -11`:
+    ///
+    /// - `(true, sqrt(u / v))` if `u / v` is a square (including `u = 0`),
+    /// - `(false, sqrt(Z * u / v))` otherwise.
+    ///
+    /// Unlike [`FieldElement::sqrt_ratio`], which uses the field's `ROOT_OF_UNITY = -1` as the
+    /// non-square, this matches the RFC 9380 definition for the `secp256k1_XMD:SHA-256_SSWU_*`
+    /// suites. The returned root is normalized.
+    fn sqrt_ratio_3mod4(u: &Self, v: &Self) -> (Choice, Self) {
+        // c2 = sqrt(-Z) = sqrt(11)
+        const C2: FieldElement = FieldElement::from_bytes_unchecked(&[
+            0x31, 0xfd, 0xf3, 0x02, 0x72, 0x40, 0x13, 0xe5, 0x7a, 0xd1, 0x3f, 0xb3, 0x8f, 0x84,
+            0x2a, 0xfe, 0xec, 0x18, 0x4f, 0x00, 0xa7, 0x47, 0x89, 0xdd, 0x28, 0x67, 0x29, 0xc8,
+            0x30, 0x3c, 0x4a, 0x59,
+        ]);
+
+        // For a non-square `u / v`, y1 = sqrt(-u / v), so y1 * c2 = sqrt(Z * u / v)
+        let (is_square, y1) = FieldElement::sqrt_ratio(u, v);
+        let y2 = (y1 * C2).normalize();
+        (is_square, Self::conditional_select(&y2, &y1, is_square))
+    }
+}
+
 impl MapToCurve for FieldElement {
     type Output = ProjectivePoint;
 
@@ -274,7 +277,7 @@ impl CofactorGroup for ProjectivePoint {
 
 #[cfg(test)]
 mod tests {
-    use crate::{FieldElement, Scalar, Secp256k1, U256};
+    use crate::{arithmetic::FieldElement, Scalar, Secp256k1, U256};
     use elliptic_curve::{
         array::Array,
         bigint::{ArrayEncoding, NonZero, U384},
@@ -400,6 +403,41 @@ mod tests {
         }
     }
 
+    /// RFC 9380 `sqrt_ratio` with `Z = -11`, for the `(gx1, gxd)` inputs produced while mapping
+    /// `u[0]` for msg = "" and `u[1]` for msg = "q128_qqq..." from the test vectors above.
+    #[test]
+    fn sqrt_ratio_3mod4() {
+        struct TestVector {
+            u: [u8; 32],
+            v: [u8; 32],
+            is_square: bool,
+            root: [u8; 32],
+        }
+
+        const TEST_VECTORS: [TestVector; 2] = [
+            TestVector {
+                u: hex!("3f5d6e7dbc13cc4c41c6a28777766e108f891b1b7dee3a28d1dbb6849464317e"),
+                v: hex!("b8d838f4a7895253a0b49cb999bdf1395e44adde7e08cb7eba12fb88f0c43776"),
+                is_square: true,
+                root: hex!("4f30b2fd1988697cabc0819818e425c38b84dedee37c8d656279b793b4a05381"),
+            },
+            TestVector {
+                u: hex!("eb90a74795667c8538300571461efccbe1a9cb82ff04f2cf8dfa4f39134f6426"),
+                v: hex!("41219404b8f61a2291447d46d8e611b9724c3dc98991d4965bbcf621480daef7"),
+                is_square: false,
+                root: hex!("a8ad76aea04a0a8ad60253a9b658d6442c112b332ac70f2fc55d222d9936fdbb"),
+            },
+        ];
+
+        for test_vector in TEST_VECTORS {
+            let u = FieldElement::from_bytes(&test_vector.u.into()).unwrap();
+            let v = FieldElement::from_bytes(&test_vector.v.into()).unwrap();
+            let (is_square, root) = FieldElement::sqrt_ratio_3mod4(&u, &v);
+            assert_eq!(bool::from(is_square), test_vector.is_square);
+            assert_eq!(root.to_bytes().as_slice(), test_vector.root);
+        }
+    }
+
     #[test]
     fn from_okm_fuzz() {
         let mut wide_order = Array::default();
@@ -429,4 +467,35 @@ mod tests {
             assert_eq!(from_okm, simple_from_okm);
         });
     }
+
+    /// Blind, evaluate and unblind an input as in the RFC 9497 OPRF protocol.
+    #[cfg(feature = "voprf")]
+    #[test]
+    fn voprf_blind_unblind() {
+        use crate::{NonZeroScalar, ProjectivePoint};
+        use elliptic_curve::{
+            hash2curve::ExpandMsgXmd, ops::Invert, rand_core::OsRng, VoprfParameters,
+        };
+
+        type Hash = <Secp256k1 as VoprfParameters>::Hash;
+
+        // `"HashToGroup-" || contextString` for the base (OPRF) mode.
+        const DST: &[u8] = b"HashToGroup-OPRFV1-\x00-secp256k1-SHA256";
+        assert!(DST.ends_with(Secp256k1::ID.as_bytes()));
+
+        let input: &[u8] = b"privacy-preserving lookup";
+        let element = Secp256k1::hash_from_bytes::<ExpandMsgXmd<Hash>>(&[input], &[DST]).unwrap();
+
+        let blind = NonZeroScalar::random(&mut OsRng);
+        let sk = NonZeroScalar::random(&mut OsRng);
+
+        // Client blinds, server evaluates, client unblinds.
+        let blinded_element = element * *blind;
+        assert_ne!(blinded_element, element);
+        let evaluated_element = blinded_element * *sk;
+        let unblinded = evaluated_element * *blind.invert();
+
+        assert_eq!(unblinded, element * *sk);
+        assert_ne!(unblinded, ProjectivePoint::IDENTITY);
+    }
 }
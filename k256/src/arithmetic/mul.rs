@@ -34,12 +34,6 @@
 //! (Note that 'd' is also equal to the curve order here because `[a1,b1]` and `[a2,b2]` are found
 //! as outputs of the Extended Euclidean Algorithm on inputs 'order' and 'lambda').
 
-#[cfg(all(
-    feature = "precomputed-tables",
-    not(any(feature = "critical-section", feature = "std"))
-))]
-compile_error!("`precomputed-tables` feature requires either `critical-section` or `std`");
-
 use crate::arithmetic::{
     scalar::{Scalar, WideScalar},
     ProjectivePoint,
@@ -48,12 +42,19 @@ use crate::arithmetic::{
 use core::ops::{Mul, MulAssign};
 use elliptic_curve::{
     ops::{LinearCombination, MulByGenerator},
+    rand_core::CryptoRngCore,
     scalar::IsHigh,
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
 };
 
 #[cfg(feature = "precomputed-tables")]
-use once_cell::sync::Lazy;
+use {crate::arithmetic::AffinePoint, cfg_if::cfg_if};
+
+#[cfg(feature = "precomputed-tables")]
+mod gen_table;
+
+#[cfg(feature = "alloc")]
+mod pippenger;
 
 /// Lookup table containing precomputed values `[p, 2p, 3p, ..., 8p]`
 #[derive(Copy, Clone, Default)]
@@ -361,24 +362,30 @@ fn lincomb(
     acc
 }
 
-/// Lazily computed basepoint table.
-#[cfg(feature = "precomputed-tables")]
-static GEN_LOOKUP_TABLE: Lazy<[LookupTable; 33]> = Lazy::new(precompute_gen_lookup_table);
-
+/// Given -8 <= x <= 8, returns x * p in constant time, where `table` contains the
+/// precomputed affine multiples `[p, 2p, 3p, ..., 8p]`.
 #[cfg(feature = "precomputed-tables")]
-fn precompute_gen_lookup_table() -> [LookupTable; 33] {
-    let mut gen = ProjectivePoint::GENERATOR;
-    let mut res = [LookupTable::default(); 33];
-
-    for i in 0..33 {
-        res[i] = LookupTable::from(&gen);
-        // We are storing tables spaced by two radix steps,
-        // to decrease the size of the precomputed data.
-        for _ in 0..8 {
-            gen = gen.double();
-        }
+fn select_affine(table: &[AffinePoint; 8], x: i8) -> AffinePoint {
+    debug_assert!(x >= -8);
+    debug_assert!(x <= 8);
+
+    // Compute xabs = |x|
+    let xmask = x >> 7;
+    let xabs = (x + xmask) ^ xmask;
+
+    // Get an array element in constant time
+    let mut t = AffinePoint::IDENTITY;
+    for j in 1..9 {
+        let c = (xabs as u8).ct_eq(&(j as u8));
+        t.conditional_assign(&table[j - 1], c);
     }
-    res
+    // Now t == |x| * p.
+
+    let neg_mask = Choice::from((xmask & 1) as u8);
+    t.conditional_assign(&-t, neg_mask);
+    // Now t == x * p.
+
+    t
 }
 
 impl MulByGenerator for ProjectivePoint {
@@ -391,28 +398,126 @@ impl MulByGenerator for ProjectivePoint {
     /// Calculates `k * G`, where `G` is the generator.
     #[cfg(feature = "precomputed-tables")]
     fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
-        let digits = Radix16Decomposition::<65>::new(k);
-        let table = *GEN_LOOKUP_TABLE;
-        let mut acc = table[32].select(digits.0[64]);
-        let mut acc2 = ProjectivePoint::IDENTITY;
-        for i in (0..32).rev() {
-            acc2 += &table[i].select(digits.0[i * 2 + 1]);
-            acc += &table[i].select(digits.0[i * 2]);
-        }
-        // This is the price of halving the precomputed table size (from 60kb to 30kb)
-        // The performance hit is minor, about 3%.
-        for _ in 0..4 {
-            acc2 = acc2.double();
-        }
-        acc + acc2
+        mul_by_generator_comb::<GEN_TABLE_STRIDE>(&GEN_TABLE, k)
+    }
+}
+
+#[cfg(feature = "precomputed-tables")]
+cfg_if! {
+    if #[cfg(feature = "precomputed-tables-quarter")] {
+        /// Number of radix-16 digits spanned by each row of [`GEN_TABLE`].
+        const GEN_TABLE_STRIDE: usize = 8;
+
+        /// Basepoint table used by `mul_by_generator`.
+        static GEN_TABLE: [[AffinePoint; 8]; 9] = gen_table::QUARTER_TABLE;
+    } else if #[cfg(feature = "precomputed-tables-half")] {
+        /// Number of radix-16 digits spanned by each row of [`GEN_TABLE`].
+        const GEN_TABLE_STRIDE: usize = 4;
+
+        /// Basepoint table used by `mul_by_generator`.
+        static GEN_TABLE: [[AffinePoint; 8]; 17] = gen_table::HALF_TABLE;
+    } else {
+        /// Number of radix-16 digits spanned by each row of [`GEN_TABLE`].
+        const GEN_TABLE_STRIDE: usize = 2;
+
+        /// Basepoint table used by `mul_by_generator`.
+        static GEN_TABLE: [[AffinePoint; 8]; 33] = gen_table::TABLE;
     }
 }
 
+#[cfg(feature = "expose-gen-table")]
+impl ProjectivePoint {
+    /// Returns the precomputed table of multiples of the generator used by
+    /// [`MulByGenerator::mul_by_generator`], for inspection and testing.
+    ///
+    /// Entry `[i][j]` of the table is `(j + 1) * 2^(b * i) * G` for
+    /// `b = 256 / (table.len() - 1)`. The spacing `b` of the rows is 8 bits
+    /// by default, or 16 or 32 bits with the `precomputed-tables-half` or
+    /// `precomputed-tables-quarter` features respectively.
+    pub fn generator_table() -> &'static [[AffinePoint; 8]] {
+        &GEN_TABLE
+    }
+}
+
+/// Calculates `k * G` using a table whose rows are the multiples `[p, 2p, ..., 8p]` of
+/// `p = 2^(4 * M * i) * G`.
+///
+/// Radix-16 digit `d` of `k` is looked up in row `d / M` and added to accumulator `d % M`, and
+/// accumulator `m` is then multiplied by `16^m`, taking `4 * (M - 1)` doublings in total. Each
+/// doubling of `M` thus halves the size of the table at the cost of a few more doublings: with
+/// `M = 2` (the full table) this is only about 3% slower than a table with a row per digit,
+/// while being half its size.
+#[cfg(feature = "precomputed-tables")]
+fn mul_by_generator_comb<const M: usize>(
+    table: &[[AffinePoint; 8]],
+    k: &Scalar,
+) -> ProjectivePoint {
+    debug_assert!(M * table.len() > 64);
+
+    let digits = Radix16Decomposition::<65>::new(k);
+    let mut accs = [ProjectivePoint::IDENTITY; M];
+    for (i, &digit) in digits.0.iter().enumerate() {
+        accs[i % M] += &select_affine(&table[i / M], digit);
+    }
+
+    let (last, rest) = accs.split_last().expect("M > 0");
+    rest.iter().rev().fold(*last, |acc, partial| {
+        acc.double().double().double().double() + partial
+    })
+}
+
 #[inline(always)]
 fn mul(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
     ProjectivePoint::lincomb(&[(*x, *k)])
 }
 
+impl ProjectivePoint {
+    /// Calculates `g_scalar * G + p_scalar * p`, where `G` is the generator.
+    ///
+    /// This is the operation at the core of Schnorr and ECDSA verification. When the
+    /// `precomputed-tables` feature is enabled the generator multiple uses the fixed-base
+    /// table, and `p` is multiplied using the GLV-decomposed windowed method.
+    pub fn mul_add(g_scalar: &Scalar, p: &ProjectivePoint, p_scalar: &Scalar) -> ProjectivePoint {
+        #[cfg(feature = "precomputed-tables")]
+        {
+            ProjectivePoint::mul_by_generator(g_scalar) + mul(p, p_scalar)
+        }
+
+        #[cfg(not(feature = "precomputed-tables"))]
+        {
+            ProjectivePoint::lincomb(&[(ProjectivePoint::GENERATOR, *g_scalar), (*p, *p_scalar)])
+        }
+    }
+
+    /// Calculates `self + k * G`, where `G` is the generator.
+    ///
+    /// The generator multiple is computed with [`MulByGenerator::mul_by_generator`], which uses
+    /// the fixed-base comb table when `precomputed-tables` is enabled.
+    pub fn add_generator_mul(&self, k: &Scalar) -> ProjectivePoint {
+        self + &ProjectivePoint::mul_by_generator(k)
+    }
+
+    /// Calculates `k * G`, where `G` is the generator, blinding the computation with
+    /// randomness from `rng`.
+    ///
+    /// [`Scalar`] is always fully reduced, so rather than adding a random multiple of the group
+    /// order to `k`, it is split into the random shares `k - r` and `r`. Each share is multiplied
+    /// with [`MulByGenerator::mul_by_generator`] (using the fixed-base comb table when
+    /// `precomputed-tables` is enabled), so the table lookups are uncorrelated with `k`. The
+    /// projective coordinates of the sum are then re-randomized with
+    /// [`ProjectivePoint::randomize`].
+    ///
+    /// This costs about twice as much as the unblinded multiplication, and is intended for
+    /// multiplying secret nonces when signing.
+    pub fn mul_by_generator_blinded(k: &Scalar, rng: &mut impl CryptoRngCore) -> ProjectivePoint {
+        let r = Scalar::generate_biased(rng);
+        let mut point =
+            ProjectivePoint::mul_by_generator(&(k - &r)) + ProjectivePoint::mul_by_generator(&r);
+        point.randomize(rng);
+        point
+    }
+}
+
 impl Mul<Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
@@ -475,6 +580,135 @@ mod tests {
         assert_eq!(reference, test);
     }
 
+    #[test]
+    fn test_add_generator_mul() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+        assert_eq!(p.add_generator_mul(&k), p + ProjectivePoint::GENERATOR * k);
+
+        assert_eq!(p.add_generator_mul(&Scalar::ZERO), p);
+        assert_eq!(
+            ProjectivePoint::IDENTITY.add_generator_mul(&k),
+            ProjectivePoint::GENERATOR * k
+        );
+    }
+
+    #[test]
+    fn test_mul_by_generator_blinded() {
+        for k in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            assert_eq!(
+                ProjectivePoint::mul_by_generator_blinded(&k, &mut OsRng),
+                ProjectivePoint::mul_by_generator(&k)
+            );
+        }
+
+        for _ in 0..10 {
+            let k = Scalar::random(&mut OsRng);
+            let blinded = ProjectivePoint::mul_by_generator_blinded(&k, &mut OsRng);
+            assert_eq!(blinded, ProjectivePoint::mul_by_generator(&k));
+            assert_eq!(
+                blinded.to_affine(),
+                (ProjectivePoint::GENERATOR * k).to_affine()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+        let l = Scalar::random(&mut OsRng);
+
+        let reference = ProjectivePoint::GENERATOR * k + p * l;
+        assert_eq!(ProjectivePoint::mul_add(&k, &p, &l), reference);
+        assert_eq!(ProjectivePoint::mul_add(&Scalar::ZERO, &p, &l), p * l);
+        assert_eq!(
+            ProjectivePoint::mul_add(&k, &ProjectivePoint::IDENTITY, &l),
+            ProjectivePoint::GENERATOR * k
+        );
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn test_mul_by_generator_comb() {
+        use super::mul_by_generator_comb;
+
+        let mut scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::ZERO];
+        scalars[3] = Scalar::random(&mut OsRng);
+
+        for k in scalars {
+            let reference = ProjectivePoint::GENERATOR * k;
+            assert_eq!(mul_by_generator_comb::<2>(&gen_table::TABLE, &k), reference);
+            assert_eq!(
+                mul_by_generator_comb::<4>(&gen_table::HALF_TABLE, &k),
+                reference
+            );
+            assert_eq!(
+                mul_by_generator_comb::<8>(&gen_table::QUARTER_TABLE, &k),
+                reference
+            );
+            assert_eq!(ProjectivePoint::mul_by_generator(&k), reference);
+        }
+    }
+
+    #[cfg(feature = "expose-gen-table")]
+    #[test]
+    fn test_generator_table() {
+        let table = ProjectivePoint::generator_table();
+        let row_bits = 256 / (table.len() - 1);
+
+        for (i, row) in table.iter().enumerate() {
+            let row_base = Scalar::from(2u64).pow_vartime([(row_bits * i) as u64]);
+            for (j, entry) in row.iter().enumerate() {
+                let expected = ProjectivePoint::GENERATOR * (row_base * Scalar::from(j as u64 + 1));
+                assert_eq!(
+                    ProjectivePoint::from(*entry),
+                    expected,
+                    "entry [{}][{}]",
+                    i,
+                    j
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn test_gen_table_subsets() {
+        for (i, row) in gen_table::HALF_TABLE.iter().enumerate() {
+            assert_eq!(row, &gen_table::TABLE[2 * i]);
+        }
+        for (i, row) in gen_table::QUARTER_TABLE.iter().enumerate() {
+            assert_eq!(row, &gen_table::TABLE[4 * i]);
+        }
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn test_gen_table() {
+        use crate::{arithmetic::AffinePoint, EncodedPoint};
+        use elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+
+        let table = &gen_table::TABLE;
+        assert_eq!(table[0][0], AffinePoint::GENERATOR);
+
+        let mut gen = ProjectivePoint::GENERATOR;
+        for window in table.iter() {
+            let expected = LookupTable::from(&gen);
+            for (point, expected) in window.iter().zip(expected.0.iter()) {
+                let encoded: EncodedPoint = point.to_encoded_point(false);
+                assert!(bool::from(
+                    AffinePoint::from_encoded_point(&encoded).is_some()
+                ));
+                assert_eq!(*point, expected.to_affine());
+            }
+
+            for _ in 0..8 {
+                gen = gen.double();
+            }
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn test_lincomb_slice() {
@@ -0,0 +1,2759 @@
+//! Precomputed table of multiples of the secp256k1 generator.
+//!
+//! Entry `TABLE[i][j]` is the affine point `(j + 1) * 2^(8 * i) * G` for `0 <= i < 33` and
+//! `0 <= j < 8`, i.e. the multiples `[p, 2p, ..., 8p]` of `p = 2^(8 * i) * G`. Tables are spaced
+//! by two radix-16 digits to halve the size of the precomputed data.
+//!
+//! Smaller tables containing every second or fourth row are derived from it at compile time, for
+//! targets where the size of the full table matters more than the speed of `mul_by_generator`.
+//!
+//! The values were generated with exact integer affine arithmetic over the secp256k1 base field,
+//! starting from the SEC 2 generator and repeatedly doubling, and are checked against points
+//! computed at runtime by the tests in the parent module.
+
+use crate::arithmetic::{AffinePoint, FieldElement};
+
+/// Create an [`AffinePoint`] from big endian coordinates at compile time.
+const fn point(x: &[u8; 32], y: &[u8; 32]) -> AffinePoint {
+    AffinePoint::new(
+        FieldElement::from_bytes_unchecked(x),
+        FieldElement::from_bytes_unchecked(y),
+    )
+}
+
+/// Basepoint table: `TABLE[i][j] = (j + 1) * 2^(8 * i) * G`.
+///
+/// This is a `const` so that only the rows of the table which are actually used end up in the
+/// binary: see [`HALF_TABLE`] and [`QUARTER_TABLE`].
+#[rustfmt::skip]
+pub(super) const TABLE: [[AffinePoint; 8]; 33] = [
+    [
+        point(
+            &[
+                0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b, 0x07,
+                0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17, 0x98,
+            ],
+            &[
+                0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e, 0x11, 0x08, 0xa8,
+                0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f, 0xfb, 0x10, 0xd4, 0xb8,
+            ],
+        ),
+        point(
+            &[
+                0xc6, 0x04, 0x7f, 0x94, 0x41, 0xed, 0x7d, 0x6d, 0x30, 0x45, 0x40, 0x6e, 0x95, 0xc0, 0x7c, 0xd8,
+                0x5c, 0x77, 0x8e, 0x4b, 0x8c, 0xef, 0x3c, 0xa7, 0xab, 0xac, 0x09, 0xb9, 0x5c, 0x70, 0x9e, 0xe5,
+            ],
+            &[
+                0x1a, 0xe1, 0x68, 0xfe, 0xa6, 0x3d, 0xc3, 0x39, 0xa3, 0xc5, 0x84, 0x19, 0x46, 0x6c, 0xea, 0xee,
+                0xf7, 0xf6, 0x32, 0x65, 0x32, 0x66, 0xd0, 0xe1, 0x23, 0x64, 0x31, 0xa9, 0x50, 0xcf, 0xe5, 0x2a,
+            ],
+        ),
+        point(
+            &[
+                0xf9, 0x30, 0x8a, 0x01, 0x92, 0x58, 0xc3, 0x10, 0x49, 0x34, 0x4f, 0x85, 0xf8, 0x9d, 0x52, 0x29,
+                0xb5, 0x31, 0xc8, 0x45, 0x83, 0x6f, 0x99, 0xb0, 0x86, 0x01, 0xf1, 0x13, 0xbc, 0xe0, 0x36, 0xf9,
+            ],
+            &[
+                0x38, 0x8f, 0x7b, 0x0f, 0x63, 0x2d, 0xe8, 0x14, 0x0f, 0xe3, 0x37, 0xe6, 0x2a, 0x37, 0xf3, 0x56,
+                0x65, 0x00, 0xa9, 0x99, 0x34, 0xc2, 0x23, 0x1b, 0x6c, 0xb9, 0xfd, 0x75, 0x84, 0xb8, 0xe6, 0x72,
+            ],
+        ),
+        point(
+            &[
+                0xe4, 0x93, 0xdb, 0xf1, 0xc1, 0x0d, 0x80, 0xf3, 0x58, 0x1e, 0x49, 0x04, 0x93, 0x0b, 0x14, 0x04,
+                0xcc, 0x6c, 0x13, 0x90, 0x0e, 0xe0, 0x75, 0x84, 0x74, 0xfa, 0x94, 0xab, 0xe8, 0xc4, 0xcd, 0x13,
+            ],
+            &[
+                0x51, 0xed, 0x99, 0x3e, 0xa0, 0xd4, 0x55, 0xb7, 0x56, 0x42, 0xe2, 0x09, 0x8e, 0xa5, 0x14, 0x48,
+                0xd9, 0x67, 0xae, 0x33, 0xbf, 0xbd, 0xfe, 0x40, 0xcf, 0xe9, 0x7b, 0xdc, 0x47, 0x73, 0x99, 0x22,
+            ],
+        ),
+        point(
+            &[
+                0x2f, 0x8b, 0xde, 0x4d, 0x1a, 0x07, 0x20, 0x93, 0x55, 0xb4, 0xa7, 0x25, 0x0a, 0x5c, 0x51, 0x28,
+                0xe8, 0x8b, 0x84, 0xbd, 0xdc, 0x61, 0x9a, 0xb7, 0xcb, 0xa8, 0xd5, 0x69, 0xb2, 0x40, 0xef, 0xe4,
+            ],
+            &[
+                0xd8, 0xac, 0x22, 0x26, 0x36, 0xe5, 0xe3, 0xd6, 0xd4, 0xdb, 0xa9, 0xdd, 0xa6, 0xc9, 0xc4, 0x26,
+                0xf7, 0x88, 0x27, 0x1b, 0xab, 0x0d, 0x68, 0x40, 0xdc, 0xa8, 0x7d, 0x3a, 0xa6, 0xac, 0x62, 0xd6,
+            ],
+        ),
+        point(
+            &[
+                0xff, 0xf9, 0x7b, 0xd5, 0x75, 0x5e, 0xee, 0xa4, 0x20, 0x45, 0x3a, 0x14, 0x35, 0x52, 0x35, 0xd3,
+                0x82, 0xf6, 0x47, 0x2f, 0x85, 0x68, 0xa1, 0x8b, 0x2f, 0x05, 0x7a, 0x14, 0x60, 0x29, 0x75, 0x56,
+            ],
+            &[
+                0xae, 0x12, 0x77, 0x7a, 0xac, 0xfb, 0xb6, 0x20, 0xf3, 0xbe, 0x96, 0x01, 0x7f, 0x45, 0xc5, 0x60,
+                0xde, 0x80, 0xf0, 0xf6, 0x51, 0x8f, 0xe4, 0xa0, 0x3c, 0x87, 0x0c, 0x36, 0xb0, 0x75, 0xf2, 0x97,
+            ],
+        ),
+        point(
+            &[
+                0x5c, 0xbd, 0xf0, 0x64, 0x6e, 0x5d, 0xb4, 0xea, 0xa3, 0x98, 0xf3, 0x65, 0xf2, 0xea, 0x7a, 0x0e,
+                0x3d, 0x41, 0x9b, 0x7e, 0x03, 0x30, 0xe3, 0x9c, 0xe9, 0x2b, 0xdd, 0xed, 0xca, 0xc4, 0xf9, 0xbc,
+            ],
+            &[
+                0x6a, 0xeb, 0xca, 0x40, 0xba, 0x25, 0x59, 0x60, 0xa3, 0x17, 0x8d, 0x6d, 0x86, 0x1a, 0x54, 0xdb,
+                0xa8, 0x13, 0xd0, 0xb8, 0x13, 0xfd, 0xe7, 0xb5, 0xa5, 0x08, 0x26, 0x28, 0x08, 0x72, 0x64, 0xda,
+            ],
+        ),
+        point(
+            &[
+                0x2f, 0x01, 0xe5, 0xe1, 0x5c, 0xca, 0x35, 0x1d, 0xaf, 0xf3, 0x84, 0x3f, 0xb7, 0x0f, 0x3c, 0x2f,
+                0x0a, 0x1b, 0xdd, 0x05, 0xe5, 0xaf, 0x88, 0x8a, 0x67, 0x78, 0x4e, 0xf3, 0xe1, 0x0a, 0x2a, 0x01,
+            ],
+            &[
+                0x5c, 0x4d, 0xa8, 0xa7, 0x41, 0x53, 0x99, 0x49, 0x29, 0x3d, 0x08, 0x2a, 0x13, 0x2d, 0x13, 0xb4,
+                0xc2, 0xe2, 0x13, 0xd6, 0xba, 0x5b, 0x76, 0x17, 0xb5, 0xda, 0x2c, 0xb7, 0x6c, 0xbd, 0xe9, 0x04,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x82, 0x82, 0x26, 0x32, 0x12, 0xc6, 0x09, 0xd9, 0xea, 0x2a, 0x6e, 0x3e, 0x17, 0x2d, 0xe2, 0x38,
+                0xd8, 0xc3, 0x9c, 0xab, 0xd5, 0xac, 0x1c, 0xa1, 0x06, 0x46, 0xe2, 0x3f, 0xd5, 0xf5, 0x15, 0x08,
+            ],
+            &[
+                0x11, 0xf8, 0xa8, 0x09, 0x85, 0x57, 0xdf, 0xe4, 0x5e, 0x82, 0x56, 0xe8, 0x30, 0xb6, 0x0a, 0xce,
+                0x62, 0xd6, 0x13, 0xac, 0x2f, 0x7b, 0x17, 0xbe, 0xd3, 0x1b, 0x6e, 0xaf, 0xf6, 0xe2, 0x6c, 0xaf,
+            ],
+        ),
+        point(
+            &[
+                0x46, 0x53, 0x70, 0xb2, 0x87, 0xa7, 0x9f, 0xf3, 0x90, 0x5a, 0x85, 0x7a, 0x9c, 0xf9, 0x18, 0xd5,
+                0x0a, 0xdb, 0xc9, 0x68, 0xd9, 0xe1, 0x59, 0xd0, 0x92, 0x6e, 0x2c, 0x00, 0xef, 0x34, 0xa2, 0x4d,
+            ],
+            &[
+                0x35, 0xe5, 0x31, 0xb3, 0x83, 0x68, 0xc0, 0x82, 0xa4, 0xaf, 0x8b, 0xda, 0xfd, 0xee, 0xc2, 0xc1,
+                0x58, 0x8e, 0x09, 0xb2, 0x15, 0xd3, 0x7a, 0x10, 0xa2, 0xf8, 0xfb, 0x20, 0xb3, 0x38, 0x87, 0xf4,
+            ],
+        ),
+        point(
+            &[
+                0x82, 0x62, 0xcf, 0x2f, 0xf0, 0x79, 0x9c, 0x4c, 0x0d, 0x9a, 0x30, 0xf8, 0xac, 0xa9, 0x8c, 0xa0,
+                0x09, 0x80, 0x91, 0x91, 0xa3, 0xc7, 0xe1, 0x84, 0xfc, 0xfc, 0x0c, 0xb9, 0xe5, 0x7e, 0x8d, 0xfa,
+            ],
+            &[
+                0x83, 0xfd, 0x95, 0xe2, 0x09, 0x10, 0x9e, 0x4e, 0x66, 0xfe, 0xe2, 0x2e, 0xc5, 0xb3, 0x4f, 0x34,
+                0x57, 0xb6, 0xed, 0x33, 0x2b, 0x14, 0xc4, 0x78, 0x35, 0xcf, 0xf8, 0xd8, 0xfb, 0xac, 0x37, 0x6a,
+            ],
+        ),
+        point(
+            &[
+                0x24, 0x1f, 0xeb, 0xb8, 0xe2, 0x3c, 0xbd, 0x77, 0xd6, 0x64, 0xa1, 0x8f, 0x66, 0xad, 0x62, 0x40,
+                0xaa, 0xec, 0x6e, 0xcd, 0xc8, 0x13, 0xb0, 0x88, 0xd5, 0xb9, 0x01, 0xb2, 0xe2, 0x85, 0x13, 0x1f,
+            ],
+            &[
+                0x51, 0x33, 0x78, 0xd9, 0xff, 0x94, 0xf8, 0xd3, 0xd6, 0xc4, 0x20, 0xbd, 0x13, 0x98, 0x1d, 0xf8,
+                0xcd, 0x50, 0xfd, 0x0f, 0xbd, 0x0c, 0xb5, 0xaf, 0xab, 0xb3, 0xe6, 0x6f, 0x27, 0x50, 0x02, 0x6d,
+            ],
+        ),
+        point(
+            &[
+                0x19, 0x82, 0x5c, 0x8b, 0x1d, 0xa0, 0xdd, 0xd5, 0x16, 0x81, 0x05, 0xb2, 0x4c, 0xe9, 0x9c, 0x87,
+                0x7c, 0xa4, 0x1b, 0xd4, 0x7b, 0x73, 0x4b, 0x94, 0x90, 0x52, 0xe4, 0x8b, 0x02, 0x6b, 0xdb, 0x6f,
+            ],
+            &[
+                0x62, 0x94, 0x31, 0x0f, 0x0d, 0x4c, 0x87, 0x8f, 0x32, 0x02, 0x61, 0xcc, 0x94, 0xf5, 0x9f, 0x6c,
+                0xeb, 0xe9, 0xee, 0xcc, 0x8c, 0xf6, 0xd3, 0xa6, 0xb5, 0xdf, 0x70, 0x84, 0xc4, 0x9c, 0xfc, 0x9b,
+            ],
+        ),
+        point(
+            &[
+                0x16, 0x53, 0xa8, 0xa4, 0x8d, 0x2c, 0x23, 0x6d, 0xc6, 0x0d, 0xd3, 0x1a, 0x2b, 0x67, 0x17, 0x80,
+                0x49, 0x98, 0xc4, 0xbe, 0xab, 0xc2, 0x88, 0xd9, 0xd1, 0x7c, 0xc1, 0xf2, 0x7c, 0x70, 0x62, 0x0c,
+            ],
+            &[
+                0x03, 0x38, 0x29, 0x09, 0x35, 0xaf, 0x7f, 0x7a, 0xea, 0xfa, 0x99, 0x47, 0x4e, 0xfa, 0x70, 0x1c,
+                0x01, 0x2a, 0xf7, 0x48, 0xdf, 0xd3, 0xdc, 0x52, 0x6c, 0xa2, 0xe8, 0x1d, 0x31, 0x5b, 0x32, 0xcd,
+            ],
+        ),
+        point(
+            &[
+                0x6f, 0x12, 0xd8, 0x6c, 0x11, 0x60, 0x19, 0x14, 0x43, 0xc5, 0xf5, 0x6e, 0xc6, 0xc2, 0x99, 0x9e,
+                0xdf, 0xa5, 0x8e, 0x34, 0x5e, 0x15, 0x34, 0xe6, 0x50, 0xed, 0x09, 0x52, 0x3d, 0x82, 0x82, 0x4c,
+            ],
+            &[
+                0x5c, 0x4f, 0xf7, 0xf4, 0x4a, 0xb3, 0xbf, 0xa0, 0x87, 0x59, 0x94, 0xf3, 0xfd, 0x62, 0x37, 0x69,
+                0x39, 0x1c, 0x92, 0x41, 0x08, 0x54, 0xbc, 0x5b, 0x85, 0x79, 0xc3, 0x48, 0x06, 0xeb, 0x34, 0xd0,
+            ],
+        ),
+        point(
+            &[
+                0x5d, 0x1b, 0xdb, 0x4e, 0xa1, 0x72, 0xfa, 0x79, 0xfc, 0xe4, 0xcc, 0x29, 0x83, 0xd8, 0xf8, 0xd9,
+                0xfc, 0x31, 0x8b, 0x85, 0xf4, 0x23, 0xde, 0x0d, 0xed, 0xcb, 0x63, 0x06, 0x9b, 0x92, 0x04, 0x71,
+            ],
+            &[
+                0x28, 0x43, 0x82, 0x67, 0x79, 0x37, 0x9e, 0x2e, 0x79, 0x4b, 0xb9, 0x94, 0x38, 0xa2, 0x26, 0x56,
+                0x79, 0xeb, 0x1e, 0x99, 0x96, 0xc5, 0x6e, 0x7b, 0x70, 0x33, 0x06, 0x66, 0xf7, 0xb8, 0x31, 0x03,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x36, 0x3d, 0x90, 0xd4, 0x47, 0xb0, 0x0c, 0x9c, 0x99, 0xce, 0xac, 0x05, 0xb6, 0x26, 0x2e, 0xe0,
+                0x53, 0x44, 0x1c, 0x7e, 0x55, 0x55, 0x2f, 0xfe, 0x52, 0x6b, 0xad, 0x8f, 0x83, 0xff, 0x46, 0x40,
+            ],
+            &[
+                0x04, 0xe2, 0x73, 0xad, 0xfc, 0x73, 0x22, 0x21, 0x95, 0x3b, 0x44, 0x53, 0x97, 0xf3, 0x36, 0x31,
+                0x45, 0xb9, 0xa8, 0x90, 0x08, 0x19, 0x9e, 0xcb, 0x62, 0x00, 0x3c, 0x7f, 0x3b, 0xee, 0x9d, 0xe9,
+            ],
+        ),
+        point(
+            &[
+                0x4c, 0x1b, 0x98, 0x66, 0xed, 0x9a, 0x7e, 0x9b, 0x55, 0x39, 0x73, 0xc6, 0xc9, 0x3b, 0x02, 0xbf,
+                0x0b, 0x62, 0xfb, 0x01, 0x2e, 0xdf, 0xb5, 0x9d, 0xd2, 0x71, 0x2a, 0x5c, 0xaf, 0x92, 0xc5, 0x41,
+            ],
+            &[
+                0xc1, 0xf7, 0x92, 0xd3, 0x20, 0xbe, 0x8a, 0x0f, 0x7f, 0xbc, 0xb7, 0x53, 0xce, 0x56, 0xe6, 0x9c,
+                0xc6, 0x52, 0xea, 0xd7, 0xe4, 0x3e, 0xb1, 0xad, 0x72, 0xc4, 0xf3, 0xfd, 0xc6, 0x8f, 0xe0, 0x20,
+            ],
+        ),
+        point(
+            &[
+                0x44, 0x31, 0x40, 0x47, 0x90, 0xc5, 0xff, 0xb2, 0xba, 0x84, 0xa4, 0x40, 0xc0, 0x50, 0x94, 0x42,
+                0x6f, 0xf9, 0x5a, 0xb6, 0xea, 0xca, 0x04, 0x39, 0x4b, 0x89, 0x12, 0x16, 0xf6, 0xe5, 0x5d, 0xc8,
+            ],
+            &[
+                0x96, 0xb0, 0xc1, 0x42, 0xe6, 0x53, 0x66, 0xf8, 0xfe, 0x99, 0x83, 0x7f, 0x56, 0x42, 0xfe, 0xd7,
+                0xa6, 0x6a, 0x29, 0xb7, 0x9e, 0xaa, 0x2e, 0x50, 0x31, 0xd9, 0x44, 0xae, 0xdb, 0xe3, 0x23, 0xb3,
+            ],
+        ),
+        point(
+            &[
+                0xa4, 0x08, 0x38, 0x77, 0xba, 0x83, 0xb1, 0x2b, 0x52, 0x9a, 0x2f, 0x3c, 0x07, 0x80, 0xb5, 0x4e,
+                0x32, 0x33, 0xed, 0xbc, 0x1a, 0x28, 0xf1, 0x35, 0xe0, 0xc8, 0xf2, 0x8c, 0xbe, 0xaa, 0xf3, 0xd1,
+            ],
+            &[
+                0x40, 0xe9, 0xf6, 0x12, 0xfe, 0xef, 0xbc, 0x79, 0xb8, 0xbf, 0x83, 0xd6, 0x93, 0x61, 0xb3, 0xe2,
+                0x20, 0x01, 0xe7, 0x57, 0x6e, 0xd1, 0xef, 0x90, 0xb1, 0x2b, 0x53, 0x4d, 0xf0, 0xb2, 0x54, 0xb9,
+            ],
+        ),
+        point(
+            &[
+                0x9e, 0x22, 0xfe, 0x8d, 0x86, 0x6c, 0xa8, 0x7c, 0x12, 0x62, 0x43, 0xd5, 0xb9, 0x21, 0x08, 0x9d,
+                0xab, 0x7b, 0x7d, 0x47, 0x0a, 0x87, 0xee, 0x0a, 0xdf, 0xe9, 0x48, 0x5d, 0x70, 0x1b, 0x23, 0xa8,
+            ],
+            &[
+                0xfd, 0x2f, 0xf0, 0xe9, 0xca, 0x12, 0x2d, 0x10, 0x17, 0x7f, 0x3f, 0x02, 0x09, 0x9c, 0x15, 0x33,
+                0xc0, 0xf7, 0xc9, 0x49, 0xfb, 0x51, 0x1c, 0xec, 0xf7, 0xa4, 0x13, 0xc5, 0x08, 0x84, 0xed, 0xae,
+            ],
+        ),
+        point(
+            &[
+                0xe5, 0x38, 0x0f, 0xe8, 0x57, 0x5f, 0x26, 0xad, 0xb7, 0x92, 0x4a, 0xe0, 0xd5, 0x81, 0x38, 0xd2,
+                0x68, 0x11, 0x27, 0x76, 0xb1, 0x1b, 0xd3, 0x4b, 0x3e, 0xb5, 0xe1, 0x96, 0x33, 0xf0, 0xe9, 0xaa,
+            ],
+            &[
+                0xb9, 0x7f, 0xd8, 0x73, 0x90, 0x87, 0xb4, 0x1d, 0x5b, 0x69, 0x13, 0x63, 0x92, 0x4c, 0x8e, 0x01,
+                0x6f, 0xb9, 0x4e, 0x5d, 0xf4, 0x68, 0x31, 0x8e, 0xc4, 0xba, 0x41, 0x36, 0x40, 0x82, 0x72, 0x0f,
+            ],
+        ),
+        point(
+            &[
+                0x50, 0x8d, 0xf6, 0xd5, 0x03, 0xce, 0x2a, 0x8d, 0x2e, 0xdd, 0x69, 0xe6, 0x47, 0x05, 0x30, 0x6d,
+                0xcd, 0x5e, 0xe5, 0x1f, 0x5f, 0x5c, 0xf4, 0x75, 0xdd, 0x74, 0x08, 0xbf, 0x07, 0x1a, 0x70, 0xe4,
+            ],
+            &[
+                0x15, 0x4c, 0x43, 0x9b, 0x93, 0x3b, 0xc4, 0x2d, 0x77, 0x73, 0x04, 0xaa, 0x73, 0x3e, 0x49, 0xc5,
+                0x4e, 0xc0, 0x32, 0x28, 0xee, 0x8a, 0xad, 0xfe, 0xdf, 0x2e, 0x5b, 0xf7, 0x29, 0x95, 0x09, 0x84,
+            ],
+        ),
+        point(
+            &[
+                0xa8, 0x04, 0xc6, 0x41, 0xd2, 0x8c, 0xc0, 0xb5, 0x3a, 0x4e, 0x3e, 0x1a, 0x2f, 0x56, 0xc8, 0x6f,
+                0x6e, 0x0d, 0x88, 0x0a, 0x45, 0x42, 0x03, 0xb9, 0x8c, 0xd3, 0xdb, 0x5a, 0x79, 0x40, 0xd3, 0x3a,
+            ],
+            &[
+                0x95, 0xbe, 0x83, 0x25, 0x2b, 0x2f, 0xa6, 0xd0, 0x3d, 0xec, 0x28, 0x42, 0xc1, 0x60, 0x47, 0xe8,
+                0x1a, 0xf1, 0x8c, 0xa8, 0x9c, 0xf7, 0x36, 0xa9, 0x43, 0xce, 0x95, 0xfa, 0x6d, 0x46, 0x96, 0x7a,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x72, 0x3c, 0xba, 0xa6, 0xe5, 0xdb, 0x99, 0x6d, 0x6b, 0xf7, 0x71, 0xc0, 0x0b, 0xd5, 0x48, 0xc7,
+                0xb7, 0x00, 0xdb, 0xff, 0xa6, 0xc0, 0xe7, 0x7b, 0xcb, 0x61, 0x15, 0x92, 0x52, 0x32, 0xfc, 0xda,
+            ],
+            &[
+                0x96, 0xe8, 0x67, 0xb5, 0x59, 0x5c, 0xc4, 0x98, 0xa9, 0x21, 0x13, 0x74, 0x88, 0x82, 0x4d, 0x6e,
+                0x26, 0x60, 0xa0, 0x65, 0x37, 0x79, 0x49, 0x48, 0x01, 0xdc, 0x06, 0x9d, 0x9e, 0xb3, 0x9f, 0x5f,
+            ],
+        ),
+        point(
+            &[
+                0x57, 0xef, 0xa7, 0x86, 0x43, 0x7b, 0x74, 0x4d, 0x34, 0x3d, 0x7d, 0xc4, 0x57, 0x73, 0xa3, 0xc6,
+                0x2d, 0x24, 0x0a, 0x43, 0x07, 0x98, 0x49, 0x07, 0x1f, 0xd3, 0x83, 0xd6, 0x0c, 0xa0, 0x30, 0xd5,
+            ],
+            &[
+                0xd7, 0x12, 0xdb, 0x0b, 0xd1, 0xb4, 0x85, 0x18, 0x89, 0x36, 0x27, 0xc9, 0x28, 0xde, 0x03, 0xec,
+                0x68, 0x9b, 0x6d, 0x2a, 0xe5, 0xe9, 0x97, 0x4a, 0xb0, 0x7a, 0xb4, 0x42, 0x74, 0xb0, 0x2f, 0x9e,
+            ],
+        ),
+        point(
+            &[
+                0x6d, 0xde, 0x9c, 0xf3, 0x17, 0xaa, 0xca, 0xd4, 0x00, 0xc6, 0x27, 0x32, 0x12, 0x18, 0x1f, 0xcb,
+                0x57, 0x5a, 0x22, 0x4b, 0x69, 0xd0, 0x21, 0x13, 0x25, 0x67, 0xe0, 0x9e, 0x80, 0x63, 0x3c, 0xb1,
+            ],
+            &[
+                0x91, 0x88, 0xfb, 0xe7, 0xa7, 0x07, 0xe4, 0x1d, 0x5c, 0x99, 0xef, 0x86, 0xa1, 0xba, 0x66, 0xa8,
+                0x80, 0xb2, 0x7f, 0xda, 0xcf, 0x85, 0x9e, 0xf3, 0x57, 0xdd, 0x49, 0xaa, 0x67, 0xce, 0x6b, 0x34,
+            ],
+        ),
+        point(
+            &[
+                0x26, 0x4b, 0xbd, 0x43, 0x6a, 0x28, 0xbc, 0x42, 0xa2, 0xdf, 0x7e, 0x9c, 0xd5, 0x22, 0x6c, 0xb9,
+                0x10, 0x80, 0x57, 0x7e, 0x32, 0x7b, 0x01, 0x2a, 0x7f, 0xaf, 0xc7, 0x77, 0x0c, 0x58, 0x4d, 0xd5,
+            ],
+            &[
+                0xd8, 0x7c, 0x6f, 0xa9, 0x4e, 0xe0, 0x93, 0xb4, 0xd4, 0xf7, 0x5c, 0xe2, 0x4c, 0x33, 0xbe, 0x22,
+                0x6a, 0x11, 0x82, 0x43, 0x71, 0x7b, 0x8d, 0x8d, 0xe6, 0x12, 0x27, 0x93, 0x77, 0x04, 0xab, 0x11,
+            ],
+        ),
+        point(
+            &[
+                0x48, 0x6f, 0xa7, 0x2c, 0xd5, 0xb5, 0xcd, 0xe8, 0x13, 0xc4, 0xbb, 0x7f, 0x8e, 0x47, 0xb8, 0x50,
+                0x08, 0x5a, 0x0f, 0x71, 0x15, 0xf1, 0x25, 0x22, 0x41, 0x9a, 0x51, 0x8d, 0x29, 0x33, 0xf3, 0xc5,
+            ],
+            &[
+                0x62, 0xe1, 0x23, 0x19, 0xf5, 0x6b, 0xdd, 0x43, 0xe4, 0x8c, 0x48, 0xba, 0xec, 0xc8, 0xf1, 0x9f,
+                0x62, 0xd9, 0xb7, 0x83, 0xcf, 0x0f, 0x23, 0xb7, 0x9a, 0xd4, 0xa7, 0x1a, 0xca, 0xfb, 0x0f, 0x53,
+            ],
+        ),
+        point(
+            &[
+                0x97, 0xd0, 0x64, 0xf0, 0xfc, 0x69, 0xa1, 0x22, 0x2c, 0x21, 0xf6, 0x2e, 0x0e, 0xc9, 0x70, 0xe9,
+                0x40, 0x90, 0x8a, 0xb8, 0x19, 0xbb, 0xfa, 0xce, 0x4d, 0x0b, 0xd7, 0x6a, 0x44, 0xe5, 0x46, 0x7d,
+            ],
+            &[
+                0x89, 0x97, 0x4f, 0x2e, 0xd3, 0x34, 0x02, 0xcc, 0x03, 0xf7, 0xc6, 0x6f, 0xa8, 0x50, 0x86, 0x1f,
+                0xda, 0x5f, 0xb3, 0xb8, 0x54, 0xf1, 0x7c, 0xcd, 0x79, 0x73, 0x00, 0xfd, 0x1e, 0x9c, 0xb3, 0xfa,
+            ],
+        ),
+        point(
+            &[
+                0x24, 0x79, 0x69, 0x74, 0xa8, 0x94, 0xaf, 0x4f, 0xb6, 0x64, 0xff, 0x27, 0xb7, 0x6a, 0x53, 0x03,
+                0x67, 0x73, 0x75, 0xfb, 0xe6, 0xf1, 0x22, 0x04, 0xeb, 0xd5, 0x94, 0x22, 0x5e, 0x99, 0xf7, 0x28,
+            ],
+            &[
+                0xe3, 0xd7, 0x8d, 0x44, 0x68, 0x8f, 0x30, 0x01, 0xec, 0x52, 0xe8, 0x7e, 0x7d, 0x8d, 0x66, 0x4a,
+                0x5a, 0xdb, 0xf3, 0xc0, 0x95, 0x75, 0xa2, 0xd8, 0x37, 0xa0, 0x05, 0x16, 0xeb, 0xaa, 0xeb, 0xff,
+            ],
+        ),
+        point(
+            &[
+                0xa9, 0x4c, 0x65, 0x24, 0xbd, 0x40, 0xd2, 0xbb, 0xda, 0xc8, 0x5c, 0x05, 0x62, 0x36, 0xa7, 0x9d,
+                0xa7, 0x8b, 0xc6, 0x1f, 0xd5, 0xbd, 0xec, 0x9d, 0x2b, 0xf2, 0x6b, 0xd8, 0x4b, 0x24, 0x38, 0xe8,
+            ],
+            &[
+                0xb5, 0x20, 0x1f, 0xd9, 0x92, 0xf9, 0x62, 0x80, 0xfd, 0x79, 0x21, 0x95, 0x05, 0x01, 0x9e, 0x3a,
+                0x7e, 0x5d, 0x3c, 0x60, 0xa0, 0xe3, 0x9b, 0x2b, 0xc2, 0xe2, 0xc8, 0xdb, 0xf1, 0x86, 0x61, 0xf4,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x10, 0x0f, 0x44, 0xda, 0x69, 0x6e, 0x71, 0x67, 0x27, 0x91, 0xd0, 0xa0, 0x9b, 0x7b, 0xde, 0x45,
+                0x9f, 0x12, 0x15, 0xa2, 0x9b, 0x3c, 0x03, 0xbf, 0xef, 0xd7, 0x83, 0x5b, 0x39, 0xa4, 0x8d, 0xb0,
+            ],
+            &[
+                0xcd, 0xd9, 0xe1, 0x31, 0x92, 0xa0, 0x0b, 0x77, 0x2e, 0xc8, 0xf3, 0x30, 0x0c, 0x09, 0x06, 0x66,
+                0xb7, 0xff, 0x4a, 0x18, 0xff, 0x51, 0x95, 0xac, 0x0f, 0xbd, 0x5c, 0xd6, 0x2b, 0xc6, 0x5a, 0x09,
+            ],
+        ),
+        point(
+            &[
+                0x8c, 0x09, 0x89, 0xf2, 0xce, 0xb5, 0xc7, 0x71, 0xa8, 0x41, 0x5d, 0xff, 0x2b, 0x4c, 0x41, 0x99,
+                0xd8, 0xd9, 0xc8, 0xf9, 0x23, 0x7d, 0x08, 0x08, 0x4b, 0x05, 0x28, 0x4f, 0x1e, 0x4d, 0xf7, 0x06,
+            ],
+            &[
+                0xfb, 0x4d, 0xbd, 0x04, 0x4f, 0x43, 0x20, 0x34, 0xff, 0xd2, 0x17, 0x2c, 0xb9, 0xdc, 0x96, 0x6c,
+                0x60, 0xde, 0x6b, 0xf5, 0x15, 0x65, 0x11, 0xaa, 0x73, 0x6a, 0xc5, 0xa3, 0x5d, 0x72, 0xfa, 0x98,
+            ],
+        ),
+        point(
+            &[
+                0x10, 0xe9, 0x0e, 0x2e, 0x51, 0xee, 0xad, 0xc9, 0xed, 0x85, 0x8e, 0xe9, 0xce, 0xd7, 0xca, 0x8d,
+                0x92, 0x75, 0x02, 0x8e, 0x46, 0x5a, 0x2e, 0xe6, 0x9c, 0xb9, 0xa1, 0x34, 0x95, 0xbc, 0x15, 0xb4,
+            ],
+            &[
+                0xc6, 0x8a, 0x37, 0x03, 0x80, 0xd5, 0xe0, 0x42, 0x4d, 0x57, 0xa8, 0xc6, 0x16, 0xad, 0x1f, 0x75,
+                0x4c, 0xa5, 0x89, 0x63, 0x02, 0xbb, 0x6a, 0x88, 0x34, 0xeb, 0xe6, 0x09, 0x58, 0xaa, 0x25, 0x8d,
+            ],
+        ),
+        point(
+            &[
+                0xfb, 0x8f, 0x15, 0x3c, 0x5e, 0x26, 0x67, 0x04, 0xc4, 0xa4, 0x81, 0x74, 0x32, 0x62, 0xc0, 0x25,
+                0x9c, 0x52, 0x85, 0x39, 0xbc, 0x95, 0xbc, 0x1b, 0xb1, 0xe6, 0x3c, 0x33, 0xdc, 0x47, 0xbf, 0xfd,
+            ],
+            &[
+                0x6c, 0xa2, 0x7a, 0x9d, 0xc5, 0xe0, 0x62, 0x18, 0x16, 0xfa, 0x11, 0xd9, 0xb4, 0xbc, 0xcd, 0x53,
+                0x1d, 0xde, 0x13, 0x89, 0xac, 0x54, 0x26, 0x13, 0x09, 0x0a, 0x45, 0xdd, 0xd9, 0x49, 0xb0, 0x95,
+            ],
+        ),
+        point(
+            &[
+                0xf7, 0x42, 0x2f, 0x42, 0xda, 0x54, 0x16, 0x38, 0x45, 0x75, 0xb9, 0x0b, 0x71, 0x4b, 0x7d, 0xcd,
+                0xa3, 0x77, 0xa3, 0xcc, 0x05, 0x3d, 0x33, 0x18, 0x2d, 0xd3, 0xfc, 0x30, 0x3f, 0xe7, 0x52, 0x69,
+            ],
+            &[
+                0x40, 0x6c, 0x2f, 0x1a, 0x33, 0x13, 0x09, 0x3f, 0xd1, 0x8c, 0xe7, 0xdc, 0xb9, 0xf6, 0x35, 0x97,
+                0x7f, 0xb3, 0xa2, 0x37, 0xf4, 0xa3, 0x98, 0xe0, 0x18, 0x98, 0x0e, 0x87, 0x17, 0xe4, 0x9b, 0xd5,
+            ],
+        ),
+        point(
+            &[
+                0xb6, 0xb1, 0x5a, 0x68, 0xa6, 0x14, 0xcc, 0xa5, 0xb1, 0x7c, 0x9b, 0xe7, 0x00, 0x2f, 0xf9, 0xc3,
+                0x4d, 0x62, 0x51, 0x58, 0xc6, 0x5a, 0x9c, 0x74, 0x0f, 0x62, 0xab, 0xc8, 0x7a, 0x1c, 0x0a, 0x80,
+            ],
+            &[
+                0xfa, 0xe6, 0x2e, 0x14, 0xd6, 0xcd, 0xb6, 0x1e, 0xa5, 0x4e, 0x22, 0x3e, 0xef, 0x65, 0x36, 0xd4,
+                0x9c, 0x9a, 0x12, 0xb3, 0x08, 0x2e, 0x16, 0xee, 0xb6, 0xcd, 0x01, 0x10, 0x41, 0xce, 0x0a, 0x03,
+            ],
+        ),
+        point(
+            &[
+                0x2d, 0x8c, 0xad, 0x04, 0x17, 0xd4, 0x3c, 0xff, 0x72, 0x87, 0x9a, 0x55, 0x82, 0xd5, 0xde, 0xbb,
+                0xed, 0xb8, 0xe7, 0x71, 0xd3, 0x1c, 0xf4, 0x2a, 0x65, 0x3b, 0x66, 0x96, 0xf5, 0xa7, 0x17, 0x5f,
+            ],
+            &[
+                0xc7, 0x3f, 0x3b, 0x83, 0x31, 0x8c, 0xa9, 0x4a, 0x7b, 0xb2, 0x32, 0xfa, 0x61, 0x2c, 0x9d, 0x37,
+                0x7a, 0x84, 0x6b, 0xfd, 0x9c, 0xb5, 0xe5, 0xe0, 0xcf, 0x37, 0xbb, 0x91, 0xbb, 0x9d, 0x59, 0x2a,
+            ],
+        ),
+        point(
+            &[
+                0xe7, 0x47, 0x33, 0x3f, 0xd7, 0x5d, 0x51, 0x75, 0x5a, 0x0c, 0xc9, 0xf0, 0xa7, 0x28, 0x70, 0x84,
+                0x65, 0xa0, 0x2c, 0x58, 0x77, 0x37, 0xa7, 0xb8, 0xb8, 0xfa, 0x1b, 0x8b, 0x4b, 0xb2, 0x62, 0x9a,
+            ],
+            &[
+                0xf2, 0xaf, 0xfe, 0x01, 0x45, 0x07, 0x0c, 0x11, 0x4c, 0xc4, 0x36, 0x03, 0x80, 0x4c, 0x25, 0x81,
+                0xc8, 0x83, 0x76, 0xaa, 0x6e, 0x1a, 0x96, 0x9a, 0x9f, 0x8d, 0x96, 0x1a, 0x69, 0x46, 0xf6, 0xd6,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xfe, 0xea, 0x6c, 0xae, 0x46, 0xd5, 0x5b, 0x53, 0x0a, 0xc2, 0x83, 0x9f, 0x14, 0x3b, 0xd7, 0xec,
+                0x5c, 0xf8, 0xb2, 0x66, 0xa4, 0x1d, 0x6a, 0xf5, 0x2d, 0x5e, 0x68, 0x8d, 0x90, 0x94, 0x69, 0x6d,
+            ],
+            &[
+                0xe5, 0x7c, 0x6b, 0x6c, 0x97, 0xdc, 0xe1, 0xba, 0xb0, 0x6e, 0x4e, 0x12, 0xbf, 0x3e, 0xcd, 0x5c,
+                0x98, 0x1c, 0x89, 0x57, 0xcc, 0x41, 0x44, 0x2d, 0x31, 0x55, 0xde, 0xbf, 0x18, 0x09, 0x00, 0x88,
+            ],
+        ),
+        point(
+            &[
+                0x4d, 0x00, 0x0b, 0x62, 0x1a, 0xdb, 0x87, 0xe1, 0xc5, 0x32, 0x61, 0xaf, 0x9d, 0xb2, 0xe1, 0x79,
+                0x14, 0x1e, 0xca, 0xe0, 0xb3, 0x31, 0xa1, 0x87, 0x0a, 0xa4, 0x04, 0x0a, 0xee, 0x75, 0x2b, 0x08,
+            ],
+            &[
+                0x6a, 0x0d, 0x5b, 0x8f, 0x18, 0xe0, 0xd2, 0x55, 0xcb, 0x6d, 0x82, 0x55, 0x82, 0xd9, 0x72, 0xcc,
+                0xcb, 0x7d, 0xf5, 0xf1, 0x19, 0xc7, 0x29, 0x3a, 0x3e, 0x72, 0x85, 0x1f, 0x48, 0x30, 0x2c, 0xea,
+            ],
+        ),
+        point(
+            &[
+                0x50, 0x84, 0xb4, 0x1b, 0xac, 0xf4, 0x50, 0x8b, 0x34, 0x86, 0x7a, 0xaa, 0x2c, 0xf5, 0xa1, 0x2d,
+                0x5e, 0xc4, 0xba, 0x38, 0xc9, 0xb0, 0x26, 0x56, 0x07, 0x93, 0x61, 0xbb, 0x48, 0xdf, 0xd5, 0x87,
+            ],
+            &[
+                0x34, 0xa9, 0x63, 0x1a, 0x1d, 0x98, 0x0d, 0x31, 0x61, 0x9a, 0xa6, 0xc8, 0x55, 0x29, 0x27, 0x47,
+                0x5d, 0xb6, 0xf5, 0x60, 0x68, 0x91, 0xf5, 0x60, 0x6e, 0x79, 0xe9, 0x7f, 0x91, 0x47, 0x0e, 0x89,
+            ],
+        ),
+        point(
+            &[
+                0x71, 0xf5, 0x70, 0xca, 0x20, 0x3d, 0xa0, 0x5d, 0xd6, 0xaa, 0x26, 0x21, 0x14, 0x71, 0x71, 0x28,
+                0xd6, 0x57, 0xa0, 0x40, 0x3e, 0x1f, 0x1b, 0x77, 0xf8, 0x99, 0x62, 0xfd, 0x47, 0x5c, 0x58, 0xef,
+            ],
+            &[
+                0xeb, 0x42, 0x41, 0x5b, 0x95, 0xdc, 0x88, 0x0d, 0xd2, 0x55, 0x57, 0x34, 0x5b, 0xc9, 0x5b, 0x8d,
+                0xf2, 0x44, 0x5d, 0x00, 0xc3, 0x36, 0x3e, 0x7d, 0xf8, 0x64, 0x9a, 0x72, 0xd3, 0x5d, 0x42, 0x0e,
+            ],
+        ),
+        point(
+            &[
+                0x4f, 0x14, 0xc0, 0x3e, 0x06, 0x42, 0xd5, 0xea, 0x53, 0xfb, 0x3f, 0x1f, 0xd1, 0x8d, 0x71, 0x28,
+                0xc8, 0x0c, 0x29, 0x76, 0x7c, 0xf3, 0x0a, 0x12, 0xd0, 0x82, 0x32, 0x61, 0x7a, 0xb3, 0x4c, 0xc6,
+            ],
+            &[
+                0x7b, 0x53, 0xd0, 0xa8, 0xca, 0xa4, 0xe8, 0x94, 0xc6, 0x53, 0xa7, 0x0f, 0x43, 0xa6, 0x25, 0x40,
+                0xb9, 0xde, 0x3c, 0xce, 0x8e, 0x81, 0xdd, 0x02, 0x25, 0xec, 0x25, 0x2f, 0x98, 0x7e, 0x68, 0x1f,
+            ],
+        ),
+        point(
+            &[
+                0xa4, 0x9e, 0xd1, 0x0e, 0xaa, 0xab, 0x93, 0x23, 0x3a, 0x5f, 0x48, 0x5d, 0x4b, 0xd1, 0x8c, 0x06,
+                0x28, 0xab, 0x26, 0x29, 0xf0, 0xb8, 0xc3, 0xdb, 0x4d, 0x05, 0x95, 0x6d, 0x6c, 0x95, 0x3f, 0xa9,
+            ],
+            &[
+                0xcc, 0x72, 0xb8, 0x94, 0x66, 0x0f, 0x53, 0x98, 0xe0, 0x34, 0x76, 0xc0, 0xa0, 0xdf, 0xdd, 0xfb,
+                0x5a, 0xe8, 0x75, 0x34, 0x96, 0x8e, 0x18, 0x1b, 0x67, 0xb2, 0xbd, 0x22, 0x46, 0xfb, 0x4c, 0x72,
+            ],
+        ),
+        point(
+            &[
+                0xa7, 0x4d, 0xb8, 0x7e, 0x49, 0xc7, 0x9e, 0xd1, 0xd4, 0x14, 0x36, 0x09, 0x5e, 0xdd, 0xd3, 0x63,
+                0xe8, 0x1c, 0xf1, 0x41, 0xdb, 0x24, 0x44, 0xf8, 0x34, 0x27, 0x71, 0x01, 0x12, 0x41, 0xd9, 0x0d,
+            ],
+            &[
+                0xf7, 0x86, 0x91, 0xcd, 0xaf, 0x23, 0xee, 0xf3, 0x27, 0xfd, 0xd0, 0x8a, 0x58, 0x81, 0x71, 0xc8,
+                0xa9, 0xee, 0x50, 0x93, 0x44, 0xa0, 0xa3, 0x13, 0xf3, 0x25, 0x18, 0xb8, 0x3f, 0x7a, 0xda, 0xd4,
+            ],
+        ),
+        point(
+            &[
+                0xa2, 0xb7, 0xb3, 0x62, 0x9f, 0x7b, 0xd2, 0x53, 0xb7, 0xd2, 0x82, 0xb5, 0xc2, 0x1d, 0xa0, 0x14,
+                0x46, 0xb4, 0x82, 0x1d, 0xc6, 0x5e, 0x76, 0x51, 0x60, 0x48, 0xb0, 0x60, 0x43, 0xff, 0x83, 0x59,
+            ],
+            &[
+                0x69, 0x30, 0x38, 0x94, 0x16, 0x95, 0x12, 0x2d, 0x57, 0xa9, 0x37, 0xa3, 0xf7, 0x1e, 0x29, 0xc9,
+                0x10, 0xd1, 0x08, 0x35, 0x04, 0x6f, 0x38, 0x35, 0xa2, 0x39, 0x7f, 0xec, 0xfe, 0x86, 0xfe, 0xc2,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x53, 0x90, 0x4f, 0xaa, 0x0b, 0x33, 0x4c, 0xdd, 0xa6, 0xe0, 0x00, 0x93, 0x5e, 0xf2, 0x21, 0x51,
+                0xec, 0x08, 0xd0, 0xf7, 0xbb, 0x11, 0x06, 0x9f, 0x57, 0x54, 0x5c, 0xcc, 0x1a, 0x37, 0xb7, 0xc0,
+            ],
+            &[
+                0x5b, 0xc0, 0x87, 0xd0, 0xbc, 0x80, 0x10, 0x6d, 0x88, 0xc9, 0xec, 0xca, 0xc2, 0x0d, 0x3c, 0x1c,
+                0x13, 0x99, 0x99, 0x81, 0xe1, 0x44, 0x34, 0x69, 0x9d, 0xcb, 0x09, 0x6b, 0x02, 0x27, 0x71, 0xc8,
+            ],
+        ),
+        point(
+            &[
+                0x01, 0xa5, 0x75, 0xaf, 0x9d, 0x41, 0x46, 0x75, 0x3c, 0xf9, 0x91, 0x19, 0x63, 0x16, 0x99, 0x5d,
+                0x2a, 0x6e, 0xe7, 0xaa, 0xad, 0x0f, 0x85, 0xad, 0x57, 0xcd, 0x0f, 0x1f, 0x38, 0xa4, 0x7c, 0xa9,
+            ],
+            &[
+                0x30, 0x38, 0xf1, 0xcb, 0x8a, 0xb2, 0x0d, 0xc3, 0xcc, 0x55, 0xfc, 0x52, 0xe1, 0xbb, 0x86, 0x98,
+                0xbd, 0xb9, 0x3c, 0x5d, 0x9f, 0x4d, 0x7e, 0xa6, 0x67, 0xc5, 0xdf, 0x2e, 0x77, 0xeb, 0xcd, 0xb7,
+            ],
+        ),
+        point(
+            &[
+                0x67, 0x37, 0x24, 0xfd, 0x24, 0xbc, 0x73, 0x18, 0x96, 0xb7, 0x69, 0xcc, 0x6e, 0x47, 0x9b, 0x89,
+                0x74, 0x2e, 0xf5, 0x57, 0x61, 0x5f, 0x8a, 0x67, 0x71, 0xac, 0x42, 0xfe, 0x48, 0xa2, 0x05, 0x0e,
+            ],
+            &[
+                0xe4, 0xcf, 0x82, 0x57, 0x89, 0x6a, 0x4a, 0x20, 0x20, 0x34, 0x82, 0xc0, 0x9a, 0x88, 0x6b, 0x6d,
+                0xbe, 0x6b, 0xac, 0xfd, 0x43, 0x34, 0x9c, 0xc2, 0xb9, 0x0c, 0x9a, 0x49, 0x06, 0x1d, 0x3d, 0x70,
+            ],
+        ),
+        point(
+            &[
+                0xf5, 0xf0, 0xe0, 0x43, 0x76, 0x21, 0xd4, 0x39, 0xca, 0x71, 0xf5, 0xc1, 0xb7, 0x61, 0x55, 0xd6,
+                0xd3, 0xa6, 0x1a, 0x83, 0xd3, 0xc2, 0x0c, 0x6e, 0xe3, 0x09, 0xd7, 0x55, 0xe3, 0x15, 0x56, 0x5b,
+            ],
+            &[
+                0x6b, 0x9f, 0x4e, 0x62, 0xbe, 0x5a, 0x05, 0x2b, 0xf6, 0x21, 0x89, 0x16, 0x0d, 0xf7, 0x10, 0x1a,
+                0xa5, 0xbf, 0x61, 0xbf, 0x3e, 0xd7, 0xe4, 0x0a, 0x67, 0x84, 0x30, 0xaf, 0xdd, 0x2e, 0xcc, 0x82,
+            ],
+        ),
+        point(
+            &[
+                0x43, 0x66, 0xef, 0xa4, 0x72, 0xdf, 0x4c, 0x30, 0x9e, 0x59, 0x8a, 0x63, 0x1f, 0x61, 0x66, 0xa8,
+                0xe0, 0x3a, 0xf5, 0x32, 0x87, 0x26, 0x1c, 0x66, 0xcb, 0xf6, 0xe4, 0x83, 0x82, 0xde, 0x63, 0xbf,
+            ],
+            &[
+                0x2e, 0x7d, 0xd9, 0x09, 0xbe, 0xe2, 0xd7, 0xce, 0x2e, 0xe2, 0x53, 0x7e, 0x13, 0x02, 0x68, 0xea,
+                0xf3, 0x3b, 0x0c, 0x52, 0x5a, 0xaa, 0x6d, 0x6b, 0x02, 0xc6, 0xa4, 0x08, 0xe1, 0x79, 0x24, 0xcd,
+            ],
+        ),
+        point(
+            &[
+                0x99, 0x5c, 0xa7, 0xf3, 0x70, 0x81, 0xb8, 0xdc, 0x90, 0x5a, 0x47, 0x33, 0x62, 0x09, 0xed, 0x9f,
+                0x6b, 0x50, 0xe9, 0x05, 0x26, 0x0d, 0x6a, 0xfe, 0x34, 0xcf, 0x60, 0x1f, 0xa9, 0xa7, 0x81, 0x79,
+            ],
+            &[
+                0xd9, 0xa0, 0x59, 0xe9, 0x55, 0x53, 0x57, 0x3a, 0xb5, 0xe3, 0x4d, 0x88, 0x04, 0x92, 0x86, 0x3a,
+                0xbb, 0xcc, 0x85, 0x56, 0x9a, 0xb6, 0x4e, 0x52, 0x93, 0x1f, 0xbb, 0x4c, 0xaf, 0xec, 0x1f, 0x47,
+            ],
+        ),
+        point(
+            &[
+                0x7b, 0xd7, 0x53, 0x62, 0x79, 0x91, 0xab, 0x1f, 0x97, 0xbe, 0x55, 0x69, 0x66, 0x7a, 0xa7, 0x5f,
+                0xd1, 0xf0, 0x2d, 0xe9, 0x07, 0xc9, 0x52, 0x5e, 0xf1, 0x05, 0x27, 0xff, 0x06, 0xf9, 0x61, 0x90,
+            ],
+            &[
+                0x83, 0x36, 0xf2, 0xb3, 0xdb, 0xba, 0x63, 0x09, 0xd5, 0xed, 0x64, 0x74, 0x94, 0x38, 0x27, 0xd6,
+                0xce, 0x0f, 0xcc, 0x5c, 0x1e, 0x0e, 0xa6, 0x95, 0xa3, 0xd1, 0x72, 0x04, 0xab, 0xda, 0x00, 0xf6,
+            ],
+        ),
+        point(
+            &[
+                0x8f, 0x50, 0x6f, 0x0b, 0x6c, 0x0b, 0x6e, 0x9a, 0x57, 0xa7, 0xf3, 0x6d, 0x97, 0x0c, 0xa4, 0xe3,
+                0x47, 0xcb, 0xc9, 0x21, 0x46, 0x22, 0x76, 0x42, 0xcb, 0xe7, 0x81, 0xd9, 0xf5, 0x36, 0x2d, 0x33,
+            ],
+            &[
+                0x46, 0x9f, 0x95, 0x5d, 0x2a, 0xfa, 0x61, 0x71, 0x95, 0x30, 0xc5, 0x42, 0x4f, 0x1c, 0x33, 0x68,
+                0x48, 0xcf, 0x92, 0x5d, 0x43, 0xbb, 0x8e, 0xaf, 0x30, 0x48, 0x7d, 0x0c, 0x87, 0xfa, 0x24, 0x3f,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x38, 0x5e, 0xed, 0x34, 0xc1, 0xcd, 0xff, 0x21, 0xe6, 0xd0, 0x81, 0x86, 0x89, 0xb8, 0x1b, 0xde,
+                0x71, 0xa7, 0xf4, 0xf1, 0x83, 0x97, 0xe6, 0x69, 0x0a, 0x84, 0x1e, 0x15, 0x99, 0xc4, 0x38, 0x62,
+            ],
+            &[
+                0x28, 0x3b, 0xeb, 0xc3, 0xe8, 0xea, 0x23, 0xf5, 0x67, 0x01, 0xde, 0x19, 0xe9, 0xeb, 0xf4, 0x57,
+                0x6b, 0x30, 0x4e, 0xec, 0x20, 0x86, 0xdc, 0x8c, 0xc0, 0x45, 0x8f, 0xe5, 0x54, 0x2e, 0x54, 0x53,
+            ],
+        ),
+        point(
+            &[
+                0xf6, 0xf6, 0x22, 0x08, 0x3d, 0xaf, 0x54, 0x80, 0x04, 0x56, 0xbe, 0x13, 0x4d, 0x5f, 0x67, 0xd1,
+                0x47, 0xc8, 0x26, 0x42, 0xbe, 0xfc, 0x1c, 0xe2, 0xdc, 0x83, 0xa2, 0x70, 0x78, 0xf2, 0x82, 0x7c,
+            ],
+            &[
+                0x1b, 0xcd, 0x4e, 0x81, 0x7d, 0xe7, 0x3a, 0x0f, 0xaf, 0x2c, 0x57, 0x15, 0xb3, 0x67, 0xce, 0xe7,
+                0xe6, 0x57, 0xca, 0x74, 0x48, 0x32, 0x1b, 0xf6, 0xd1, 0x5b, 0x20, 0xb5, 0x20, 0xaa, 0xa1, 0x02,
+            ],
+        ),
+        point(
+            &[
+                0x19, 0xa3, 0x14, 0xf3, 0x97, 0xc7, 0x05, 0xe7, 0x5e, 0x12, 0xea, 0x61, 0x39, 0xcf, 0x84, 0x56,
+                0x41, 0xdb, 0x92, 0x83, 0x1b, 0x38, 0xd6, 0x35, 0x14, 0xbd, 0x30, 0x6a, 0xb6, 0xe2, 0xd9, 0xb3,
+            ],
+            &[
+                0x6c, 0xac, 0xd8, 0xf5, 0xda, 0xc7, 0x28, 0xdd, 0x23, 0x49, 0x65, 0xf8, 0x87, 0xf5, 0x28, 0xb3,
+                0xa5, 0x02, 0x1d, 0x1d, 0x24, 0x04, 0xbe, 0x56, 0xd5, 0x52, 0xee, 0x25, 0xcb, 0xaa, 0xaf, 0x33,
+            ],
+        ),
+        point(
+            &[
+                0xfb, 0x26, 0xe5, 0x18, 0x8f, 0x95, 0x3d, 0xe2, 0xbd, 0x70, 0xcb, 0x3c, 0x3d, 0x1f, 0xc2, 0x55,
+                0xcd, 0x91, 0xc3, 0xce, 0x7d, 0x8c, 0x6f, 0x36, 0x9d, 0x89, 0x32, 0x09, 0x71, 0x5a, 0xdc, 0xb6,
+            ],
+            &[
+                0xf3, 0xe1, 0x28, 0x81, 0x10, 0x12, 0xa3, 0x4d, 0x58, 0xe8, 0x46, 0xa7, 0x19, 0xd0, 0x17, 0x69,
+                0x16, 0xd2, 0xcb, 0x31, 0xb8, 0xb7, 0xab, 0x54, 0x49, 0xdb, 0xca, 0x3b, 0x58, 0xba, 0x68, 0xf3,
+            ],
+        ),
+        point(
+            &[
+                0x58, 0x40, 0xed, 0x4b, 0x95, 0xa8, 0xda, 0xa3, 0x55, 0xdc, 0x98, 0x63, 0x64, 0xf6, 0x72, 0x19,
+                0x20, 0x35, 0x88, 0x04, 0xa1, 0x00, 0xdc, 0xee, 0x7d, 0x85, 0x87, 0xeb, 0x12, 0xf0, 0x04, 0x80,
+            ],
+            &[
+                0x67, 0x0c, 0xda, 0x6b, 0x22, 0x0b, 0xf1, 0x41, 0x07, 0x96, 0x8d, 0xea, 0xa1, 0x5d, 0xd8, 0xda,
+                0xba, 0x75, 0x22, 0x54, 0x52, 0xae, 0x38, 0x72, 0x15, 0x92, 0xd5, 0xe2, 0xbe, 0x22, 0xcf, 0x9e,
+            ],
+        ),
+        point(
+            &[
+                0x85, 0xff, 0xdc, 0x0d, 0xe8, 0x18, 0x7f, 0xe9, 0xa8, 0x06, 0xe2, 0xa9, 0xaa, 0xe5, 0x1c, 0xff,
+                0xd0, 0xad, 0xfc, 0x03, 0x15, 0x40, 0x0b, 0x37, 0x52, 0xe7, 0x37, 0xd9, 0x63, 0xa5, 0x22, 0x64,
+            ],
+            &[
+                0x3f, 0xee, 0x30, 0x18, 0x7a, 0xe2, 0x94, 0x8d, 0x0b, 0xe5, 0x68, 0xf5, 0x05, 0x54, 0x70, 0x6c,
+                0x67, 0x81, 0x82, 0xc9, 0x2b, 0x17, 0x9d, 0x04, 0x98, 0xc7, 0xd2, 0x9a, 0x82, 0xda, 0x20, 0x82,
+            ],
+        ),
+        point(
+            &[
+                0x9f, 0x57, 0x01, 0xa5, 0x34, 0x69, 0x18, 0xfb, 0x1d, 0xc5, 0x8c, 0x1f, 0x4e, 0xce, 0x53, 0x25,
+                0x45, 0xdc, 0x1a, 0x3c, 0x26, 0x9a, 0x3d, 0xc8, 0xe4, 0x84, 0xde, 0xe8, 0x23, 0xf5, 0x4c, 0x42,
+            ],
+            &[
+                0xce, 0x7b, 0x8f, 0xb8, 0x80, 0x1d, 0x9e, 0x57, 0xbf, 0xb9, 0x5b, 0x6b, 0x57, 0x29, 0xbf, 0xdd,
+                0x89, 0xee, 0x78, 0x4a, 0xb2, 0x19, 0xe5, 0x27, 0x86, 0x0e, 0x1c, 0x49, 0x2f, 0xeb, 0x6a, 0x21,
+            ],
+        ),
+        point(
+            &[
+                0x89, 0x91, 0x22, 0x59, 0x11, 0xb9, 0x13, 0x2d, 0x28, 0xf5, 0xc6, 0xbc, 0x76, 0x3c, 0xea, 0xb7,
+                0xd1, 0x8c, 0x37, 0x06, 0x0e, 0x8b, 0xd1, 0xd7, 0xed, 0x44, 0xdb, 0x75, 0x60, 0x78, 0x8c, 0x1e,
+            ],
+            &[
+                0xda, 0x8b, 0x4d, 0x98, 0x7c, 0xc9, 0xac, 0x9b, 0x27, 0xb8, 0x76, 0x35, 0x59, 0xb1, 0x36, 0xfa,
+                0x36, 0x96, 0x9c, 0x84, 0xfd, 0xef, 0x9e, 0x11, 0x63, 0x5c, 0x42, 0x22, 0x8e, 0x8f, 0x0e, 0xf1,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x33, 0x22, 0xd4, 0x01, 0x24, 0x3c, 0x4e, 0x25, 0x82, 0xa2, 0x14, 0x7c, 0x10, 0x4d, 0x6e, 0xcb,
+                0xf7, 0x74, 0xd1, 0x63, 0xdb, 0x0f, 0x5e, 0x53, 0x13, 0xb7, 0xe0, 0xe7, 0x42, 0xd0, 0xe6, 0xbd,
+            ],
+            &[
+                0x56, 0xe7, 0x07, 0x97, 0xe9, 0x66, 0x4e, 0xf5, 0xbf, 0xb0, 0x19, 0xbc, 0x4d, 0xda, 0xf9, 0xb7,
+                0x28, 0x05, 0xf6, 0x3e, 0xa2, 0x87, 0x3a, 0xf6, 0x24, 0xf3, 0xa2, 0xe9, 0x6c, 0x28, 0xb2, 0xa0,
+            ],
+        ),
+        point(
+            &[
+                0x8d, 0x26, 0x20, 0x02, 0x50, 0xce, 0xbd, 0xae, 0x12, 0x0e, 0xf3, 0x1b, 0x04, 0xc8, 0x0c, 0xd5,
+                0x0d, 0x4c, 0xdd, 0xc8, 0xea, 0xdb, 0xcf, 0x29, 0xfc, 0x69, 0x6d, 0x32, 0xc0, 0xad, 0xe4, 0x62,
+            ],
+            &[
+                0xeb, 0xed, 0x3b, 0xb4, 0x71, 0x5b, 0xf4, 0x37, 0xd3, 0x1f, 0x6f, 0x2d, 0xc3, 0xee, 0x36, 0xba,
+                0x1d, 0x4a, 0xfb, 0x4e, 0x72, 0x67, 0x8b, 0x3a, 0xd8, 0xe0, 0xa8, 0xb9, 0x0f, 0x26, 0x47, 0x0c,
+            ],
+        ),
+        point(
+            &[
+                0x78, 0xba, 0xaf, 0xf3, 0x01, 0x5c, 0x05, 0xba, 0x5d, 0x21, 0x96, 0xb3, 0xc6, 0x7f, 0x01, 0xbc,
+                0x0b, 0x13, 0x29, 0x9c, 0x6e, 0x73, 0xc3, 0x30, 0xab, 0xd9, 0xd3, 0xf2, 0x05, 0x9a, 0xb4, 0x99,
+            ],
+            &[
+                0xad, 0x4b, 0xdc, 0xdb, 0xdb, 0x06, 0xc0, 0xaf, 0xaf, 0xca, 0x84, 0xe0, 0xed, 0x82, 0x08, 0x2e,
+                0x91, 0x63, 0x2e, 0xee, 0x8d, 0x12, 0x51, 0x99, 0x68, 0x1d, 0x23, 0x18, 0xfe, 0xe0, 0x97, 0xfd,
+            ],
+        ),
+        point(
+            &[
+                0x12, 0x38, 0xc0, 0x76, 0x6e, 0xae, 0xbe, 0xa9, 0xce, 0x40, 0x68, 0xa1, 0xf5, 0x94, 0xd0, 0x3b,
+                0x8e, 0xd4, 0x93, 0x0d, 0x07, 0x2d, 0x9c, 0x8b, 0x91, 0x64, 0x64, 0x3e, 0x15, 0x16, 0xe6, 0x33,
+            ],
+            &[
+                0x8a, 0x9d, 0xb0, 0x2d, 0xbb, 0x27, 0x13, 0x59, 0xd6, 0xc9, 0x79, 0xe2, 0xd1, 0xc3, 0xdc, 0x17,
+                0x09, 0x46, 0x25, 0x2d, 0xcc, 0x74, 0x02, 0x28, 0x05, 0xcd, 0xb7, 0x28, 0xc7, 0x7b, 0x78, 0x05,
+            ],
+        ),
+        point(
+            &[
+                0x6f, 0x70, 0xf2, 0x11, 0xa1, 0x4a, 0xe3, 0xd4, 0xc2, 0x0b, 0x84, 0x98, 0x49, 0x29, 0xab, 0x1a,
+                0x23, 0x70, 0x9b, 0x36, 0xf8, 0x3a, 0x20, 0xca, 0x44, 0x93, 0xe1, 0x6c, 0xfd, 0x06, 0xac, 0xe6,
+            ],
+            &[
+                0x79, 0x1e, 0x8a, 0x30, 0x94, 0x02, 0x7b, 0x73, 0x6f, 0x95, 0xd8, 0xf3, 0x47, 0xb9, 0x9f, 0x50,
+                0x75, 0x32, 0x95, 0x66, 0xbe, 0x5a, 0xc5, 0xee, 0x04, 0x8b, 0xed, 0x34, 0xb6, 0x02, 0xd5, 0xde,
+            ],
+        ),
+        point(
+            &[
+                0x17, 0xc0, 0x72, 0xd5, 0x6b, 0xdd, 0x13, 0x82, 0xa7, 0x82, 0x48, 0x1b, 0x8a, 0xa4, 0xd2, 0x23,
+                0x2d, 0xb7, 0x94, 0x38, 0x58, 0x70, 0xbc, 0xad, 0xc3, 0x06, 0x33, 0x30, 0xa5, 0xcd, 0x53, 0x79,
+            ],
+            &[
+                0xd9, 0x01, 0xbd, 0xf4, 0x28, 0x3d, 0xa0, 0x64, 0xe7, 0x7c, 0x12, 0x47, 0xaf, 0x1d, 0x03, 0x4f,
+                0x89, 0x59, 0xac, 0x76, 0x26, 0x5b, 0xad, 0x0d, 0xf7, 0xca, 0xe0, 0x51, 0xb1, 0x08, 0xcd, 0x25,
+            ],
+        ),
+        point(
+            &[
+                0xe1, 0x59, 0x9d, 0xb2, 0x9d, 0x6a, 0xa4, 0x15, 0xf8, 0x09, 0x49, 0xf1, 0x91, 0x03, 0xcc, 0xd4,
+                0x8c, 0xed, 0x48, 0x5b, 0x71, 0xe9, 0x62, 0x47, 0xdc, 0x8e, 0xe3, 0xee, 0x60, 0xee, 0x1b, 0x40,
+            ],
+            &[
+                0x79, 0x33, 0x62, 0x23, 0x2a, 0x81, 0xd4, 0xa0, 0xef, 0xaf, 0x89, 0x4a, 0xaa, 0x2f, 0xc7, 0xcf,
+                0xa6, 0x36, 0x3a, 0x74, 0xbc, 0x32, 0x99, 0x9d, 0xe1, 0xd6, 0x26, 0x5e, 0xd7, 0x8f, 0x93, 0xa6,
+            ],
+        ),
+        point(
+            &[
+                0x27, 0x1d, 0x5b, 0x07, 0x70, 0xcb, 0x9c, 0x15, 0xe7, 0xb2, 0xea, 0x75, 0x8a, 0x6a, 0x11, 0xb9,
+                0xcd, 0xdc, 0xd7, 0x28, 0x2b, 0x0e, 0xc2, 0x16, 0x19, 0xb0, 0x15, 0x52, 0x78, 0x8e, 0x7a, 0x66,
+            ],
+            &[
+                0x5d, 0x3a, 0xa4, 0x58, 0x34, 0xe7, 0xf4, 0x91, 0xe4, 0x57, 0xd0, 0x99, 0x49, 0xac, 0x87, 0x7f,
+                0xe2, 0xa0, 0x65, 0xe3, 0x50, 0x8a, 0x82, 0x4e, 0x7a, 0x8d, 0x72, 0x58, 0xe0, 0x3c, 0x97, 0x27,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x09, 0x48, 0xbf, 0x80, 0x9b, 0x19, 0x88, 0xa4, 0x6b, 0x06, 0xc9, 0xf1, 0x91, 0x94, 0x13, 0xb1,
+                0x0f, 0x92, 0x26, 0xc6, 0x0f, 0x66, 0x88, 0x32, 0xff, 0xd9, 0x59, 0xaf, 0x60, 0xc8, 0x2a, 0x0a,
+            ],
+            &[
+                0x53, 0xa5, 0x62, 0x85, 0x6d, 0xcb, 0x66, 0x46, 0xdc, 0x6b, 0x74, 0xc5, 0xd1, 0xc3, 0x41, 0x8c,
+                0x6d, 0x4d, 0xff, 0x08, 0xc9, 0x7c, 0xd2, 0xbe, 0xd4, 0xcb, 0x7f, 0x88, 0xd8, 0xc8, 0xe5, 0x89,
+            ],
+        ),
+        point(
+            &[
+                0x26, 0x95, 0x2c, 0x7f, 0x37, 0x2e, 0x59, 0x36, 0x0d, 0x5c, 0xe4, 0xc6, 0x62, 0x91, 0xf0, 0xb6,
+                0xef, 0x16, 0xc1, 0x33, 0x1e, 0x82, 0x5e, 0x51, 0x39, 0x6e, 0xb0, 0x45, 0x7e, 0x8b, 0x00, 0x0a,
+            ],
+            &[
+                0xf5, 0x13, 0xea, 0x4c, 0x58, 0x00, 0xa6, 0x88, 0x62, 0xbc, 0x89, 0x3d, 0x2d, 0x68, 0x84, 0x22,
+                0xde, 0xbe, 0x39, 0x8f, 0x65, 0x3d, 0x67, 0x31, 0x8c, 0x3d, 0x40, 0x1f, 0x05, 0xef, 0x70, 0x5a,
+            ],
+        ),
+        point(
+            &[
+                0x99, 0x45, 0xb2, 0xfb, 0xe3, 0x82, 0x2b, 0xbc, 0xb5, 0xc3, 0x39, 0x6d, 0x20, 0x56, 0xf8, 0x49,
+                0x64, 0x12, 0x42, 0xee, 0x65, 0xe2, 0xaa, 0x52, 0x72, 0x82, 0xfe, 0x5f, 0xb8, 0xc8, 0xac, 0x7f,
+            ],
+            &[
+                0x3e, 0xef, 0xed, 0x82, 0x4b, 0x0f, 0x28, 0x2d, 0x60, 0x7d, 0xb4, 0x4f, 0xfb, 0x28, 0xef, 0xf5,
+                0x28, 0x2f, 0x7a, 0x23, 0xee, 0xda, 0xcd, 0xfa, 0x96, 0xd9, 0x43, 0xa1, 0x69, 0xae, 0xa3, 0xb0,
+            ],
+        ),
+        point(
+            &[
+                0xc6, 0x2e, 0x58, 0xe6, 0xfc, 0x23, 0xc5, 0xbd, 0xbe, 0xf2, 0xbe, 0x8b, 0x13, 0x1f, 0xf2, 0x43,
+                0xf5, 0x21, 0x19, 0x65, 0x72, 0xd6, 0xb0, 0xe9, 0xf1, 0x02, 0x58, 0x89, 0x76, 0x13, 0x4f, 0x96,
+            ],
+            &[
+                0x43, 0x97, 0x82, 0x7d, 0x45, 0xb1, 0xa1, 0x67, 0x8c, 0x3d, 0x67, 0x67, 0x53, 0x14, 0x1f, 0xc5,
+                0xbc, 0xfb, 0x85, 0x35, 0x63, 0x73, 0x1c, 0x3e, 0x82, 0x27, 0x7e, 0xd4, 0xd1, 0x4c, 0xf9, 0x7e,
+            ],
+        ),
+        point(
+            &[
+                0x2a, 0x31, 0x4c, 0x6b, 0x20, 0x58, 0x70, 0xe6, 0x23, 0x5f, 0x82, 0x22, 0x71, 0x07, 0xd5, 0xfe,
+                0x10, 0xa0, 0x44, 0x08, 0x52, 0xbb, 0xe1, 0xf6, 0xed, 0x1d, 0x79, 0xe3, 0x96, 0x9e, 0x35, 0x3a,
+            ],
+            &[
+                0x15, 0xa4, 0xac, 0x0b, 0xf3, 0x5a, 0x27, 0xac, 0x1a, 0x46, 0x3e, 0x47, 0x6b, 0xaa, 0x1b, 0xa0,
+                0x13, 0x8a, 0x54, 0xaa, 0xdb, 0x26, 0x58, 0xbf, 0xc2, 0x59, 0x26, 0xe1, 0xe5, 0x74, 0x60, 0x67,
+            ],
+        ),
+        point(
+            &[
+                0x0c, 0x7d, 0x11, 0x5c, 0x0e, 0xb4, 0x63, 0x7d, 0xb0, 0x68, 0x77, 0x2c, 0x77, 0xc9, 0x5d, 0xbf,
+                0x3b, 0xd2, 0x78, 0xde, 0x25, 0x81, 0x31, 0x8e, 0x3b, 0xaa, 0x49, 0x98, 0x92, 0xfc, 0xcf, 0x64,
+            ],
+            &[
+                0x4a, 0xa8, 0x74, 0x7b, 0x19, 0x25, 0xb0, 0x44, 0x7a, 0xa4, 0x24, 0xb8, 0x47, 0x8a, 0xf1, 0x45,
+                0x48, 0x78, 0x5d, 0x83, 0x95, 0x9a, 0xe6, 0x8d, 0x53, 0xf6, 0xd3, 0xb3, 0x28, 0x78, 0xfe, 0xe0,
+            ],
+        ),
+        point(
+            &[
+                0x59, 0x59, 0xa5, 0x00, 0xb7, 0x03, 0xfc, 0x2d, 0x49, 0x8b, 0x6f, 0xaf, 0x3a, 0x6b, 0x6c, 0x91,
+                0x1b, 0x4f, 0x41, 0x06, 0xdd, 0x7f, 0x3f, 0xf9, 0x89, 0xe2, 0xf4, 0x9e, 0xe9, 0xb8, 0x49, 0x66,
+            ],
+            &[
+                0x03, 0x70, 0xe6, 0x74, 0x1f, 0x5c, 0xa8, 0x97, 0xf9, 0x43, 0x12, 0x82, 0x0d, 0xc8, 0x2a, 0x70,
+                0x91, 0x00, 0xdc, 0xc0, 0x8c, 0xfe, 0x24, 0x26, 0x40, 0xa6, 0x63, 0x21, 0x87, 0x47, 0x3a, 0x6a,
+            ],
+        ),
+        point(
+            &[
+                0x10, 0x74, 0x60, 0x52, 0x0e, 0xec, 0x5c, 0x74, 0x16, 0x83, 0x32, 0x9a, 0x71, 0x66, 0x22, 0xb0,
+                0xb8, 0x1c, 0x03, 0x20, 0x08, 0x07, 0xde, 0x97, 0x36, 0x86, 0xf8, 0x80, 0x0b, 0x18, 0x8c, 0xbb,
+            ],
+            &[
+                0xab, 0xe5, 0xd4, 0xc0, 0x9a, 0x21, 0x59, 0x8c, 0x35, 0x32, 0x6b, 0x9b, 0x9c, 0xf5, 0x4a, 0x11,
+                0x24, 0x2e, 0x0d, 0x74, 0x8d, 0xce, 0x3d, 0xa6, 0x01, 0xd7, 0xb6, 0x36, 0x1f, 0x27, 0x21, 0x24,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xe5, 0x03, 0x7d, 0xe0, 0xaf, 0xc1, 0xd8, 0xd4, 0x3d, 0x83, 0x48, 0x41, 0x4b, 0xbf, 0x41, 0x03,
+                0x04, 0x3e, 0xc8, 0xf5, 0x75, 0xbf, 0xdc, 0x43, 0x29, 0x53, 0xcc, 0x8d, 0x20, 0x37, 0xfa, 0x2d,
+            ],
+            &[
+                0x45, 0x71, 0x53, 0x4b, 0xaa, 0x94, 0xd3, 0xb5, 0xf9, 0xf9, 0x8d, 0x09, 0xfb, 0x99, 0x0b, 0xdd,
+                0xbd, 0x5f, 0x5b, 0x03, 0xec, 0x48, 0x1f, 0x10, 0xe0, 0xe5, 0xdc, 0x84, 0x1d, 0x75, 0x5b, 0xda,
+            ],
+        ),
+        point(
+            &[
+                0xa5, 0xe0, 0x0d, 0xa4, 0x67, 0xfd, 0x54, 0x94, 0xf4, 0x0b, 0x6c, 0xf7, 0xd2, 0xd6, 0x1b, 0x3e,
+                0xc3, 0xab, 0x21, 0x7c, 0x79, 0x2a, 0x2d, 0xdb, 0x8c, 0x63, 0xc8, 0xc7, 0x9e, 0x3d, 0x34, 0xef,
+            ],
+            &[
+                0x09, 0x8f, 0xe5, 0xf5, 0xe5, 0x60, 0x85, 0x55, 0x42, 0x17, 0x26, 0xfe, 0x99, 0xbf, 0x43, 0xd2,
+                0x5b, 0x60, 0xdc, 0xfe, 0x79, 0x09, 0x00, 0xac, 0xb8, 0x55, 0xc5, 0xce, 0x2f, 0x7a, 0xdb, 0x4c,
+            ],
+        ),
+        point(
+            &[
+                0x9d, 0x89, 0x6a, 0x3a, 0xff, 0x96, 0x33, 0xce, 0xe5, 0x85, 0x43, 0xba, 0xcf, 0x52, 0x91, 0xae,
+                0xb5, 0xe1, 0x55, 0x93, 0x88, 0xed, 0x95, 0xf6, 0x38, 0x8a, 0x8a, 0x6e, 0x17, 0x7e, 0x77, 0x75,
+            ],
+            &[
+                0xdd, 0x91, 0xa9, 0xe4, 0x3f, 0x49, 0xbf, 0x0b, 0x94, 0xe9, 0x64, 0xed, 0x72, 0x50, 0x92, 0x7d,
+                0xe8, 0x99, 0xcd, 0x7e, 0xe2, 0x99, 0x25, 0x3b, 0xef, 0xcf, 0x6d, 0x3a, 0xba, 0x05, 0x66, 0x91,
+            ],
+        ),
+        point(
+            &[
+                0xa9, 0x94, 0x15, 0xf5, 0xef, 0x3a, 0x2b, 0x40, 0x35, 0x19, 0xf4, 0xbb, 0x1c, 0x9b, 0xfb, 0xc4,
+                0x6d, 0x4a, 0xfd, 0x2e, 0x44, 0x77, 0x57, 0x2a, 0xe6, 0x73, 0x71, 0x60, 0xd7, 0xb9, 0x12, 0x52,
+            ],
+            &[
+                0x82, 0xd0, 0xe6, 0x4c, 0xae, 0x81, 0xf8, 0x4b, 0xb9, 0xe2, 0xf1, 0x0f, 0x24, 0xf6, 0xf6, 0xb6,
+                0x89, 0x9a, 0x16, 0xad, 0x59, 0x0f, 0x4d, 0xdd, 0x73, 0xa3, 0x77, 0xac, 0x4b, 0xed, 0xc2, 0x64,
+            ],
+        ),
+        point(
+            &[
+                0x83, 0x27, 0xb8, 0xee, 0x71, 0x16, 0x37, 0x92, 0xd0, 0xeb, 0x0a, 0x57, 0x32, 0x82, 0xf4, 0xcd,
+                0x56, 0x88, 0xb8, 0x6e, 0xe9, 0x03, 0x47, 0x6c, 0x5f, 0xde, 0x04, 0xde, 0x3c, 0x2a, 0x32, 0x93,
+            ],
+            &[
+                0x04, 0x99, 0x7e, 0x26, 0x6e, 0xe0, 0xa9, 0x8e, 0x18, 0xbb, 0x3e, 0xa6, 0x62, 0x79, 0x70, 0x84,
+                0x96, 0xaf, 0xda, 0xb4, 0xee, 0x32, 0x6a, 0x41, 0x6b, 0xc8, 0x54, 0xe1, 0x8e, 0x0d, 0xf9, 0xbd,
+            ],
+        ),
+        point(
+            &[
+                0x00, 0xcf, 0x8c, 0x2d, 0x2d, 0xb8, 0x18, 0xdf, 0xf9, 0x7f, 0x8e, 0x64, 0x31, 0x27, 0x23, 0x84,
+                0xdf, 0x2a, 0x74, 0x58, 0x90, 0x58, 0x57, 0x84, 0x15, 0x95, 0x7f, 0x1e, 0x8c, 0x90, 0x4e, 0xd3,
+            ],
+            &[
+                0xaa, 0xad, 0x00, 0x0e, 0xa7, 0x81, 0xd4, 0x41, 0x19, 0xdd, 0x5c, 0x1b, 0xd5, 0x1b, 0xd8, 0x11,
+                0x22, 0x99, 0x1d, 0x79, 0xd3, 0x2f, 0x68, 0x27, 0xb0, 0x09, 0x96, 0x3b, 0x79, 0x6f, 0x77, 0xc1,
+            ],
+        ),
+        point(
+            &[
+                0x5a, 0xe4, 0x2a, 0xaa, 0x2a, 0x6d, 0xb1, 0x68, 0x97, 0xd6, 0x66, 0x1d, 0x8f, 0x9a, 0x8e, 0xd6,
+                0x09, 0x2d, 0x23, 0x23, 0x4b, 0x8d, 0xfb, 0x1a, 0x24, 0x21, 0xb2, 0x6c, 0x45, 0x62, 0xc0, 0x42,
+            ],
+            &[
+                0x99, 0xd9, 0x3a, 0x7c, 0x05, 0xff, 0x05, 0x1e, 0xab, 0x5c, 0x1d, 0xdc, 0x60, 0x38, 0x9d, 0x4a,
+                0x94, 0xe0, 0xdb, 0x95, 0x10, 0x7c, 0xd8, 0xdb, 0xf9, 0x05, 0xcc, 0xdf, 0x8f, 0x79, 0x26, 0x9c,
+            ],
+        ),
+        point(
+            &[
+                0xb5, 0x6f, 0x4e, 0x9f, 0x9e, 0x4f, 0xd1, 0xfc, 0x7d, 0x8e, 0xdd, 0xe0, 0x98, 0xf9, 0x35, 0xf8,
+                0x4c, 0x75, 0x0d, 0x70, 0x5f, 0x0c, 0x13, 0x2b, 0xd8, 0xc4, 0x65, 0xb6, 0x6a, 0x54, 0x0f, 0x17,
+            ],
+            &[
+                0x32, 0xe8, 0xe5, 0x34, 0x29, 0xcc, 0xa8, 0x56, 0xd3, 0xdc, 0x11, 0xad, 0xf0, 0x58, 0x2d, 0x1d,
+                0x21, 0xd4, 0x29, 0x63, 0xcb, 0xcc, 0xa8, 0x54, 0x46, 0xa2, 0xfc, 0xae, 0x02, 0x00, 0x10, 0x2d,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x21, 0x3c, 0x7a, 0x71, 0x5c, 0xd5, 0xd4, 0x53, 0x58, 0xd0, 0xbb, 0xf9, 0xdc, 0x0c, 0xe0, 0x22,
+                0x04, 0xb1, 0x0b, 0xdd, 0xe2, 0xa3, 0xf5, 0x85, 0x40, 0xad, 0x69, 0x08, 0xd0, 0x55, 0x97, 0x54,
+            ],
+            &[
+                0x4b, 0x6d, 0xad, 0x0b, 0x5a, 0xe4, 0x62, 0x50, 0x70, 0x13, 0xad, 0x06, 0x24, 0x5b, 0xa1, 0x90,
+                0xbb, 0x48, 0x50, 0xf5, 0xf3, 0x6a, 0x7e, 0xed, 0xdf, 0xf2, 0xc2, 0x75, 0x34, 0xb4, 0x58, 0xf2,
+            ],
+        ),
+        point(
+            &[
+                0x1c, 0x5e, 0x54, 0x81, 0x32, 0xb4, 0x9a, 0x7f, 0x66, 0xae, 0x9f, 0xed, 0x83, 0x23, 0x48, 0x0e,
+                0x0d, 0x1a, 0xb9, 0x74, 0x62, 0x2e, 0x7c, 0xf0, 0x89, 0x93, 0x89, 0x5e, 0x0e, 0xc8, 0x7f, 0xac,
+            ],
+            &[
+                0x4f, 0xfc, 0xf6, 0x0f, 0x83, 0x7f, 0x46, 0x8f, 0x2b, 0xb9, 0x59, 0xfa, 0x1d, 0x4c, 0x2a, 0xd3,
+                0xa3, 0xde, 0xac, 0xeb, 0x26, 0xfe, 0x32, 0x4c, 0x55, 0x5d, 0x7b, 0x3d, 0x5f, 0xc2, 0xd4, 0xef,
+            ],
+        ),
+        point(
+            &[
+                0xb8, 0xce, 0xf6, 0xe1, 0x75, 0x3d, 0xa0, 0x30, 0x95, 0x46, 0xe0, 0x96, 0xb9, 0x53, 0xd1, 0x72,
+                0xae, 0x3f, 0xa3, 0xed, 0x4c, 0x19, 0xa9, 0x3e, 0x53, 0x2d, 0x80, 0x11, 0x9e, 0x05, 0xdc, 0xcc,
+            ],
+            &[
+                0x30, 0x2b, 0x8a, 0x60, 0xa6, 0xcc, 0x9b, 0xbf, 0x6a, 0x4d, 0x4a, 0x74, 0xe4, 0xd2, 0xbd, 0x99,
+                0xdf, 0x75, 0x7f, 0xc3, 0x6a, 0x6b, 0x68, 0x13, 0x30, 0x14, 0xa0, 0xcf, 0xcc, 0x6d, 0x57, 0x50,
+            ],
+        ),
+        point(
+            &[
+                0x46, 0x27, 0x6d, 0x06, 0x02, 0xc5, 0x66, 0x8d, 0xde, 0xf6, 0xe9, 0x42, 0x10, 0xbb, 0xc7, 0xce,
+                0x1f, 0x90, 0x1c, 0x19, 0xfe, 0xd5, 0xc9, 0x70, 0xe2, 0x0f, 0xcb, 0xa1, 0xd4, 0x53, 0x1d, 0xbc,
+            ],
+            &[
+                0x0e, 0x0f, 0x7f, 0x24, 0xd4, 0x4c, 0x75, 0xb8, 0x4a, 0x29, 0x22, 0x87, 0x57, 0x0d, 0xed, 0x99,
+                0x49, 0x8b, 0xad, 0xfb, 0xff, 0xe1, 0xbc, 0x99, 0xaf, 0x87, 0x30, 0x09, 0x96, 0x86, 0xb8, 0xe2,
+            ],
+        ),
+        point(
+            &[
+                0x03, 0xfb, 0x33, 0xe7, 0x79, 0xb4, 0x73, 0x85, 0xe0, 0x2a, 0x0b, 0xde, 0x2e, 0xbb, 0x5f, 0x49,
+                0x70, 0x1f, 0x7b, 0x6b, 0x5f, 0xdb, 0x97, 0xb4, 0x85, 0xbd, 0xfe, 0xe1, 0x37, 0x3b, 0xb3, 0x1a,
+            ],
+            &[
+                0xf3, 0x6a, 0xd9, 0x52, 0x54, 0x8e, 0xfe, 0x28, 0x1e, 0x0c, 0x16, 0x1a, 0xab, 0xbb, 0x57, 0x2b,
+                0xc6, 0x5a, 0x7c, 0x76, 0xc4, 0x76, 0x40, 0xd4, 0xe3, 0x4c, 0xbe, 0x69, 0x7d, 0x21, 0x5c, 0x9e,
+            ],
+        ),
+        point(
+            &[
+                0x3a, 0x57, 0x16, 0x30, 0x93, 0x5c, 0x1f, 0x02, 0xd6, 0xfd, 0x87, 0x44, 0x2c, 0x08, 0x20, 0x60,
+                0xcd, 0x5e, 0x79, 0x2a, 0x1c, 0x6f, 0x92, 0xbd, 0x0c, 0x4e, 0xed, 0x01, 0x68, 0x6d, 0xf5, 0x0d,
+            ],
+            &[
+                0x85, 0xe1, 0x38, 0x73, 0xb5, 0x99, 0xf3, 0x2f, 0x51, 0x6f, 0x3f, 0xf2, 0xe5, 0x70, 0xa0, 0xdd,
+                0xe0, 0xaa, 0xf8, 0x1e, 0xbb, 0x78, 0x1a, 0x11, 0xd2, 0x15, 0x8b, 0x28, 0xe8, 0x59, 0x67, 0x9b,
+            ],
+        ),
+        point(
+            &[
+                0x4b, 0x17, 0x7c, 0xd1, 0x09, 0xec, 0x3e, 0x11, 0x61, 0xf1, 0x6f, 0x7b, 0x4d, 0x0f, 0x7a, 0x36,
+                0x71, 0x7c, 0x36, 0xc1, 0x85, 0x5b, 0xb7, 0xc0, 0xa5, 0xb5, 0x15, 0xeb, 0xdd, 0x9a, 0x4a, 0xb4,
+            ],
+            &[
+                0x3e, 0xc9, 0x66, 0xe9, 0xa5, 0xe2, 0xfa, 0x65, 0xfd, 0xa8, 0xf6, 0x72, 0x93, 0x62, 0x6b, 0x48,
+                0x6e, 0x37, 0xe2, 0x55, 0xf1, 0x74, 0x1f, 0x55, 0x8a, 0xad, 0xdf, 0xe4, 0x63, 0x5a, 0xb6, 0xf7,
+            ],
+        ),
+        point(
+            &[
+                0xef, 0xea, 0x68, 0xec, 0xa7, 0xa6, 0xc2, 0x4f, 0x4e, 0x65, 0xeb, 0x21, 0x1c, 0x31, 0x91, 0x63,
+                0x68, 0x50, 0xe0, 0xac, 0xdc, 0x78, 0xd8, 0x99, 0x61, 0x14, 0xef, 0x13, 0x52, 0x2f, 0x00, 0x1d,
+            ],
+            &[
+                0xaa, 0xb8, 0x47, 0x86, 0x9d, 0x58, 0x3c, 0x14, 0xda, 0x15, 0x03, 0x07, 0xa3, 0x71, 0x9a, 0x17,
+                0xe4, 0x13, 0x95, 0x9f, 0xb3, 0x84, 0x87, 0x71, 0xc1, 0x28, 0x41, 0x9f, 0x73, 0xbc, 0x44, 0x15,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xfe, 0xa7, 0x4e, 0x3d, 0xbe, 0x77, 0x8b, 0x1b, 0x10, 0xf2, 0x38, 0xad, 0x61, 0x68, 0x6a, 0xa5,
+                0xc7, 0x6e, 0x3d, 0xb2, 0xbe, 0x43, 0x05, 0x76, 0x32, 0x42, 0x7e, 0x28, 0x40, 0xfb, 0x27, 0xb6,
+            ],
+            &[
+                0x6e, 0x05, 0x68, 0xdb, 0x9b, 0x0b, 0x13, 0x29, 0x7c, 0xf6, 0x74, 0xde, 0xcc, 0xb6, 0xaf, 0x93,
+                0x12, 0x6b, 0x59, 0x6b, 0x97, 0x3f, 0x7b, 0x77, 0x70, 0x1d, 0x3d, 0xb7, 0xf2, 0x3c, 0xb9, 0x6f,
+            ],
+        ),
+        point(
+            &[
+                0xed, 0x94, 0x41, 0xc8, 0x30, 0x42, 0x80, 0xff, 0x18, 0x0e, 0x03, 0xd8, 0x50, 0xe8, 0xcd, 0x0e,
+                0xbb, 0x57, 0x0e, 0xe5, 0xde, 0x37, 0x30, 0x48, 0x8f, 0xd9, 0x7c, 0x96, 0x1f, 0x97, 0x56, 0xe4,
+            ],
+            &[
+                0x3d, 0xbe, 0x9e, 0x9e, 0xfe, 0x8b, 0xfa, 0x19, 0xaf, 0xa1, 0x76, 0x12, 0x8b, 0x13, 0x91, 0x1e,
+                0x09, 0xf2, 0x37, 0x74, 0xfe, 0x4d, 0xe9, 0x8b, 0xff, 0x0e, 0x09, 0xf9, 0x3f, 0x3a, 0xbf, 0xae,
+            ],
+        ),
+        point(
+            &[
+                0x76, 0x2e, 0x8b, 0xc3, 0x32, 0x11, 0xfe, 0xa8, 0x22, 0x6c, 0xd9, 0x7b, 0x27, 0x18, 0x99, 0xf3,
+                0xf2, 0x7b, 0x64, 0x99, 0x7b, 0x00, 0x4b, 0xb2, 0x5d, 0xd8, 0x1a, 0xe9, 0xbe, 0x88, 0x97, 0x56,
+            ],
+            &[
+                0xc0, 0x28, 0x94, 0x26, 0x0a, 0xf3, 0xe9, 0x7c, 0x3c, 0x7c, 0xc4, 0xf1, 0x49, 0x82, 0xe3, 0x47,
+                0x19, 0x72, 0xdb, 0x31, 0x48, 0x84, 0xfa, 0x5e, 0x25, 0xe2, 0x59, 0xe0, 0x7c, 0xa6, 0xb7, 0x74,
+            ],
+        ),
+        point(
+            &[
+                0x29, 0xd9, 0x69, 0x8e, 0xe6, 0x7a, 0x7c, 0x3f, 0xc9, 0xfe, 0xd3, 0xf6, 0x24, 0xb4, 0x87, 0x51,
+                0x5b, 0x10, 0xbd, 0xd8, 0x4f, 0xab, 0x4d, 0x30, 0x15, 0xba, 0xd0, 0x33, 0xd5, 0x1c, 0xf1, 0x19,
+            ],
+            &[
+                0x7f, 0xd0, 0x2c, 0x51, 0x7d, 0xc8, 0x2b, 0x45, 0x27, 0x7a, 0x12, 0x54, 0x04, 0xf1, 0xc9, 0x6f,
+                0xb8, 0x9c, 0x94, 0x0e, 0x93, 0xa7, 0xc2, 0x96, 0x3c, 0x88, 0x74, 0x05, 0x75, 0x05, 0x63, 0x39,
+            ],
+        ),
+        point(
+            &[
+                0xdf, 0x07, 0x7d, 0x47, 0xdf, 0x60, 0x95, 0x34, 0x8e, 0x19, 0xbd, 0xbb, 0x23, 0x08, 0xf4, 0xa9,
+                0x1e, 0x52, 0xac, 0xfa, 0x10, 0x14, 0xe8, 0xea, 0x26, 0xf7, 0x5e, 0x97, 0x09, 0x75, 0xd2, 0xea,
+            ],
+            &[
+                0xf8, 0x61, 0x7a, 0x88, 0x00, 0xef, 0x7f, 0x44, 0x24, 0xc8, 0x42, 0x5c, 0x98, 0xa2, 0x52, 0x7c,
+                0x8a, 0x1e, 0xc5, 0xb8, 0x4f, 0xbd, 0xd2, 0x77, 0xaa, 0x3c, 0x2d, 0x9e, 0x31, 0x93, 0x6f, 0x95,
+            ],
+        ),
+        point(
+            &[
+                0x38, 0xb8, 0x2a, 0x75, 0x57, 0x9a, 0xd3, 0x6b, 0x9d, 0x47, 0xef, 0x64, 0xf1, 0xa5, 0xa8, 0x5c,
+                0x24, 0x7f, 0x21, 0x02, 0x7e, 0x56, 0xc6, 0xc2, 0x87, 0x55, 0x80, 0xa5, 0xa6, 0x71, 0x45, 0x60,
+            ],
+            &[
+                0xf9, 0xd8, 0xa6, 0x97, 0x6f, 0x26, 0x1e, 0xf4, 0x28, 0xbf, 0x56, 0x34, 0x61, 0x64, 0x34, 0x59,
+                0x7d, 0x94, 0xf2, 0x3b, 0xef, 0x71, 0x62, 0x84, 0xad, 0xa8, 0x73, 0x34, 0xa7, 0x74, 0x29, 0x9e,
+            ],
+        ),
+        point(
+            &[
+                0x9f, 0x3e, 0x7d, 0x75, 0x8b, 0xd3, 0xda, 0x03, 0x2e, 0x17, 0xde, 0xa8, 0x33, 0x4b, 0x14, 0x29,
+                0x35, 0xdb, 0x4d, 0x6e, 0xe5, 0x43, 0x91, 0xb4, 0x5b, 0x84, 0x91, 0xfb, 0xbc, 0x4c, 0x92, 0xd7,
+            ],
+            &[
+                0xec, 0xd2, 0x84, 0x1e, 0xa7, 0x7d, 0x46, 0x6b, 0x58, 0x86, 0x2b, 0x21, 0xcb, 0xab, 0x15, 0x02,
+                0x45, 0x2a, 0x23, 0x03, 0xd6, 0x94, 0xe1, 0x18, 0x6c, 0xbb, 0xbf, 0xcf, 0xb1, 0x49, 0x06, 0xdd,
+            ],
+        ),
+        point(
+            &[
+                0x12, 0x6b, 0x57, 0xd0, 0x50, 0x13, 0x93, 0x6d, 0x6f, 0x3f, 0xb7, 0xbd, 0x33, 0x58, 0x0a, 0x31,
+                0xfd, 0x45, 0x3e, 0x4a, 0x86, 0x06, 0x0c, 0xff, 0x46, 0x7c, 0x44, 0x53, 0x7f, 0x42, 0x24, 0x91,
+            ],
+            &[
+                0xc1, 0xa7, 0xdc, 0x13, 0x06, 0x16, 0x62, 0xc2, 0xe3, 0xc4, 0xa3, 0xeb, 0xa2, 0xbf, 0x3f, 0xb0,
+                0xe1, 0x48, 0xba, 0xc3, 0x0b, 0xf3, 0x93, 0x47, 0xaf, 0xa3, 0x1f, 0x19, 0x9d, 0xa3, 0xef, 0x84,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xc7, 0x38, 0xc5, 0x6b, 0x03, 0xb2, 0xab, 0xe1, 0xe8, 0x28, 0x1b, 0xaa, 0x74, 0x3f, 0x8f, 0x9a,
+                0x8f, 0x7c, 0xc6, 0x43, 0xdf, 0x26, 0xcb, 0xee, 0x3a, 0xb1, 0x50, 0x24, 0x2b, 0xcb, 0xb8, 0x91,
+            ],
+            &[
+                0x89, 0x3f, 0xb5, 0x78, 0x95, 0x1a, 0xd2, 0x53, 0x7f, 0x71, 0x8f, 0x2e, 0xac, 0xbf, 0xbb, 0xbb,
+                0x82, 0x31, 0x4e, 0xef, 0x78, 0x80, 0xcf, 0xe9, 0x17, 0xe7, 0x35, 0xd9, 0x69, 0x9a, 0x84, 0xc3,
+            ],
+        ),
+        point(
+            &[
+                0x55, 0x78, 0x84, 0x5e, 0xcd, 0x7c, 0x03, 0x74, 0x35, 0xb3, 0x2a, 0x69, 0x92, 0xe7, 0xaa, 0x94,
+                0x64, 0x71, 0x97, 0xea, 0x49, 0xb8, 0xc9, 0xe4, 0xdd, 0xaa, 0xb0, 0x78, 0x46, 0x62, 0xab, 0x1b,
+            ],
+            &[
+                0xe6, 0x1d, 0x07, 0x97, 0x8b, 0x6d, 0xe2, 0xc3, 0xce, 0xa6, 0xd0, 0xa5, 0x1d, 0x2a, 0x40, 0x53,
+                0xf6, 0x53, 0xa7, 0x74, 0x6a, 0x5d, 0x64, 0xde, 0x31, 0x6d, 0x18, 0xf3, 0x05, 0x6f, 0x35, 0x11,
+            ],
+        ),
+        point(
+            &[
+                0xb8, 0xc4, 0x61, 0x27, 0x82, 0x3f, 0x61, 0x46, 0x10, 0xa2, 0x40, 0xa3, 0x57, 0x20, 0xdf, 0x7a,
+                0xca, 0x4c, 0x9b, 0xe4, 0x08, 0xd6, 0x0e, 0x2f, 0x34, 0xba, 0xaf, 0x33, 0x87, 0x61, 0xd5, 0x8d,
+            ],
+            &[
+                0x8f, 0x9e, 0xd9, 0x6c, 0x51, 0x70, 0xe3, 0x7d, 0x14, 0xa4, 0x58, 0xf6, 0x97, 0xf3, 0xc5, 0x05,
+                0x3f, 0x75, 0x04, 0x78, 0x5e, 0x10, 0x7c, 0x5b, 0x63, 0x8e, 0xa0, 0xba, 0x9d, 0x10, 0x51, 0xa4,
+            ],
+        ),
+        point(
+            &[
+                0x47, 0xf3, 0x38, 0x38, 0x88, 0xa3, 0x64, 0xcc, 0x4a, 0xbf, 0xa3, 0xbc, 0x1d, 0x0c, 0xec, 0xcd,
+                0x22, 0xf1, 0x23, 0x54, 0xfc, 0xe3, 0x99, 0x60, 0x94, 0xf8, 0x69, 0xb8, 0x94, 0x8b, 0x6c, 0x29,
+            ],
+            &[
+                0x48, 0xca, 0x9a, 0x8d, 0x0f, 0x03, 0x29, 0x37, 0x19, 0x0e, 0x48, 0x67, 0x5b, 0x41, 0x6c, 0x71,
+                0x18, 0xbb, 0x49, 0x95, 0x88, 0xf9, 0x94, 0xa8, 0x1e, 0xde, 0xe1, 0x12, 0x0e, 0x53, 0x7e, 0xf9,
+            ],
+        ),
+        point(
+            &[
+                0x08, 0xd5, 0x6e, 0x9f, 0x71, 0x02, 0x71, 0xf7, 0xa3, 0x50, 0xc9, 0x93, 0xfe, 0x9a, 0x36, 0x71,
+                0xf3, 0xf4, 0x7d, 0xb9, 0xf0, 0x13, 0x4a, 0xdc, 0x2d, 0xb0, 0xb3, 0x04, 0x05, 0x0b, 0x00, 0x40,
+            ],
+            &[
+                0xa1, 0x21, 0x85, 0xae, 0xbd, 0x0a, 0x9a, 0xa2, 0x1f, 0xfd, 0x15, 0x0a, 0x8d, 0x79, 0xb2, 0x85,
+                0xf6, 0xd7, 0xc4, 0x72, 0x0e, 0x17, 0x82, 0xbe, 0xb5, 0x8e, 0x26, 0x7a, 0x5b, 0x3f, 0xd0, 0xa1,
+            ],
+        ),
+        point(
+            &[
+                0x6b, 0x00, 0x40, 0x33, 0x18, 0x81, 0x8d, 0x2b, 0x7c, 0x78, 0x12, 0x5d, 0x8e, 0x48, 0x0a, 0x2e,
+                0xca, 0xee, 0xbf, 0x4a, 0x41, 0xff, 0xf3, 0x6d, 0xa1, 0x4d, 0xea, 0x1a, 0x11, 0x66, 0xff, 0x40,
+            ],
+            &[
+                0x41, 0xcd, 0x1b, 0x3a, 0xe8, 0xaa, 0xfa, 0xc9, 0x96, 0x89, 0x43, 0xa0, 0x59, 0x0b, 0xf2, 0xf0,
+                0x07, 0x68, 0x83, 0xf6, 0xe7, 0xf3, 0xed, 0xe9, 0xeb, 0x74, 0x13, 0x0e, 0x9d, 0x71, 0xb8, 0x47,
+            ],
+        ),
+        point(
+            &[
+                0xdc, 0x13, 0xf2, 0x32, 0xd4, 0x2f, 0xce, 0x63, 0x41, 0xe2, 0x0f, 0x97, 0x7b, 0xe6, 0x5a, 0x37,
+                0x88, 0x9a, 0x1c, 0x5e, 0xd3, 0x0b, 0x62, 0x70, 0xf5, 0x65, 0x63, 0xdf, 0x13, 0x57, 0x3b, 0x7f,
+            ],
+            &[
+                0xc9, 0x09, 0xba, 0x80, 0x42, 0x9e, 0x34, 0x0c, 0x3c, 0x74, 0x10, 0xda, 0x84, 0xa9, 0x0a, 0x76,
+                0x70, 0x45, 0x54, 0x70, 0x17, 0x40, 0x4b, 0x1c, 0x42, 0xac, 0xd2, 0x28, 0x4c, 0x1f, 0x2b, 0xa6,
+            ],
+        ),
+        point(
+            &[
+                0xc0, 0xc0, 0x1f, 0x34, 0xae, 0x41, 0xb8, 0xcf, 0xe4, 0x66, 0xb4, 0xc9, 0xc6, 0xa5, 0xd5, 0xf6,
+                0x14, 0xf5, 0x70, 0xd6, 0xfc, 0xbe, 0xf7, 0x68, 0xa8, 0x1a, 0x6c, 0x8f, 0x05, 0xff, 0x4a, 0xdb,
+            ],
+            &[
+                0x0b, 0x84, 0xf5, 0xbe, 0xe4, 0x35, 0x7f, 0x5c, 0x7c, 0x93, 0x7a, 0x0b, 0x40, 0x75, 0xb8, 0xce,
+                0xcd, 0xbc, 0x43, 0xd1, 0x70, 0xd1, 0x5b, 0x85, 0xfc, 0x4e, 0xff, 0x73, 0xac, 0x35, 0x10, 0x65,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xb8, 0xda, 0x94, 0x03, 0x2a, 0x95, 0x75, 0x18, 0xeb, 0x0f, 0x64, 0x33, 0x57, 0x1e, 0x87, 0x61,
+                0xce, 0xff, 0xc7, 0x36, 0x93, 0xe8, 0x4e, 0xdd, 0x49, 0x15, 0x0a, 0x56, 0x4f, 0x67, 0x6e, 0x03,
+            ],
+            &[
+                0x28, 0x04, 0xdf, 0xa4, 0x48, 0x05, 0xa1, 0xe4, 0xd7, 0xc9, 0x9c, 0xc9, 0x76, 0x28, 0x08, 0xb0,
+                0x92, 0xcc, 0x58, 0x4d, 0x95, 0xff, 0x3b, 0x51, 0x14, 0x88, 0xe4, 0xe7, 0x4e, 0xfd, 0xf6, 0xe7,
+            ],
+        ),
+        point(
+            &[
+                0x6d, 0x36, 0xd1, 0x05, 0xed, 0x8c, 0xc5, 0xce, 0x53, 0xf2, 0xcb, 0x69, 0x8a, 0xb6, 0x20, 0xf9,
+                0x46, 0x9a, 0x3e, 0x5c, 0xb2, 0x5b, 0xf6, 0xe6, 0xd4, 0x13, 0xf4, 0x14, 0xc5, 0xaf, 0x72, 0x6a,
+            ],
+            &[
+                0xe4, 0xba, 0x5c, 0x34, 0xe3, 0x77, 0x66, 0x9e, 0x72, 0xd8, 0xc6, 0x6c, 0x95, 0xc5, 0x00, 0x29,
+                0xdc, 0xc5, 0x99, 0x36, 0xb4, 0x10, 0x8a, 0x35, 0xc5, 0x70, 0x49, 0x1a, 0x13, 0xf9, 0xfc, 0x7d,
+            ],
+        ),
+        point(
+            &[
+                0x06, 0x90, 0x68, 0xff, 0x09, 0x82, 0xd1, 0x0b, 0xe5, 0x4c, 0x76, 0x1f, 0x14, 0xd1, 0x52, 0xc0,
+                0x5a, 0xbe, 0x7b, 0x10, 0x38, 0x5a, 0xf1, 0xc5, 0xea, 0x19, 0x84, 0x9d, 0xc6, 0xe1, 0x34, 0x6b,
+            ],
+            &[
+                0xb8, 0x63, 0xe3, 0xe0, 0x90, 0xbf, 0xde, 0x26, 0xda, 0x85, 0xdb, 0x2b, 0xd0, 0x86, 0x44, 0x2a,
+                0xa4, 0xf6, 0x89, 0x39, 0x94, 0xc6, 0x02, 0x6e, 0x7b, 0xb5, 0x8a, 0x54, 0xd7, 0x22, 0x6c, 0x13,
+            ],
+        ),
+        point(
+            &[
+                0x3a, 0xb6, 0xbd, 0xe1, 0x0c, 0xd3, 0xac, 0x0c, 0xd0, 0x68, 0x83, 0xfa, 0x66, 0xf0, 0xb0, 0xe3,
+                0xeb, 0x13, 0x09, 0xc0, 0x53, 0x4b, 0x81, 0x22, 0x86, 0xe2, 0xa3, 0x0c, 0xa5, 0x40, 0xdb, 0x99,
+            ],
+            &[
+                0xba, 0xca, 0x62, 0x07, 0x9b, 0xe8, 0x71, 0xd7, 0xfc, 0x31, 0x17, 0xa9, 0x6a, 0x13, 0xe9, 0x9c,
+                0x38, 0xd1, 0x37, 0xb0, 0xe3, 0x69, 0xc0, 0x43, 0xe6, 0x87, 0x3f, 0xe3, 0x1b, 0xda, 0x78, 0xa3,
+            ],
+        ),
+        point(
+            &[
+                0x89, 0x8c, 0x34, 0x93, 0xcb, 0x25, 0x97, 0x61, 0x52, 0x86, 0xdc, 0x5c, 0xb1, 0xe8, 0x6c, 0xe1,
+                0x06, 0x8c, 0xbd, 0x14, 0x34, 0x8c, 0xff, 0x1a, 0x30, 0xe6, 0x91, 0xfc, 0xdc, 0xa1, 0xf6, 0xa1,
+            ],
+            &[
+                0x75, 0xf7, 0x59, 0x86, 0xab, 0x56, 0xa5, 0x54, 0x9d, 0x84, 0x54, 0x24, 0x52, 0xac, 0x6e, 0x93,
+                0xb2, 0xa7, 0xcf, 0x97, 0x9f, 0x2b, 0xd7, 0x9c, 0xa4, 0xad, 0xc2, 0x0f, 0x16, 0x4f, 0x64, 0x7c,
+            ],
+        ),
+        point(
+            &[
+                0x06, 0x3c, 0x46, 0x24, 0x35, 0xef, 0x97, 0x4b, 0x39, 0x3b, 0x05, 0xb3, 0x7d, 0x1c, 0x89, 0xd7,
+                0x0b, 0x0d, 0x89, 0x58, 0xeb, 0xd5, 0x41, 0xd7, 0x58, 0x4e, 0x2b, 0xbc, 0x72, 0x35, 0xc7, 0x95,
+            ],
+            &[
+                0xe2, 0x7f, 0x9b, 0xb9, 0x13, 0x03, 0x84, 0x04, 0x43, 0x1f, 0x66, 0x0d, 0x93, 0x1c, 0x85, 0xb6,
+                0x9e, 0x4a, 0xcb, 0xac, 0xfa, 0x49, 0xa9, 0xbd, 0x3f, 0x32, 0xdc, 0x09, 0x5b, 0x11, 0x02, 0x58,
+            ],
+        ),
+        point(
+            &[
+                0xb2, 0x13, 0xe2, 0xfe, 0xd2, 0x91, 0x8b, 0xf0, 0x1a, 0x52, 0x99, 0xd7, 0x02, 0x2a, 0x27, 0x4e,
+                0x5e, 0x56, 0xc8, 0xb9, 0x17, 0xa0, 0x43, 0x28, 0xaa, 0x69, 0xe0, 0x3c, 0x3d, 0x1e, 0x39, 0x98,
+            ],
+            &[
+                0x22, 0x9f, 0x8e, 0xc2, 0x0f, 0x2d, 0x3c, 0x12, 0xc3, 0xd6, 0x1e, 0xbf, 0x83, 0xa4, 0x3b, 0xc3,
+                0xd5, 0x34, 0x16, 0x5b, 0xeb, 0xde, 0xd1, 0x75, 0xec, 0x2c, 0xbd, 0xc6, 0x32, 0x5f, 0xb8, 0x1e,
+            ],
+        ),
+        point(
+            &[
+                0x79, 0x66, 0x34, 0xe3, 0xf1, 0xad, 0x56, 0xf0, 0xfd, 0xba, 0x06, 0x9d, 0x9d, 0x07, 0xbc, 0xe2,
+                0xba, 0x2f, 0xd4, 0xf3, 0x73, 0xdd, 0xd3, 0xba, 0x77, 0x77, 0xbf, 0x27, 0x9f, 0x10, 0x48, 0xda,
+            ],
+            &[
+                0x4d, 0x8e, 0xe2, 0xb6, 0xcf, 0xb2, 0x0b, 0x89, 0x56, 0xde, 0x74, 0x73, 0x5a, 0x79, 0x27, 0xf2,
+                0x53, 0x25, 0x76, 0xd8, 0xcf, 0xd7, 0x48, 0x62, 0xe8, 0xf9, 0xbe, 0x24, 0xa1, 0x06, 0xcf, 0x01,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xa3, 0x01, 0x69, 0x7b, 0xdf, 0xcd, 0x70, 0x43, 0x13, 0xba, 0x48, 0xe5, 0x1d, 0x56, 0x75, 0x43,
+                0xf2, 0xa1, 0x82, 0x03, 0x1e, 0xfd, 0x69, 0x15, 0xdd, 0xc0, 0x7b, 0xbc, 0xc4, 0xe1, 0x60, 0x70,
+            ],
+            &[
+                0x73, 0x70, 0xf9, 0x1c, 0xfb, 0x67, 0xe4, 0xf5, 0x08, 0x18, 0x09, 0xfa, 0x25, 0xd4, 0x0f, 0x9b,
+                0x17, 0x35, 0xdb, 0xf7, 0xc0, 0xa1, 0x1a, 0x13, 0x0c, 0x0d, 0x1a, 0x04, 0x1e, 0x17, 0x7e, 0xa1,
+            ],
+        ),
+        point(
+            &[
+                0x27, 0xe1, 0xe5, 0x9c, 0xff, 0x79, 0xf0, 0x49, 0xf3, 0xe8, 0xd2, 0x41, 0x9e, 0x0b, 0xff, 0x74,
+                0xb4, 0x39, 0x65, 0x00, 0x4c, 0x34, 0xb5, 0xd8, 0x11, 0x42, 0x03, 0x16, 0xf2, 0x4b, 0xa5, 0xae,
+            ],
+            &[
+                0x31, 0x0b, 0x26, 0xa6, 0xc8, 0x04, 0xe2, 0x09, 0xee, 0x1b, 0x5e, 0x3c, 0xfc, 0x79, 0xdf, 0x05,
+                0xdf, 0x48, 0xa1, 0xa6, 0x9a, 0xfa, 0x63, 0xf7, 0x84, 0xa5, 0xbf, 0xee, 0x88, 0x3a, 0x45, 0xb3,
+            ],
+        ),
+        point(
+            &[
+                0x6e, 0x83, 0x13, 0xa3, 0x08, 0x15, 0xeb, 0x11, 0x15, 0x6b, 0x13, 0x30, 0x82, 0x20, 0x0a, 0x4d,
+                0x83, 0x59, 0x6a, 0x67, 0xad, 0x72, 0x85, 0x62, 0x67, 0x01, 0x27, 0x00, 0x13, 0x80, 0x11, 0xfc,
+            ],
+            &[
+                0xc1, 0x47, 0x81, 0x8b, 0xdc, 0x24, 0xf2, 0x04, 0xc1, 0xa1, 0x2d, 0xb2, 0x01, 0xda, 0xc3, 0x04,
+                0x26, 0xaf, 0x91, 0x5a, 0xe9, 0xc5, 0x1f, 0x9a, 0x6a, 0xcb, 0x69, 0xfa, 0x3f, 0x15, 0xab, 0x7d,
+            ],
+        ),
+        point(
+            &[
+                0xc7, 0x12, 0xe7, 0xa5, 0xf6, 0x86, 0x4a, 0xee, 0x16, 0x58, 0x8e, 0xc3, 0x89, 0x2d, 0x7e, 0x4f,
+                0x5a, 0x39, 0xad, 0xde, 0x84, 0xfb, 0xfb, 0x4f, 0x99, 0x69, 0x17, 0x5c, 0x9c, 0xae, 0xd7, 0xae,
+            ],
+            &[
+                0x49, 0x64, 0x41, 0x07, 0x51, 0x63, 0x63, 0xb3, 0x65, 0xed, 0x4b, 0x82, 0x31, 0x1d, 0xd9, 0xe5,
+                0x38, 0x0d, 0x8e, 0x54, 0x4b, 0x0c, 0xe6, 0x37, 0x84, 0xd1, 0x48, 0xaa, 0x46, 0x15, 0x62, 0x94,
+            ],
+        ),
+        point(
+            &[
+                0xf9, 0x52, 0xa9, 0x09, 0x97, 0x84, 0x85, 0x1f, 0x2b, 0xd1, 0xe0, 0x38, 0xa4, 0xd9, 0xe1, 0xb4,
+                0x3d, 0x36, 0xec, 0x5b, 0x44, 0x91, 0x6f, 0x7f, 0x53, 0xa7, 0x49, 0xb8, 0xd0, 0x0e, 0x6b, 0xa7,
+            ],
+            &[
+                0xd8, 0xa9, 0x3a, 0x5b, 0x08, 0xab, 0xce, 0xbf, 0x86, 0x13, 0x76, 0xa2, 0xe2, 0x7f, 0xa0, 0xf6,
+                0x55, 0x57, 0x16, 0x7b, 0x4c, 0x62, 0xa2, 0xb9, 0x8d, 0xba, 0xee, 0xe5, 0x01, 0x75, 0xe4, 0xc1,
+            ],
+        ),
+        point(
+            &[
+                0xa5, 0xac, 0x7d, 0x1d, 0x04, 0xcd, 0xa3, 0x0c, 0x83, 0xa0, 0x53, 0xaa, 0xd0, 0x98, 0x76, 0xcd,
+                0xbe, 0xb3, 0x2b, 0x53, 0xd3, 0x8b, 0x20, 0x21, 0xf9, 0x54, 0x9f, 0x5c, 0x59, 0x5b, 0x6f, 0x7e,
+            ],
+            &[
+                0xdf, 0x0b, 0x8a, 0x0a, 0xb5, 0x40, 0xf5, 0x5b, 0x36, 0xcc, 0x3c, 0x92, 0x47, 0xd9, 0xee, 0x1b,
+                0x99, 0xe3, 0x91, 0x60, 0xf5, 0x81, 0x8a, 0x48, 0x48, 0xdb, 0xb7, 0x30, 0x8a, 0xb1, 0x9c, 0x84,
+            ],
+        ),
+        point(
+            &[
+                0x94, 0x01, 0x6d, 0x5e, 0x31, 0xd3, 0xfe, 0xe7, 0x50, 0x40, 0x31, 0xa1, 0x9d, 0x9e, 0x89, 0x3a,
+                0x8f, 0x2e, 0x39, 0x43, 0xae, 0xfb, 0x1f, 0x62, 0x69, 0x00, 0x65, 0xa2, 0x83, 0xaa, 0x0e, 0x93,
+            ],
+            &[
+                0x67, 0x50, 0x32, 0xee, 0x5c, 0x45, 0x4d, 0x96, 0x40, 0x35, 0x5d, 0x35, 0x4e, 0xee, 0x6f, 0xd7,
+                0xf4, 0x84, 0x37, 0x3b, 0xaa, 0x57, 0xb0, 0x7a, 0x38, 0xee, 0xd2, 0x68, 0x87, 0xad, 0xda, 0xc2,
+            ],
+        ),
+        point(
+            &[
+                0x0b, 0xfc, 0x05, 0x04, 0xa4, 0xb3, 0x23, 0x5d, 0x06, 0x5c, 0x0d, 0x42, 0x6b, 0x86, 0x75, 0xfc,
+                0xb2, 0xc8, 0x5d, 0x6f, 0x58, 0x27, 0x5d, 0x79, 0x1b, 0x43, 0xe1, 0xfe, 0x44, 0xa6, 0xdb, 0x03,
+            ],
+            &[
+                0x19, 0x55, 0x46, 0x7a, 0x6c, 0x34, 0xf3, 0x45, 0x3f, 0xb8, 0xec, 0x7f, 0x94, 0xa6, 0xc9, 0x92,
+                0x37, 0x42, 0x71, 0x97, 0x34, 0x5d, 0x4f, 0x05, 0x58, 0xac, 0x8d, 0x1a, 0x46, 0x4b, 0x85, 0x42,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x8f, 0x68, 0xb9, 0xd2, 0xf6, 0x3b, 0x5f, 0x33, 0x92, 0x39, 0xc1, 0xad, 0x98, 0x1f, 0x16, 0x2e,
+                0xe8, 0x8c, 0x56, 0x78, 0x72, 0x3e, 0xa3, 0x35, 0x1b, 0x7b, 0x44, 0x4c, 0x9e, 0xc4, 0xc0, 0xda,
+            ],
+            &[
+                0x66, 0x2a, 0x9f, 0x2d, 0xba, 0x06, 0x39, 0x86, 0xde, 0x1d, 0x90, 0xc2, 0xb6, 0xbe, 0x21, 0x5d,
+                0xbb, 0xea, 0x2c, 0xfe, 0x95, 0x51, 0x0b, 0xfd, 0xf2, 0x3c, 0xbf, 0x79, 0x50, 0x1f, 0xff, 0x82,
+            ],
+        ),
+        point(
+            &[
+                0x4d, 0x49, 0xae, 0xfd, 0x78, 0x4e, 0x81, 0x58, 0xfc, 0xaf, 0xeb, 0xe7, 0x7f, 0xd9, 0xaf, 0x59,
+                0xd8, 0x98, 0x58, 0xad, 0xe7, 0x62, 0x7e, 0xae, 0xe6, 0x84, 0x7d, 0xf8, 0x4c, 0xf2, 0x70, 0x76,
+            ],
+            &[
+                0xcd, 0x32, 0xfc, 0x59, 0xa1, 0x0d, 0xd1, 0x35, 0xe7, 0x23, 0xf2, 0x10, 0x35, 0x9c, 0xa6, 0xf0,
+                0x6e, 0x0f, 0x2d, 0x1a, 0x7d, 0xf4, 0xd8, 0x46, 0x6b, 0x90, 0xb6, 0x62, 0x03, 0xaa, 0x78, 0x1e,
+            ],
+        ),
+        point(
+            &[
+                0x38, 0x38, 0x1d, 0xbe, 0x2e, 0x50, 0x9f, 0x22, 0x8b, 0xa9, 0x33, 0x63, 0xf2, 0x45, 0x1f, 0x08,
+                0xfd, 0x84, 0x5c, 0xb3, 0x51, 0xd9, 0x54, 0xbe, 0x18, 0xe2, 0xb8, 0xed, 0xd2, 0x38, 0x09, 0xfa,
+            ],
+            &[
+                0xe4, 0xa3, 0x2d, 0x0a, 0x0f, 0xb9, 0x17, 0xdc, 0xb0, 0x94, 0x05, 0xa5, 0x52, 0x0e, 0xb1, 0xcc,
+                0x36, 0x81, 0xfc, 0xcb, 0x32, 0xd8, 0xf2, 0x4d, 0xbd, 0x70, 0x75, 0x18, 0x33, 0x1f, 0xed, 0x52,
+            ],
+        ),
+        point(
+            &[
+                0x75, 0x64, 0x53, 0x9e, 0x85, 0xd5, 0x6f, 0x85, 0x37, 0xd6, 0x61, 0x9e, 0x1f, 0x5c, 0x5a, 0xa7,
+                0x8d, 0x2a, 0x3d, 0xe0, 0x88, 0x9d, 0x1d, 0x4e, 0xe8, 0xdb, 0xcb, 0x57, 0x29, 0xb6, 0x20, 0x26,
+            ],
+            &[
+                0xc1, 0xd6, 0x85, 0x41, 0x37, 0x49, 0xb3, 0xc6, 0x52, 0x31, 0xdf, 0x52, 0x4a, 0x72, 0x29, 0x25,
+                0x68, 0x4a, 0xac, 0xd9, 0x54, 0xb7, 0x9f, 0x33, 0x41, 0x72, 0xc8, 0xfa, 0xda, 0xce, 0x0c, 0xf3,
+            ],
+        ),
+        point(
+            &[
+                0x49, 0x26, 0x27, 0x24, 0xe4, 0x37, 0x2a, 0xe6, 0xf6, 0x92, 0x1b, 0x82, 0xaa, 0x46, 0x99, 0xa1,
+                0xf1, 0x86, 0xae, 0xa5, 0x40, 0x12, 0x26, 0x30, 0x3e, 0xa4, 0x26, 0x48, 0x97, 0xc2, 0xa3, 0x10,
+            ],
+            &[
+                0x13, 0x37, 0xe7, 0x73, 0xbc, 0xa7, 0xab, 0xf9, 0x5a, 0x2c, 0xfa, 0x56, 0x97, 0x14, 0x30, 0x3b,
+                0x6d, 0x16, 0x36, 0x12, 0xa7, 0x5f, 0xf8, 0xce, 0x0c, 0x41, 0xb6, 0x81, 0x5e, 0x27, 0xde, 0xd0,
+            ],
+        ),
+        point(
+            &[
+                0x6a, 0x66, 0x4a, 0x35, 0x6a, 0xa5, 0x70, 0x5e, 0x68, 0x08, 0xa6, 0xed, 0x7c, 0x44, 0xaa, 0x2b,
+                0xa5, 0xa3, 0x62, 0x91, 0x9f, 0x5d, 0x0b, 0x81, 0xf8, 0x16, 0x6c, 0x19, 0x03, 0x66, 0x3d, 0xa4,
+            ],
+            &[
+                0x44, 0x9a, 0x12, 0x59, 0x54, 0xfd, 0xe9, 0x8b, 0x29, 0xf8, 0x6e, 0xc1, 0x96, 0xbf, 0x0c, 0xd5,
+                0x08, 0x99, 0x16, 0x12, 0x7e, 0x6c, 0x04, 0xc9, 0xc2, 0x83, 0x13, 0xfb, 0x33, 0xfc, 0x22, 0xc4,
+            ],
+        ),
+        point(
+            &[
+                0xe3, 0x06, 0x56, 0x8c, 0x1a, 0x24, 0x0c, 0x90, 0xd5, 0xe2, 0x53, 0xb3, 0xe4, 0x77, 0xe2, 0xf8,
+                0x4d, 0xcc, 0x1a, 0x56, 0xff, 0x06, 0xdb, 0x8d, 0x13, 0x84, 0xb0, 0x79, 0xce, 0xbd, 0x2d, 0x31,
+            ],
+            &[
+                0x0e, 0xac, 0x6f, 0xe3, 0x78, 0x93, 0x42, 0x60, 0x88, 0x8f, 0x2b, 0x10, 0x7f, 0x7d, 0x0d, 0xb6,
+                0xff, 0xbc, 0x80, 0x42, 0xbe, 0x37, 0x38, 0x26, 0x69, 0x2b, 0x40, 0x83, 0x92, 0x54, 0x6e, 0x44,
+            ],
+        ),
+        point(
+            &[
+                0x21, 0x0a, 0x91, 0x7a, 0xd9, 0xdf, 0x27, 0x79, 0x67, 0x46, 0xff, 0x30, 0x1a, 0xd9, 0xcc, 0xc8,
+                0x78, 0xf6, 0x1a, 0x5f, 0x1f, 0xf4, 0x08, 0x2b, 0x53, 0x64, 0xda, 0xcd, 0x57, 0xb4, 0xa2, 0x78,
+            ],
+            &[
+                0x67, 0x0e, 0x1b, 0x54, 0x50, 0xb5, 0xe5, 0x7b, 0x7a, 0x39, 0xbe, 0x81, 0xf8, 0xd6, 0x73, 0x7d,
+                0x37, 0x89, 0xe6, 0x1a, 0xaf, 0xf2, 0x0b, 0xfc, 0x7f, 0x27, 0x13, 0xfd, 0x0c, 0x7b, 0x22, 0x31,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x8c, 0x00, 0xfa, 0x9b, 0x18, 0xeb, 0xf3, 0x31, 0xeb, 0x96, 0x15, 0x37, 0xa4, 0x5a, 0x42, 0x66,
+                0xc7, 0x03, 0x4f, 0x2f, 0x0d, 0x4e, 0x1d, 0x07, 0x16, 0xfb, 0x6e, 0xae, 0x20, 0xea, 0xe2, 0x9e,
+            ],
+            &[
+                0xef, 0xa4, 0x72, 0x67, 0xfe, 0xa5, 0x21, 0xa1, 0xa9, 0xdc, 0x34, 0x3a, 0x37, 0x36, 0xc9, 0x74,
+                0xc2, 0xfa, 0xda, 0xfa, 0x81, 0xe3, 0x6c, 0x54, 0xe7, 0xd2, 0xa4, 0xc6, 0x67, 0x02, 0x41, 0x4b,
+            ],
+        ),
+        point(
+            &[
+                0x24, 0xcf, 0xc0, 0x17, 0x6d, 0xa2, 0xb4, 0x6f, 0xa8, 0xbb, 0x5b, 0xf9, 0x63, 0x6b, 0xe1, 0xef,
+                0xfd, 0x7e, 0x29, 0x7f, 0x29, 0x12, 0x2f, 0xb3, 0xe8, 0x4c, 0x9a, 0xb0, 0xc1, 0x8a, 0xda, 0x5f,
+            ],
+            &[
+                0xeb, 0xff, 0x8f, 0xbb, 0x07, 0x9c, 0x61, 0xa6, 0x98, 0x68, 0x71, 0x4d, 0x5d, 0xed, 0xa9, 0x27,
+                0xed, 0x95, 0x9c, 0xa1, 0xa4, 0xf8, 0x14, 0xf2, 0x68, 0xfa, 0x61, 0x39, 0x97, 0x8a, 0x58, 0x6b,
+            ],
+        ),
+        point(
+            &[
+                0x36, 0x36, 0x2a, 0xa7, 0xe9, 0x07, 0xdd, 0xf8, 0x74, 0xd0, 0x7a, 0x08, 0x4c, 0x2a, 0x8d, 0x20,
+                0x50, 0xa6, 0x80, 0xe6, 0xee, 0x54, 0xc9, 0xea, 0xc3, 0xf9, 0x56, 0x03, 0xeb, 0xfd, 0x91, 0x3d,
+            ],
+            &[
+                0x48, 0xf2, 0x78, 0x67, 0x6c, 0xb8, 0xaf, 0xd5, 0x34, 0x16, 0x24, 0x43, 0x70, 0xda, 0x2a, 0x82,
+                0xd8, 0x30, 0xbb, 0x10, 0xd6, 0xb2, 0xfa, 0xaf, 0xc4, 0x4f, 0x9a, 0xea, 0xc5, 0x2e, 0x24, 0x3d,
+            ],
+        ),
+        point(
+            &[
+                0x00, 0x4a, 0x7d, 0x58, 0xd4, 0xb9, 0xbc, 0x82, 0xea, 0x2d, 0xed, 0x72, 0xa1, 0x29, 0x2e, 0xc6,
+                0x16, 0xdd, 0xd6, 0x7f, 0xc7, 0xf0, 0x57, 0xed, 0xf1, 0x03, 0x18, 0x95, 0x94, 0x67, 0x9d, 0xa2,
+            ],
+            &[
+                0xb9, 0x8a, 0xc5, 0xb7, 0x67, 0x02, 0xcb, 0x75, 0xe6, 0xb1, 0xd8, 0x14, 0x7e, 0xc7, 0x1b, 0x3b,
+                0x71, 0xc3, 0xb4, 0x94, 0x96, 0x3f, 0xa2, 0x8a, 0x48, 0x77, 0xf4, 0x84, 0x77, 0x9f, 0xfe, 0x26,
+            ],
+        ),
+        point(
+            &[
+                0x44, 0x87, 0x97, 0x6d, 0xf3, 0x2a, 0x1e, 0x02, 0xf2, 0x95, 0xad, 0x96, 0x2d, 0xd9, 0x20, 0x0d,
+                0xfc, 0xb1, 0xe3, 0xba, 0xb7, 0xbc, 0x6c, 0x96, 0x7c, 0xab, 0xab, 0xf9, 0xad, 0x13, 0x28, 0x96,
+            ],
+            &[
+                0x27, 0xbd, 0x58, 0x60, 0xd1, 0x15, 0xaf, 0xe1, 0xef, 0xc9, 0xa9, 0x07, 0x74, 0x56, 0x1a, 0x33,
+                0x06, 0xb4, 0x0d, 0x5a, 0x62, 0x76, 0xaa, 0x7a, 0x48, 0xc0, 0x1b, 0x12, 0xaf, 0x68, 0x52, 0x48,
+            ],
+        ),
+        point(
+            &[
+                0x8f, 0x3c, 0xcf, 0x31, 0xf8, 0xb7, 0x4b, 0x9b, 0x62, 0x4a, 0x5d, 0x1b, 0x7c, 0xb1, 0x09, 0x6e,
+                0x20, 0x2f, 0xe5, 0xe7, 0x23, 0x37, 0x77, 0xaa, 0x85, 0x98, 0x64, 0xd3, 0x77, 0x57, 0x32, 0xc0,
+            ],
+            &[
+                0x67, 0xf1, 0xcd, 0x3e, 0x2d, 0x39, 0xe5, 0x31, 0x89, 0xef, 0x46, 0xd9, 0x97, 0xce, 0x53, 0xd8,
+                0xa9, 0x0e, 0x68, 0x77, 0x54, 0x85, 0x88, 0x25, 0xe7, 0x3b, 0xde, 0x5e, 0x65, 0xb9, 0x41, 0x5b,
+            ],
+        ),
+        point(
+            &[
+                0x4a, 0x4d, 0x3a, 0xc2, 0x8b, 0xcb, 0x83, 0x78, 0x8b, 0x6a, 0x9c, 0xaa, 0x83, 0x35, 0x03, 0x24,
+                0x74, 0x00, 0xf8, 0x2b, 0xa0, 0x62, 0x73, 0xe6, 0xe0, 0x81, 0x56, 0xf6, 0xbf, 0xa2, 0x67, 0x0c,
+            ],
+            &[
+                0x70, 0xab, 0xb9, 0x1c, 0x01, 0x84, 0x5a, 0x4f, 0x17, 0xe7, 0x71, 0x1d, 0xde, 0xf0, 0x2b, 0x9d,
+                0x49, 0x30, 0x59, 0x4c, 0xbf, 0x29, 0xbe, 0xed, 0x80, 0x8b, 0x9f, 0xfd, 0xd6, 0xc1, 0x76, 0x4d,
+            ],
+        ),
+        point(
+            &[
+                0xee, 0x7d, 0x69, 0xc4, 0xcb, 0xd0, 0x01, 0xc7, 0xfc, 0x76, 0xc5, 0xe2, 0xc0, 0x66, 0xce, 0x49,
+                0x96, 0xf8, 0x80, 0x8a, 0x1e, 0x07, 0xb2, 0xa9, 0xcc, 0xf3, 0x4e, 0xad, 0xc8, 0x7c, 0x4b, 0x65,
+            ],
+            &[
+                0xec, 0xc8, 0x62, 0x6e, 0xc1, 0xa4, 0x13, 0x82, 0x1a, 0x19, 0x2a, 0xbf, 0x03, 0x0f, 0x2e, 0xe2,
+                0xc3, 0x3e, 0x89, 0x99, 0xba, 0xe9, 0x42, 0xe5, 0x23, 0xe8, 0xf4, 0x4e, 0xd1, 0x36, 0xa9, 0x5a,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xb6, 0x45, 0x9e, 0x0e, 0xe3, 0x66, 0x2e, 0xc8, 0xd2, 0x35, 0x40, 0xc2, 0x23, 0xbc, 0xbd, 0xc5,
+                0x71, 0xcb, 0xcb, 0x96, 0x7d, 0x79, 0x42, 0x4f, 0x3c, 0xf2, 0x9e, 0xb3, 0xde, 0x6b, 0x80, 0xef,
+            ],
+            &[
+                0x06, 0x7c, 0x87, 0x6d, 0x06, 0xf3, 0xe0, 0x6d, 0xe1, 0xda, 0xdf, 0x16, 0xe5, 0x66, 0x1d, 0xb3,
+                0xc4, 0xb3, 0xae, 0x6d, 0x48, 0xe3, 0x5b, 0x2f, 0xf3, 0x0b, 0xf0, 0xb6, 0x1a, 0x71, 0xba, 0x45,
+            ],
+        ),
+        point(
+            &[
+                0xe5, 0xd8, 0xe8, 0xf0, 0xd9, 0x82, 0x3c, 0x88, 0xe4, 0xd3, 0x6f, 0x73, 0x01, 0xf4, 0x15, 0x93,
+                0xb6, 0x89, 0x05, 0x76, 0xbe, 0x79, 0xc2, 0x11, 0x25, 0x3e, 0xf3, 0x75, 0x03, 0x3e, 0xb5, 0x1f,
+            ],
+            &[
+                0x4d, 0xc1, 0xe9, 0xb7, 0x86, 0x1e, 0x3e, 0x04, 0xab, 0xb1, 0x6a, 0x57, 0xd8, 0xfe, 0xee, 0xf0,
+                0xe5, 0x09, 0xdc, 0x46, 0xd9, 0xf0, 0xf5, 0x49, 0x79, 0xd5, 0xbd, 0x96, 0x5a, 0x62, 0xa2, 0xd9,
+            ],
+        ),
+        point(
+            &[
+                0x1f, 0x90, 0xea, 0x77, 0x3a, 0xc3, 0xa6, 0xe2, 0xdd, 0xe6, 0x0d, 0x30, 0x29, 0x66, 0x81, 0x67,
+                0x3d, 0x12, 0xba, 0x6b, 0xf2, 0x44, 0x8a, 0x8b, 0x43, 0x9c, 0xf2, 0x79, 0x31, 0x98, 0x88, 0xe9,
+            ],
+            &[
+                0x89, 0xbe, 0x36, 0x7c, 0x15, 0xda, 0xa1, 0x0e, 0x95, 0x81, 0x53, 0xd2, 0x71, 0xeb, 0x96, 0xa8,
+                0x21, 0x37, 0x51, 0xfe, 0xe5, 0x95, 0x22, 0xe6, 0x56, 0xfd, 0xfc, 0x97, 0xef, 0x11, 0x3b, 0x79,
+            ],
+        ),
+        point(
+            &[
+                0xa9, 0xca, 0x27, 0xf7, 0x7d, 0xbc, 0x8c, 0x3d, 0xc5, 0x6b, 0x0f, 0x73, 0x21, 0xba, 0xe0, 0xdd,
+                0xab, 0x66, 0xbe, 0x4f, 0xa8, 0xa3, 0x01, 0x17, 0x37, 0xa6, 0x76, 0x48, 0x0f, 0x15, 0x5e, 0x64,
+            ],
+            &[
+                0xf4, 0xbb, 0x33, 0x56, 0x78, 0xfb, 0x14, 0xd4, 0xd1, 0x97, 0xd2, 0x24, 0x6c, 0x02, 0xd0, 0x04,
+                0x87, 0x5d, 0x41, 0x82, 0x1b, 0xca, 0xf0, 0xae, 0x1f, 0x3f, 0x33, 0x3c, 0x56, 0x1b, 0x32, 0x97,
+            ],
+        ),
+        point(
+            &[
+                0x13, 0xa4, 0xe5, 0x4d, 0xed, 0xff, 0xe0, 0xad, 0x6e, 0x70, 0x2b, 0xad, 0x33, 0x4b, 0x52, 0xba,
+                0xde, 0x03, 0x44, 0x79, 0x91, 0xe6, 0xf2, 0xc6, 0x08, 0x5e, 0xfb, 0x6f, 0x35, 0x62, 0x22, 0x2c,
+            ],
+            &[
+                0xc9, 0xd6, 0x7d, 0x4e, 0x58, 0x16, 0xe8, 0x13, 0xb2, 0xca, 0xc2, 0xf8, 0x1d, 0x60, 0x9a, 0x52,
+                0xd4, 0xf9, 0xc5, 0x77, 0xad, 0xc9, 0x04, 0xc0, 0x8c, 0xb1, 0x66, 0x8c, 0xa8, 0x20, 0x01, 0x45,
+            ],
+        ),
+        point(
+            &[
+                0xd0, 0x2e, 0x1b, 0x3c, 0xd6, 0xc1, 0x05, 0xc7, 0xee, 0x74, 0x65, 0x48, 0x16, 0xce, 0xe7, 0x4e,
+                0xf7, 0x79, 0x3d, 0x9b, 0x11, 0x98, 0x87, 0x53, 0x93, 0xea, 0xb3, 0xdd, 0xc1, 0xbf, 0x6c, 0x42,
+            ],
+            &[
+                0x9a, 0x0b, 0x74, 0xf3, 0xca, 0x8e, 0x22, 0x58, 0x12, 0x73, 0x22, 0x70, 0xd0, 0xd2, 0x45, 0x26,
+                0xbc, 0xa5, 0x87, 0x9b, 0x93, 0x1b, 0x6a, 0xac, 0x13, 0x36, 0x32, 0x8f, 0x87, 0xbc, 0x54, 0xcc,
+            ],
+        ),
+        point(
+            &[
+                0x61, 0x99, 0x1e, 0xbf, 0x23, 0x3c, 0xaa, 0xdd, 0x1d, 0x40, 0x7c, 0x05, 0xd8, 0x45, 0x5d, 0xbd,
+                0x12, 0xed, 0x3a, 0x63, 0xfa, 0xc9, 0x25, 0x25, 0x43, 0x7e, 0x4d, 0xc0, 0xf4, 0x3b, 0x46, 0xbb,
+            ],
+            &[
+                0x01, 0xc5, 0xe3, 0x08, 0xf3, 0xfd, 0xe4, 0x92, 0x95, 0x2f, 0x38, 0x71, 0x66, 0xfb, 0xad, 0xf1,
+                0x1c, 0xa8, 0x72, 0x9c, 0xa5, 0x2e, 0xcd, 0x8e, 0xf4, 0x8f, 0x59, 0x01, 0xcc, 0x20, 0xa8, 0x48,
+            ],
+        ),
+        point(
+            &[
+                0x68, 0xfb, 0x71, 0x80, 0x06, 0x86, 0xd7, 0xf2, 0x5e, 0xba, 0x10, 0x56, 0x11, 0xcf, 0xe7, 0x59,
+                0x1f, 0x47, 0x8e, 0x84, 0x7f, 0x51, 0xce, 0xe0, 0x6d, 0x4b, 0xc6, 0x29, 0xd6, 0xee, 0x24, 0x7c,
+            ],
+            &[
+                0xcd, 0x12, 0xd2, 0x34, 0x62, 0xdd, 0x96, 0x36, 0x73, 0x73, 0x54, 0x27, 0x50, 0x1b, 0x0c, 0x07,
+                0x9a, 0x8d, 0x58, 0x0b, 0x04, 0xc7, 0x3c, 0x9d, 0xae, 0x1f, 0x82, 0x2d, 0x1a, 0x01, 0x86, 0x5d,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x32, 0x4a, 0xed, 0x7d, 0xf6, 0x5c, 0x80, 0x42, 0x52, 0xdc, 0x02, 0x70, 0x90, 0x7a, 0x30, 0xb0,
+                0x96, 0x12, 0xae, 0xb9, 0x73, 0x44, 0x9c, 0xea, 0x40, 0x95, 0x98, 0x0f, 0xc2, 0x8d, 0x3d, 0x5d,
+            ],
+            &[
+                0x64, 0x8a, 0x36, 0x57, 0x74, 0xb6, 0x1f, 0x2f, 0xf1, 0x30, 0xc0, 0xc3, 0x5a, 0xec, 0x1f, 0x4f,
+                0x19, 0x21, 0x3b, 0x0c, 0x7e, 0x33, 0x28, 0x43, 0x96, 0x72, 0x24, 0xaf, 0x96, 0xab, 0x7c, 0x84,
+            ],
+        ),
+        point(
+            &[
+                0x32, 0xc9, 0x33, 0x1e, 0xa2, 0x6f, 0x49, 0x02, 0x28, 0xd3, 0x26, 0x81, 0x88, 0x0d, 0x72, 0x03,
+                0xf7, 0x2b, 0x3e, 0x4a, 0x8d, 0xe0, 0xdb, 0x1f, 0xa8, 0xf3, 0x83, 0x81, 0xb2, 0x91, 0x97, 0x49,
+            ],
+            &[
+                0xd7, 0xcd, 0x27, 0x2b, 0x34, 0x20, 0x9c, 0xb5, 0x69, 0x5a, 0x2f, 0x02, 0xb6, 0xf3, 0xdb, 0xb8,
+                0x26, 0x8a, 0x4a, 0xbd, 0xae, 0x39, 0xab, 0x09, 0x63, 0x1e, 0x97, 0xb0, 0xf2, 0x90, 0xb5, 0xe3,
+            ],
+        ),
+        point(
+            &[
+                0x20, 0x84, 0x0b, 0xd5, 0x99, 0x67, 0x72, 0xad, 0x5b, 0x8f, 0x60, 0xb9, 0x31, 0xdf, 0x7c, 0x49,
+                0x16, 0x3f, 0x74, 0xfb, 0x9d, 0xa5, 0x6c, 0xce, 0xf5, 0xc9, 0x17, 0x58, 0x2f, 0xd5, 0x3e, 0xd3,
+            ],
+            &[
+                0xf2, 0x99, 0x34, 0x97, 0xce, 0xc1, 0x82, 0x43, 0x48, 0x7b, 0xd4, 0x76, 0xa6, 0xba, 0xfa, 0xc2,
+                0x54, 0x87, 0xc4, 0x73, 0x94, 0xe7, 0x08, 0x99, 0x87, 0x14, 0x3f, 0xe5, 0x1a, 0x7a, 0x71, 0x32,
+            ],
+        ),
+        point(
+            &[
+                0xeb, 0x29, 0x2f, 0x3b, 0x3b, 0x98, 0x37, 0x85, 0x4a, 0x02, 0xf6, 0xa7, 0x0f, 0xec, 0x6b, 0x1c,
+                0x69, 0xc1, 0x61, 0xb6, 0xe1, 0x84, 0x6b, 0x8e, 0x1e, 0x1c, 0x22, 0x52, 0x7b, 0x97, 0x95, 0xe4,
+            ],
+            &[
+                0x8c, 0x43, 0xc2, 0x5a, 0x96, 0xee, 0xbe, 0x80, 0x16, 0x96, 0x63, 0x4a, 0xf1, 0x45, 0x83, 0x5b,
+                0x57, 0x13, 0x1d, 0x75, 0x09, 0x11, 0x1c, 0x6f, 0x5b, 0x7e, 0x9d, 0x2f, 0xae, 0x53, 0xa0, 0xfe,
+            ],
+        ),
+        point(
+            &[
+                0xe6, 0xb6, 0xbf, 0xf6, 0x0e, 0xb3, 0x39, 0xbb, 0xbd, 0x13, 0xd0, 0x29, 0xe5, 0x88, 0xeb, 0xfd,
+                0x1a, 0xb5, 0xd8, 0x8a, 0x5c, 0x0a, 0x12, 0x1e, 0xdb, 0xd2, 0xcb, 0xb5, 0x88, 0xa3, 0x5b, 0x35,
+            ],
+            &[
+                0x1a, 0x5f, 0xf2, 0xbd, 0x33, 0x00, 0xd2, 0xf3, 0x26, 0x6f, 0x43, 0xe3, 0x83, 0x59, 0x61, 0xdd,
+                0xfe, 0x3b, 0x6c, 0x9d, 0x3b, 0xe9, 0x99, 0xc7, 0xc5, 0x72, 0x93, 0xdb, 0x9c, 0x10, 0x07, 0xbd,
+            ],
+        ),
+        point(
+            &[
+                0x25, 0xaa, 0xc6, 0xbd, 0x9a, 0x6b, 0x26, 0x40, 0xed, 0x23, 0x74, 0xcb, 0x31, 0xff, 0x8f, 0x63,
+                0xce, 0x56, 0x6f, 0x50, 0xfa, 0x1f, 0xc6, 0xcc, 0xc4, 0x8b, 0x8e, 0x29, 0x20, 0x32, 0xf9, 0xa2,
+            ],
+            &[
+                0xf5, 0xa6, 0xc6, 0x3b, 0xa6, 0x44, 0x54, 0x6c, 0x16, 0xf3, 0x2f, 0x54, 0xf4, 0xf1, 0x90, 0xf6,
+                0x55, 0x98, 0x83, 0xbb, 0xc4, 0x19, 0xf3, 0xd9, 0xe7, 0x62, 0x30, 0xa1, 0x2b, 0x51, 0xd4, 0xf0,
+            ],
+        ),
+        point(
+            &[
+                0xe0, 0x53, 0x17, 0x74, 0x5b, 0xe4, 0x99, 0xb2, 0x88, 0xb0, 0xb8, 0x08, 0x6e, 0x0c, 0xe3, 0xbb,
+                0x47, 0xa8, 0xa8, 0x36, 0xd8, 0x50, 0xe2, 0x09, 0x0d, 0x4e, 0xf8, 0xd2, 0xc0, 0x36, 0x0d, 0xd3,
+            ],
+            &[
+                0xdc, 0x1c, 0x3b, 0x71, 0xa5, 0xd9, 0x2f, 0x39, 0xcd, 0x80, 0x95, 0x82, 0xb5, 0xb6, 0xa0, 0x14,
+                0x61, 0xb9, 0x87, 0xde, 0x98, 0x10, 0x3d, 0xc2, 0xb2, 0xae, 0xcd, 0x91, 0x3c, 0x24, 0xf8, 0x7b,
+            ],
+        ),
+        point(
+            &[
+                0xa6, 0x5a, 0x3a, 0x01, 0xdf, 0x3b, 0x5e, 0xf2, 0xe6, 0x20, 0xd4, 0x31, 0x00, 0x49, 0xfb, 0xe1,
+                0x4d, 0x71, 0x45, 0x7f, 0x19, 0xd1, 0xed, 0x35, 0xae, 0xa3, 0x9d, 0x57, 0x89, 0x30, 0x3f, 0xdd,
+            ],
+            &[
+                0x79, 0x8e, 0xa0, 0x94, 0x0c, 0xff, 0x5c, 0x6f, 0xb8, 0xf4, 0x3d, 0x8d, 0x90, 0xed, 0x2c, 0x76,
+                0x86, 0x86, 0x1d, 0x02, 0x4f, 0xae, 0xd3, 0xca, 0xda, 0xd4, 0x4a, 0x8d, 0x02, 0xe6, 0x87, 0x03,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x9c, 0x39, 0x19, 0xa8, 0x4a, 0x47, 0x48, 0x70, 0xfa, 0xed, 0x8a, 0x9c, 0x1c, 0xc6, 0x60, 0x21,
+                0x52, 0x34, 0x89, 0x05, 0x4d, 0x7f, 0x03, 0x08, 0xcb, 0xfc, 0x99, 0xc8, 0xac, 0x1f, 0x98, 0xcd,
+            ],
+            &[
+                0xdd, 0xb8, 0x4f, 0x0f, 0x4a, 0x4d, 0xdd, 0x57, 0x58, 0x4f, 0x04, 0x4b, 0xf2, 0x60, 0xe6, 0x41,
+                0x90, 0x53, 0x26, 0xf7, 0x6c, 0x64, 0xc8, 0xe6, 0xbe, 0x7e, 0x5e, 0x03, 0xd4, 0xfc, 0x59, 0x9d,
+            ],
+        ),
+        point(
+            &[
+                0x2e, 0x3c, 0x05, 0x32, 0x62, 0x55, 0xd8, 0x0f, 0x0a, 0x42, 0xfc, 0x69, 0xd5, 0xc9, 0x2a, 0xa4,
+                0x0c, 0xd3, 0x26, 0xa5, 0x3e, 0x85, 0x35, 0xf0, 0x43, 0x5e, 0xfb, 0x7b, 0x69, 0x4a, 0x09, 0xec,
+            ],
+            &[
+                0x00, 0x1f, 0xf8, 0x91, 0x65, 0x6c, 0x6f, 0xb5, 0xbd, 0xda, 0xe2, 0x40, 0xb8, 0x2f, 0xc1, 0xab,
+                0xe0, 0x48, 0xa5, 0x3c, 0x70, 0x7b, 0x66, 0x51, 0x25, 0x34, 0x86, 0x81, 0x88, 0xc7, 0x32, 0x7e,
+            ],
+        ),
+        point(
+            &[
+                0xc1, 0x14, 0x23, 0x92, 0x29, 0xbd, 0xcc, 0xb7, 0x40, 0xbb, 0xb8, 0x3f, 0xbe, 0x53, 0xb8, 0xd6,
+                0xa7, 0xed, 0xe4, 0xca, 0x39, 0xdd, 0x53, 0x84, 0x17, 0xb9, 0x8d, 0x53, 0x8f, 0xb6, 0x4d, 0xb3,
+            ],
+            &[
+                0x12, 0x37, 0xf6, 0xdc, 0x5b, 0x48, 0x6f, 0xc2, 0xa5, 0xce, 0xcd, 0xe4, 0xfe, 0x97, 0x8b, 0xd1,
+                0xa8, 0x75, 0x80, 0x90, 0x4d, 0x45, 0x67, 0xd1, 0xe2, 0x30, 0xce, 0x9f, 0xfc, 0x02, 0x59, 0xbe,
+            ],
+        ),
+        point(
+            &[
+                0xe8, 0xe2, 0xa2, 0x4c, 0xcf, 0xa4, 0x15, 0x87, 0xae, 0x15, 0xfb, 0x7e, 0x3e, 0x24, 0xdd, 0xa4,
+                0x33, 0x71, 0x03, 0x16, 0xa1, 0x90, 0x89, 0x34, 0x20, 0x5f, 0x19, 0xa2, 0xab, 0x9c, 0x7c, 0xe6,
+            ],
+            &[
+                0x46, 0xc9, 0x83, 0xce, 0x0c, 0x6f, 0x5d, 0x1b, 0x4c, 0xaf, 0x2b, 0x2b, 0x3b, 0xee, 0x20, 0x59,
+                0x6e, 0x09, 0xe6, 0x03, 0xb5, 0xc2, 0x7a, 0x73, 0xb2, 0xc0, 0x1e, 0xb6, 0x88, 0x36, 0x26, 0x7c,
+            ],
+        ),
+        point(
+            &[
+                0x6c, 0x5b, 0x4b, 0xf8, 0x31, 0xa7, 0x72, 0x24, 0x08, 0x2d, 0x9c, 0x2c, 0x19, 0x26, 0x34, 0x71,
+                0x3a, 0x52, 0x21, 0x8c, 0x55, 0x45, 0x59, 0xea, 0x1e, 0xde, 0xd8, 0x34, 0x03, 0x08, 0x1e, 0x46,
+            ],
+            &[
+                0xcb, 0x05, 0x13, 0x71, 0x49, 0x26, 0xd4, 0x2f, 0xb2, 0x34, 0x78, 0x63, 0xce, 0x2b, 0xe4, 0x78,
+                0x41, 0xd0, 0xcf, 0x82, 0x6a, 0xc2, 0x2a, 0x62, 0xed, 0x1f, 0x9c, 0xb8, 0x0b, 0xfb, 0xcd, 0x70,
+            ],
+        ),
+        point(
+            &[
+                0xf8, 0x05, 0x83, 0x24, 0xc6, 0xb9, 0xc2, 0xe7, 0xe6, 0x21, 0x47, 0xe9, 0xa4, 0x1a, 0xd7, 0x8d,
+                0x60, 0xe3, 0xec, 0xf4, 0x17, 0x52, 0x4c, 0x05, 0x80, 0x83, 0x2a, 0xdd, 0xf1, 0x13, 0x49, 0xe2,
+            ],
+            &[
+                0x95, 0xc6, 0x0e, 0x5a, 0x0a, 0x88, 0x56, 0xcd, 0xcd, 0xe8, 0x1a, 0xa6, 0x0e, 0xa1, 0x12, 0x23,
+                0x50, 0x94, 0x98, 0xb5, 0x62, 0x6d, 0xe8, 0x8d, 0x5f, 0xac, 0x46, 0x9e, 0x5b, 0x20, 0x25, 0xfc,
+            ],
+        ),
+        point(
+            &[
+                0xe1, 0xe9, 0xa8, 0x56, 0x67, 0x0c, 0xad, 0xe4, 0xb5, 0x67, 0x06, 0x65, 0xca, 0xb1, 0x0a, 0x45,
+                0x0c, 0x30, 0xc7, 0xd5, 0x99, 0x11, 0xc1, 0x24, 0xdc, 0xae, 0x5a, 0xec, 0x46, 0x4d, 0xcd, 0x4b,
+            ],
+            &[
+                0x56, 0x2b, 0x0a, 0x95, 0x44, 0x55, 0xc5, 0x31, 0xb7, 0xac, 0x43, 0x59, 0x9b, 0x25, 0x77, 0x92,
+                0x7f, 0x44, 0xd1, 0x9a, 0xac, 0xa1, 0x6b, 0x29, 0x2d, 0x0b, 0x62, 0x5e, 0xbb, 0x04, 0x1f, 0x2c,
+            ],
+        ),
+        point(
+            &[
+                0xa7, 0x54, 0x9a, 0xac, 0x5d, 0x85, 0x73, 0xc2, 0xb2, 0xf0, 0xa3, 0x8b, 0x17, 0x00, 0x32, 0xa2,
+                0x12, 0xac, 0xaf, 0x92, 0x38, 0x3d, 0x5b, 0x5f, 0x5b, 0x0d, 0x39, 0x66, 0x8a, 0xc7, 0xb3, 0xc2,
+            ],
+            &[
+                0xbd, 0x17, 0xd1, 0xb9, 0x0d, 0x1c, 0x24, 0x15, 0x33, 0x5a, 0x1d, 0x70, 0xc1, 0x94, 0x7d, 0x2b,
+                0x5d, 0x6b, 0x51, 0x15, 0x53, 0x71, 0x16, 0xdf, 0xfa, 0x0c, 0x91, 0x71, 0x92, 0x87, 0xea, 0xef,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xa5, 0x76, 0xdf, 0x8e, 0x23, 0xa0, 0x84, 0x11, 0x42, 0x14, 0x39, 0xa4, 0x51, 0x8d, 0xa3, 0x18,
+                0x80, 0xce, 0xf0, 0xfb, 0xa7, 0xd4, 0xdf, 0x12, 0xb1, 0xa6, 0x97, 0x3e, 0xec, 0xb9, 0x42, 0x66,
+            ],
+            &[
+                0x40, 0xa6, 0xbf, 0x20, 0xe7, 0x66, 0x40, 0xb2, 0xc9, 0x2b, 0x97, 0xaf, 0xe5, 0x8c, 0xd8, 0x2c,
+                0x43, 0x2e, 0x10, 0xa7, 0xf5, 0x14, 0xd9, 0xf3, 0xee, 0x8b, 0xe1, 0x1a, 0xe1, 0xb2, 0x8e, 0xc8,
+            ],
+        ),
+        point(
+            &[
+                0x9e, 0x5d, 0xcc, 0x62, 0xef, 0x3b, 0x5a, 0x3b, 0x54, 0x65, 0x20, 0x86, 0x7b, 0xe7, 0x1b, 0xae,
+                0x6f, 0x3b, 0xa0, 0x63, 0xc9, 0xac, 0xfb, 0x8d, 0xce, 0xc5, 0x72, 0x5b, 0xda, 0x70, 0x48, 0x96,
+            ],
+            &[
+                0x6f, 0xed, 0xd1, 0x2d, 0xdb, 0x92, 0x5f, 0x3e, 0xa5, 0xfd, 0x3a, 0x21, 0x54, 0xc7, 0x61, 0x22,
+                0x79, 0x60, 0x5d, 0x18, 0x60, 0x30, 0xf5, 0x12, 0x48, 0xf2, 0x76, 0x9d, 0xca, 0x82, 0xc8, 0x35,
+            ],
+        ),
+        point(
+            &[
+                0x03, 0x28, 0x33, 0x6d, 0xcb, 0x74, 0xf5, 0x3e, 0x80, 0xbf, 0xc1, 0x87, 0x70, 0x5e, 0xdd, 0x0e,
+                0xc2, 0x4e, 0x74, 0x5b, 0xc3, 0xd5, 0x93, 0xd6, 0xb6, 0x8a, 0xeb, 0x58, 0xcd, 0x9e, 0xd6, 0xc1,
+            ],
+            &[
+                0x71, 0xa8, 0x98, 0x38, 0x12, 0xfd, 0x9f, 0x28, 0xc4, 0x6d, 0x59, 0x43, 0xa2, 0x0d, 0x7c, 0x8c,
+                0x26, 0x5b, 0xf4, 0xdf, 0x25, 0xcb, 0x49, 0x4a, 0xde, 0xc6, 0xeb, 0xe6, 0xf8, 0xfa, 0xfe, 0xee,
+            ],
+        ),
+        point(
+            &[
+                0xa7, 0xde, 0x08, 0x37, 0x5b, 0x87, 0x45, 0xad, 0xf8, 0xd6, 0xe9, 0xf9, 0x76, 0xf0, 0x3b, 0x20,
+                0xe3, 0x36, 0x25, 0xa0, 0x5c, 0xef, 0x58, 0x33, 0x95, 0x3e, 0xd5, 0x87, 0x44, 0xbf, 0x7e, 0xa0,
+            ],
+            &[
+                0xa6, 0x3d, 0x96, 0xb0, 0x57, 0xad, 0xa5, 0xe5, 0x21, 0x04, 0xa0, 0xb3, 0x34, 0x88, 0x8e, 0x9a,
+                0x64, 0x5a, 0x47, 0xc0, 0xfe, 0xbc, 0x5a, 0xa2, 0xe0, 0x4c, 0x05, 0x53, 0x9b, 0xbc, 0xab, 0xaa,
+            ],
+        ),
+        point(
+            &[
+                0xce, 0x4f, 0x4e, 0xae, 0x8b, 0x91, 0x1c, 0x54, 0xdc, 0x63, 0x92, 0x6d, 0x70, 0xfe, 0x15, 0x31,
+                0xe3, 0x8a, 0x10, 0x37, 0x01, 0x3b, 0xce, 0xb2, 0x91, 0x9a, 0x9a, 0x8d, 0x32, 0x35, 0x98, 0x3a,
+            ],
+            &[
+                0xf3, 0xc9, 0xf9, 0x73, 0xc3, 0x90, 0xfb, 0xbb, 0x3e, 0xad, 0xe0, 0x24, 0x9e, 0x70, 0x75, 0x43,
+                0x52, 0x6c, 0x65, 0x22, 0x8e, 0xbf, 0x74, 0x0a, 0xec, 0xfd, 0x6b, 0x19, 0x0d, 0x3c, 0xde, 0xcf,
+            ],
+        ),
+        point(
+            &[
+                0x69, 0x0c, 0xda, 0xe3, 0x98, 0x39, 0x18, 0xb9, 0xdb, 0xde, 0xa2, 0xa7, 0x46, 0x31, 0xbc, 0x1e,
+                0x98, 0xc4, 0x99, 0x6e, 0xfd, 0xcd, 0xd9, 0xf8, 0x6b, 0x75, 0x64, 0x8a, 0x66, 0xda, 0x57, 0xe4,
+            ],
+            &[
+                0x84, 0x0a, 0xdc, 0x79, 0x67, 0x7b, 0x79, 0xa4, 0x7a, 0x9f, 0xd9, 0x1b, 0xf5, 0x95, 0x89, 0x4d,
+                0xf8, 0x63, 0xd8, 0xfb, 0xce, 0x95, 0xa3, 0xd4, 0x2b, 0x61, 0x95, 0xb4, 0x93, 0x3f, 0x33, 0xbb,
+            ],
+        ),
+        point(
+            &[
+                0x4d, 0xbe, 0xbf, 0xa5, 0x4b, 0x98, 0x62, 0x22, 0x78, 0xe2, 0x8f, 0xb3, 0x6d, 0xf8, 0xbc, 0xbd,
+                0xdb, 0x5f, 0xf9, 0xcf, 0x78, 0x6e, 0x4c, 0x89, 0xa6, 0xda, 0xab, 0x66, 0x55, 0xb0, 0xe6, 0xc9,
+            ],
+            &[
+                0x10, 0xfe, 0xe7, 0xb0, 0x3c, 0x91, 0x3a, 0xed, 0xf4, 0x5a, 0x62, 0x6e, 0x6e, 0x22, 0x29, 0xf3,
+                0x58, 0x93, 0x11, 0xb2, 0xbc, 0x50, 0x4e, 0xfa, 0xe9, 0x4a, 0x78, 0x55, 0x5e, 0xb2, 0xcc, 0x25,
+            ],
+        ),
+        point(
+            &[
+                0xc2, 0x66, 0x65, 0x8e, 0x68, 0x90, 0x80, 0xc9, 0xc1, 0x3c, 0x35, 0xac, 0x01, 0xcf, 0xf4, 0xcb,
+                0xe6, 0x80, 0x65, 0xfd, 0xe9, 0x49, 0xe4, 0xa3, 0xa9, 0xf8, 0xfa, 0x10, 0x4a, 0xd9, 0x16, 0xfb,
+            ],
+            &[
+                0xe7, 0xe8, 0x59, 0x38, 0x54, 0xe7, 0xda, 0xab, 0x0f, 0x79, 0x81, 0x70, 0xb2, 0x46, 0x27, 0xab,
+                0x6b, 0x8f, 0xec, 0xdf, 0xeb, 0x61, 0x13, 0x88, 0x56, 0xae, 0xf5, 0x2b, 0xa0, 0x88, 0x78, 0x14,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x09, 0x28, 0x95, 0x5e, 0xe6, 0x37, 0xa8, 0x44, 0x63, 0x72, 0x9f, 0xd3, 0x0e, 0x7a, 0xfd, 0x2e,
+                0xd5, 0xf9, 0x62, 0x74, 0xe5, 0xad, 0x7e, 0x5c, 0xb0, 0x9e, 0xda, 0x9c, 0x06, 0xd9, 0x03, 0xac,
+            ],
+            &[
+                0xc2, 0x56, 0x21, 0x00, 0x3d, 0x3f, 0x42, 0xa8, 0x27, 0xb7, 0x8a, 0x13, 0x09, 0x3a, 0x95, 0xee,
+                0xac, 0x3d, 0x26, 0xef, 0xa8, 0xa8, 0xd8, 0x3f, 0xc5, 0x18, 0x0e, 0x93, 0x5b, 0xcd, 0x09, 0x1f,
+            ],
+        ),
+        point(
+            &[
+                0x4f, 0x89, 0xbd, 0xee, 0x37, 0x71, 0xd3, 0x50, 0xda, 0xd1, 0x63, 0xb0, 0x4c, 0xb1, 0x8a, 0xd6,
+                0x7c, 0xe5, 0xe9, 0xc5, 0x5b, 0x58, 0xf0, 0xe7, 0x23, 0x10, 0x47, 0xa6, 0x0f, 0x59, 0xdd, 0x9e,
+            ],
+            &[
+                0xca, 0x79, 0x52, 0xd5, 0x22, 0x7a, 0x1f, 0x69, 0x5c, 0x4b, 0xaf, 0x4c, 0x04, 0x3b, 0xb2, 0x47,
+                0x1e, 0x48, 0x82, 0x50, 0x66, 0x38, 0xdf, 0x5c, 0x10, 0x16, 0xae, 0x32, 0x01, 0x56, 0xb0, 0x49,
+            ],
+        ),
+        point(
+            &[
+                0x3e, 0x03, 0xb8, 0x1f, 0xc0, 0xe1, 0xe5, 0xa8, 0x05, 0x3d, 0xf0, 0xdf, 0xb2, 0x30, 0xb6, 0xae,
+                0xbe, 0x41, 0x15, 0xb3, 0x95, 0x3d, 0x2b, 0x41, 0x81, 0x11, 0x28, 0x75, 0x78, 0x74, 0xb8, 0x39,
+            ],
+            &[
+                0xd1, 0x3a, 0xe1, 0x63, 0xdf, 0xf0, 0x7f, 0x42, 0xc4, 0x4f, 0x66, 0x07, 0x57, 0x19, 0x8f, 0x66,
+                0x7d, 0xe5, 0xc5, 0xf0, 0xfd, 0xab, 0x5b, 0x8d, 0xa0, 0xc1, 0xce, 0x56, 0x7c, 0x05, 0x94, 0xba,
+            ],
+        ),
+        point(
+            &[
+                0xcb, 0x9e, 0x83, 0x04, 0xca, 0xe3, 0xc5, 0xa8, 0x0c, 0x39, 0x6b, 0xac, 0xa2, 0xc3, 0xc4, 0xc9,
+                0x94, 0xb6, 0x68, 0xf0, 0x79, 0xa2, 0x45, 0xbf, 0x52, 0x9c, 0x31, 0x4c, 0xff, 0xf0, 0x11, 0x97,
+            ],
+            &[
+                0x62, 0xc7, 0xd2, 0x80, 0x1e, 0xb8, 0x0e, 0x6a, 0x12, 0x72, 0x58, 0xcd, 0xff, 0x08, 0x89, 0x17,
+                0x41, 0xb2, 0xd1, 0x8c, 0x01, 0x5e, 0x0a, 0x24, 0xc3, 0x34, 0xe0, 0x76, 0x3b, 0x98, 0x9c, 0x1d,
+            ],
+        ),
+        point(
+            &[
+                0xe6, 0x62, 0xc0, 0xb7, 0xa2, 0xf4, 0x49, 0x2c, 0xd6, 0x2f, 0xa2, 0x83, 0xaa, 0x29, 0x22, 0xc5,
+                0xf1, 0x51, 0xbd, 0x13, 0x45, 0xb3, 0x02, 0x3f, 0x3a, 0x3f, 0x78, 0xe6, 0x83, 0x57, 0xa5, 0x13,
+            ],
+            &[
+                0xab, 0x0b, 0x19, 0x3c, 0xe6, 0x12, 0x45, 0x23, 0x05, 0xda, 0xe2, 0x08, 0xa1, 0x21, 0xa4, 0x19,
+                0xd8, 0x03, 0x5c, 0xd6, 0x25, 0x53, 0x8d, 0x89, 0x19, 0x2a, 0x20, 0x1c, 0xa0, 0x17, 0xd0, 0x7e,
+            ],
+        ),
+        point(
+            &[
+                0xc2, 0xc5, 0x8a, 0x54, 0x28, 0x0d, 0xf6, 0x39, 0x47, 0x78, 0xff, 0xe0, 0xa2, 0x2c, 0x23, 0x4b,
+                0x5e, 0x83, 0xa8, 0xc1, 0x88, 0xea, 0x59, 0x45, 0x9b, 0xae, 0x90, 0xd3, 0xb9, 0xa7, 0xd1, 0x97,
+            ],
+            &[
+                0x72, 0xea, 0x32, 0x88, 0x36, 0x6e, 0x09, 0x27, 0x57, 0x30, 0x41, 0xab, 0x62, 0xed, 0x39, 0xa6,
+                0x0a, 0x47, 0xec, 0x45, 0x45, 0xad, 0x82, 0x48, 0x4e, 0x41, 0xcc, 0xbf, 0x89, 0xee, 0xb5, 0xe9,
+            ],
+        ),
+        point(
+            &[
+                0x40, 0xbf, 0x80, 0xb1, 0xc9, 0x4c, 0xf6, 0xcb, 0x84, 0x38, 0x62, 0xc7, 0xcb, 0xe3, 0x58, 0x7d,
+                0xc2, 0x9f, 0xec, 0xa6, 0x07, 0x9c, 0x14, 0xb0, 0x49, 0x95, 0xf7, 0xef, 0xdf, 0x37, 0xd2, 0x42,
+            ],
+            &[
+                0xb5, 0x79, 0xdd, 0x35, 0xd8, 0x56, 0xaa, 0xf0, 0x4f, 0x53, 0xfe, 0x9b, 0x1b, 0x26, 0xfe, 0x12,
+                0x74, 0x32, 0x1e, 0xba, 0x42, 0xbd, 0x35, 0x58, 0x3d, 0x1d, 0x82, 0x79, 0xa6, 0x40, 0x50, 0x88,
+            ],
+        ),
+        point(
+            &[
+                0xe2, 0xf3, 0x49, 0xb0, 0xf8, 0x9c, 0x69, 0xbd, 0x3c, 0x8c, 0xf2, 0xa4, 0x10, 0x73, 0x0d, 0xc5,
+                0x8e, 0x0b, 0xee, 0xd4, 0x70, 0x48, 0xc5, 0x8c, 0x15, 0xf9, 0xff, 0xc2, 0x50, 0x8d, 0x2c, 0xc2,
+            ],
+            &[
+                0x1f, 0xeb, 0x2f, 0x28, 0x0f, 0x82, 0x72, 0x37, 0x81, 0x86, 0x0a, 0xec, 0x76, 0x02, 0x15, 0xba,
+                0x42, 0x34, 0x4b, 0xe8, 0xe0, 0x9c, 0xbd, 0xb3, 0x7e, 0x34, 0x7b, 0xd8, 0xe0, 0xd4, 0xc0, 0x4f,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xff, 0x2b, 0x0d, 0xce, 0x97, 0xee, 0xce, 0x97, 0xc1, 0xc9, 0xb6, 0x04, 0x17, 0x98, 0xb8, 0x5d,
+                0xfd, 0xfb, 0x6d, 0x88, 0x82, 0xda, 0x20, 0x30, 0x8f, 0x54, 0x04, 0x82, 0x45, 0x26, 0x08, 0x7e,
+            ],
+            &[
+                0x49, 0x3d, 0x13, 0xfe, 0xf5, 0x24, 0xba, 0x18, 0x8a, 0xf4, 0xc4, 0xdc, 0x54, 0xd0, 0x79, 0x36,
+                0xc7, 0xb7, 0xed, 0x6f, 0xb9, 0x0e, 0x2c, 0xeb, 0x2c, 0x95, 0x1e, 0x01, 0xf0, 0xc2, 0x99, 0x07,
+            ],
+        ),
+        point(
+            &[
+                0x29, 0x82, 0xdb, 0xbc, 0x5f, 0x36, 0x6c, 0x9f, 0x78, 0xe2, 0x9e, 0xbb, 0xec, 0xb1, 0xbb, 0x22,
+                0x3d, 0xeb, 0x5c, 0x4e, 0xe6, 0x38, 0xb4, 0x58, 0x3b, 0xd3, 0xa9, 0xaf, 0x31, 0x49, 0xf8, 0xef,
+            ],
+            &[
+                0xa6, 0x1b, 0x5b, 0xe9, 0xaf, 0x66, 0x22, 0x0a, 0xb9, 0xfa, 0x53, 0x39, 0xc7, 0xb5, 0xbc, 0x9d,
+                0x09, 0x5d, 0xb9, 0x94, 0x12, 0xe3, 0xed, 0x84, 0x56, 0xe7, 0x26, 0xb0, 0x16, 0xc7, 0xa2, 0x48,
+            ],
+        ),
+        point(
+            &[
+                0xc7, 0x45, 0xfd, 0xf2, 0x77, 0x5f, 0x23, 0x08, 0x88, 0xb7, 0xff, 0x25, 0xe0, 0x2c, 0x94, 0xb0,
+                0x66, 0xce, 0x0e, 0xef, 0xac, 0x8f, 0xeb, 0x9f, 0xc5, 0x90, 0x54, 0xfe, 0x79, 0xd6, 0x81, 0xf9,
+            ],
+            &[
+                0x59, 0x02, 0x22, 0xf2, 0xf6, 0xb9, 0xe5, 0xe7, 0x8a, 0x71, 0x39, 0x4c, 0x70, 0xe8, 0x18, 0x67,
+                0x8c, 0xdb, 0xd3, 0x35, 0xc6, 0x7d, 0x45, 0xc7, 0x60, 0x31, 0x73, 0x43, 0x7b, 0xbb, 0x12, 0x47,
+            ],
+        ),
+        point(
+            &[
+                0x1a, 0x28, 0xe5, 0x04, 0x2a, 0xf0, 0xc0, 0xf6, 0xb4, 0x36, 0xeb, 0x59, 0x04, 0x97, 0xdb, 0x58,
+                0x60, 0x01, 0x1f, 0x45, 0x80, 0xe1, 0x76, 0x58, 0x85, 0x28, 0x9f, 0x61, 0x23, 0x80, 0x44, 0x1b,
+            ],
+            &[
+                0x55, 0x77, 0x9a, 0x79, 0x96, 0xc5, 0x9d, 0xab, 0x7c, 0x78, 0x32, 0x9a, 0x89, 0x76, 0xf0, 0xed,
+                0x04, 0xb3, 0xe7, 0x5b, 0x46, 0xee, 0x67, 0xae, 0xb0, 0x5f, 0x60, 0x6a, 0x84, 0x52, 0xaf, 0x25,
+            ],
+        ),
+        point(
+            &[
+                0xab, 0xb2, 0x79, 0xf3, 0xa9, 0x75, 0x05, 0x0b, 0x27, 0xa5, 0x9e, 0x5e, 0xb6, 0x72, 0xe7, 0xf2,
+                0xb3, 0x44, 0x78, 0xe8, 0x20, 0xca, 0xc4, 0x81, 0x5e, 0x04, 0xce, 0xed, 0x35, 0xcd, 0x0e, 0xa3,
+            ],
+            &[
+                0x5d, 0xee, 0x10, 0x3b, 0xbf, 0x17, 0x97, 0x0d, 0x9f, 0xb4, 0xbe, 0x0c, 0x03, 0x07, 0x8e, 0xd4,
+                0x7c, 0x97, 0x69, 0x05, 0x9e, 0x02, 0xf3, 0xb6, 0x47, 0x09, 0x31, 0x33, 0x7c, 0x30, 0x7b, 0xce,
+            ],
+        ),
+        point(
+            &[
+                0xfd, 0x73, 0xc0, 0x52, 0xb1, 0x94, 0xc6, 0xc6, 0xdd, 0x46, 0xac, 0xa9, 0xd6, 0x40, 0x98, 0x1a,
+                0xec, 0x79, 0x60, 0x09, 0x17, 0xa5, 0x65, 0xeb, 0xe7, 0x7f, 0xd5, 0x34, 0x64, 0x9a, 0x21, 0x15,
+            ],
+            &[
+                0x62, 0x07, 0x68, 0xc1, 0xc8, 0x17, 0x88, 0x44, 0x02, 0x0a, 0xba, 0xb0, 0x26, 0xf7, 0xd6, 0xd9,
+                0x04, 0xf6, 0x01, 0x56, 0x2e, 0x9d, 0x42, 0x1f, 0x04, 0x9c, 0xab, 0x7b, 0xa6, 0xba, 0x6c, 0xda,
+            ],
+        ),
+        point(
+            &[
+                0x3f, 0x81, 0x15, 0x0b, 0x59, 0xfc, 0x68, 0x28, 0x27, 0xfa, 0xea, 0xa7, 0x42, 0x67, 0xed, 0x11,
+                0xea, 0x9f, 0xa1, 0xa9, 0x63, 0xe7, 0x38, 0x2c, 0x5c, 0x2e, 0x2f, 0x3f, 0x1b, 0xc9, 0xee, 0x3e,
+            ],
+            &[
+                0x19, 0xc8, 0x8a, 0x68, 0xfd, 0xbf, 0xa8, 0x2d, 0x46, 0x71, 0xbe, 0xb3, 0xc4, 0x79, 0x56, 0x62,
+                0x76, 0x23, 0xb2, 0xdc, 0xfc, 0xda, 0x81, 0x60, 0x3c, 0xea, 0xdb, 0x0c, 0x59, 0x9a, 0xae, 0x06,
+            ],
+        ),
+        point(
+            &[
+                0x0c, 0x8b, 0x83, 0xe9, 0x53, 0x5f, 0x30, 0x60, 0x1d, 0x25, 0x0c, 0xc0, 0xbd, 0x3f, 0x20, 0x14,
+                0x2e, 0xdd, 0x5e, 0xb7, 0x98, 0x5d, 0x83, 0x24, 0x2e, 0xef, 0x0e, 0x39, 0x62, 0x1e, 0x30, 0xa7,
+            ],
+            &[
+                0x0d, 0xcc, 0x70, 0x77, 0x06, 0x5f, 0xda, 0xc7, 0xb8, 0x50, 0xe3, 0xf1, 0x7e, 0xfd, 0xc8, 0x54,
+                0xaa, 0xca, 0xd2, 0x37, 0xb9, 0x87, 0x13, 0x4d, 0xbe, 0xbf, 0x7b, 0xeb, 0x9f, 0xf6, 0x88, 0xde,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xea, 0xa6, 0x49, 0xf2, 0x1f, 0x51, 0xbd, 0xba, 0xe7, 0xbe, 0x4a, 0xe3, 0x4c, 0xe6, 0xe5, 0x21,
+                0x7a, 0x58, 0xfd, 0xce, 0x7f, 0x47, 0xf9, 0xaa, 0x7f, 0x3b, 0x58, 0xfa, 0x21, 0x20, 0xe2, 0xb3,
+            ],
+            &[
+                0xbe, 0x32, 0x79, 0xed, 0x5b, 0xbb, 0xb0, 0x3a, 0xc6, 0x9a, 0x80, 0xf8, 0x98, 0x79, 0xaa, 0x5a,
+                0x01, 0xa6, 0xb9, 0x65, 0xf1, 0x3f, 0x7e, 0x59, 0xd4, 0x7a, 0x53, 0x05, 0xba, 0x5a, 0xd9, 0x3d,
+            ],
+        ),
+        point(
+            &[
+                0x3a, 0xdb, 0x9d, 0xb3, 0xbe, 0xb9, 0x97, 0xee, 0xc2, 0x62, 0x3e, 0xa5, 0x00, 0x22, 0x79, 0xea,
+                0x9e, 0x33, 0x7b, 0x5c, 0x70, 0x5f, 0x3d, 0xb4, 0x53, 0xdb, 0xc1, 0xcc, 0x1f, 0xc9, 0xb0, 0xa8,
+            ],
+            &[
+                0x37, 0x4e, 0x2d, 0x6d, 0xae, 0xe7, 0x4e, 0x71, 0x3c, 0x77, 0x4d, 0xe0, 0x7c, 0x09, 0x5f, 0xf6,
+                0xaa, 0xd9, 0xc8, 0xf9, 0x87, 0x02, 0x66, 0xcc, 0x61, 0xae, 0x79, 0x75, 0xf0, 0x5b, 0xbd, 0xda,
+            ],
+        ),
+        point(
+            &[
+                0x4b, 0x72, 0xa5, 0xe9, 0x04, 0x2f, 0x4a, 0xbf, 0xf4, 0x87, 0x31, 0xc3, 0xb8, 0x50, 0x47, 0xe2,
+                0x29, 0xaa, 0xb7, 0x1c, 0xc5, 0x2a, 0x6a, 0x98, 0xf5, 0x83, 0xfd, 0x3a, 0x3f, 0x2e, 0x07, 0x0d,
+            ],
+            &[
+                0x59, 0x9e, 0x1d, 0x4e, 0x1d, 0x6a, 0xe1, 0xcf, 0x60, 0x27, 0x7b, 0xb3, 0x6d, 0x0f, 0x3c, 0x10,
+                0xb0, 0xb4, 0x65, 0xdd, 0xd2, 0x94, 0x8c, 0x3d, 0xe4, 0x4b, 0xa8, 0x2e, 0xe9, 0x6d, 0xd7, 0x80,
+            ],
+        ),
+        point(
+            &[
+                0x12, 0x9e, 0x53, 0xac, 0x42, 0x8e, 0x9c, 0xbb, 0x7e, 0x10, 0x95, 0x5e, 0x56, 0xc5, 0xfc, 0x69,
+                0xfe, 0xfd, 0xff, 0x56, 0x96, 0x3e, 0x7c, 0xaf, 0x05, 0x4e, 0x9e, 0x0c, 0x90, 0xae, 0x86, 0xf9,
+            ],
+            &[
+                0x41, 0x5e, 0xcb, 0x95, 0x8a, 0xee, 0x9a, 0x29, 0xb2, 0xda, 0x21, 0x15, 0xb7, 0x12, 0x18, 0x3f,
+                0xb2, 0xa2, 0x32, 0xfd, 0x16, 0xb3, 0xe0, 0x1b, 0x82, 0x2e, 0xfd, 0xcd, 0x1e, 0x89, 0xc8, 0x5d,
+            ],
+        ),
+        point(
+            &[
+                0xa9, 0xfc, 0x93, 0xfc, 0x65, 0x39, 0xc8, 0xe2, 0x85, 0xa6, 0xbf, 0xbe, 0xb5, 0xe1, 0xfd, 0x61,
+                0x3e, 0xf5, 0x49, 0x96, 0x58, 0x51, 0x25, 0xa1, 0xe9, 0xce, 0x7f, 0xd8, 0x4a, 0x02, 0x59, 0x1c,
+            ],
+            &[
+                0x9c, 0x2c, 0xe7, 0x39, 0xdc, 0x53, 0x87, 0x17, 0x3e, 0x84, 0xc1, 0x7a, 0x1a, 0x91, 0x65, 0xe5,
+                0xca, 0x88, 0x8c, 0x41, 0x5f, 0xcf, 0x72, 0x53, 0x79, 0x0a, 0xdd, 0xef, 0x69, 0xbe, 0xc2, 0xdc,
+            ],
+        ),
+        point(
+            &[
+                0xaa, 0x71, 0x21, 0xd4, 0xe3, 0xfb, 0x5b, 0x78, 0x6a, 0xb4, 0x99, 0x69, 0x4a, 0xaf, 0x05, 0x43,
+                0x27, 0xf9, 0xca, 0x04, 0xe4, 0x60, 0x91, 0x13, 0x42, 0x87, 0x00, 0xa0, 0xf8, 0x59, 0x12, 0xec,
+            ],
+            &[
+                0xc6, 0x90, 0xf0, 0x77, 0xda, 0xd0, 0x95, 0x09, 0xc5, 0x05, 0x26, 0x6b, 0x96, 0xa2, 0xed, 0xeb,
+                0xfe, 0xe1, 0x34, 0xa8, 0xce, 0x05, 0x6c, 0xbd, 0xb1, 0x14, 0xcb, 0x26, 0x4a, 0xe3, 0x59, 0x78,
+            ],
+        ),
+        point(
+            &[
+                0xc9, 0x40, 0x01, 0x7c, 0x1a, 0x6f, 0x9f, 0x0a, 0x6a, 0x7d, 0x7a, 0xc1, 0x20, 0x9b, 0x02, 0x77,
+                0x09, 0xa2, 0x8b, 0xae, 0x13, 0xcb, 0xbc, 0x2e, 0x11, 0x00, 0x6e, 0x0e, 0x2d, 0x96, 0x8b, 0x59,
+            ],
+            &[
+                0x39, 0xd9, 0x22, 0x50, 0x0c, 0x9b, 0x86, 0x20, 0x13, 0xb8, 0xa1, 0xbf, 0xa5, 0xb5, 0x74, 0x2c,
+                0xe2, 0x84, 0x2c, 0xb6, 0x43, 0x90, 0xc9, 0xc8, 0xfe, 0xfd, 0x76, 0x40, 0x8d, 0xe5, 0x72, 0xfb,
+            ],
+        ),
+        point(
+            &[
+                0x60, 0x14, 0x44, 0x94, 0xc8, 0xf6, 0x94, 0x48, 0x5b, 0x85, 0xec, 0xb6, 0xae, 0xe1, 0x09, 0x56,
+                0xc7, 0x56, 0x26, 0x7d, 0x12, 0x89, 0x47, 0x11, 0x92, 0x22, 0x43, 0xd5, 0xe8, 0x55, 0xb8, 0xda,
+            ],
+            &[
+                0x8b, 0xb5, 0xd6, 0x69, 0xf6, 0x81, 0xe6, 0x46, 0x9e, 0x8b, 0xe1, 0xfd, 0x91, 0x32, 0xe6, 0x5b,
+                0x54, 0x39, 0x55, 0xc2, 0x7e, 0x3f, 0x2a, 0x4b, 0xad, 0x50, 0x05, 0x90, 0xf3, 0x4e, 0x4b, 0xbd,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x1e, 0xc8, 0x0f, 0xef, 0x36, 0x0c, 0xbd, 0xd9, 0x54, 0x16, 0x0f, 0xad, 0xab, 0x35, 0x2b, 0x6b,
+                0x92, 0xb5, 0x35, 0x76, 0xa8, 0x8f, 0xea, 0x49, 0x47, 0x17, 0x3b, 0x9d, 0x43, 0x00, 0xbf, 0x19,
+            ],
+            &[
+                0xae, 0xef, 0xe9, 0x37, 0x56, 0xb5, 0x34, 0x0d, 0x2f, 0x3a, 0x49, 0x58, 0xa7, 0xab, 0xbf, 0x5e,
+                0x01, 0x46, 0xe7, 0x7f, 0x62, 0x95, 0xa0, 0x7b, 0x67, 0x1c, 0xdc, 0x1c, 0xc1, 0x07, 0xce, 0xfd,
+            ],
+        ),
+        point(
+            &[
+                0x5b, 0xe7, 0xea, 0x35, 0x19, 0xf0, 0x4b, 0xc6, 0xcb, 0xee, 0xaa, 0x03, 0x44, 0xfc, 0x90, 0xbb,
+                0x8e, 0x84, 0x62, 0xf6, 0xeb, 0xd8, 0x90, 0x56, 0x0d, 0xae, 0x80, 0x5d, 0x41, 0x4f, 0xf9, 0xe4,
+            ],
+            &[
+                0x32, 0xf3, 0x2e, 0xc3, 0xf6, 0x38, 0xe6, 0x05, 0x47, 0x7f, 0x89, 0x0f, 0x65, 0x5a, 0xb7, 0xfe,
+                0x0e, 0x99, 0xc6, 0x30, 0x21, 0x19, 0xa3, 0x09, 0x40, 0x30, 0xb0, 0x78, 0x47, 0xe0, 0xbd, 0xbb,
+            ],
+        ),
+        point(
+            &[
+                0xa6, 0xdc, 0x88, 0x0a, 0x55, 0xd1, 0xf2, 0xe8, 0x3b, 0xcf, 0xdd, 0xab, 0x67, 0x10, 0x65, 0x31,
+                0xc4, 0xff, 0x0b, 0x50, 0x8c, 0x04, 0x52, 0xb9, 0x4b, 0x17, 0xcb, 0xbc, 0x52, 0xfe, 0xa1, 0xf9,
+            ],
+            &[
+                0x7e, 0xf1, 0xa8, 0x54, 0x7d, 0xc3, 0x67, 0xc3, 0x03, 0x86, 0x83, 0xa1, 0x16, 0xac, 0xbc, 0x50,
+                0x05, 0x7b, 0x89, 0xdb, 0x7e, 0x68, 0xf7, 0xe6, 0x3b, 0x1c, 0x14, 0xe4, 0x7b, 0xc3, 0x45, 0xe9,
+            ],
+        ),
+        point(
+            &[
+                0x58, 0xf0, 0x99, 0x11, 0x6e, 0xae, 0x4e, 0x65, 0x08, 0x13, 0xfc, 0x86, 0x98, 0xdf, 0x7f, 0x5c,
+                0xd5, 0x00, 0x28, 0x64, 0x9f, 0x85, 0x39, 0x91, 0xe3, 0xfb, 0x54, 0x5f, 0x4d, 0xdb, 0x7b, 0xb8,
+            ],
+            &[
+                0x7e, 0x07, 0x00, 0x2a, 0xaf, 0xfe, 0x11, 0x1a, 0x0d, 0x62, 0xff, 0x76, 0x14, 0x63, 0x80, 0x66,
+                0x50, 0x7e, 0xe4, 0x06, 0x2d, 0x17, 0x43, 0x02, 0xbd, 0xec, 0x73, 0x58, 0x2e, 0x5b, 0x2d, 0x6e,
+            ],
+        ),
+        point(
+            &[
+                0xfe, 0x6b, 0xa9, 0x3f, 0xea, 0x42, 0x45, 0x99, 0x2b, 0xdc, 0x22, 0x9c, 0x78, 0xa4, 0x81, 0xba,
+                0x8c, 0x6c, 0x4c, 0xe8, 0x74, 0x86, 0x56, 0x37, 0xc8, 0xd4, 0x0c, 0x3f, 0x06, 0xd6, 0xc9, 0xb3,
+            ],
+            &[
+                0x7e, 0xe9, 0x18, 0xd7, 0x40, 0x53, 0x98, 0x72, 0xb6, 0xbb, 0x41, 0xb3, 0x45, 0x41, 0x3b, 0x56,
+                0xd9, 0x80, 0xf1, 0xbf, 0x05, 0xc2, 0xe9, 0xb0, 0x0c, 0x2c, 0x78, 0x8f, 0xa9, 0x48, 0xbd, 0xfb,
+            ],
+        ),
+        point(
+            &[
+                0x23, 0x20, 0xb5, 0xca, 0xf7, 0xb5, 0x9b, 0x7c, 0xe5, 0x42, 0x84, 0x28, 0x02, 0xf7, 0x4c, 0x34,
+                0x13, 0x4b, 0xfc, 0x49, 0x5b, 0x9e, 0x2b, 0x10, 0x8b, 0x61, 0x3e, 0x77, 0x1c, 0x79, 0x85, 0xc4,
+            ],
+            &[
+                0xc7, 0x9f, 0x94, 0x3d, 0xc8, 0x8b, 0xe9, 0x43, 0x18, 0xc7, 0x21, 0xb8, 0x1f, 0x9b, 0xca, 0x93,
+                0xd9, 0x6f, 0x10, 0x21, 0x1c, 0xd9, 0xeb, 0x8d, 0x4f, 0xf4, 0xf1, 0xdf, 0x4c, 0x2c, 0x6d, 0x44,
+            ],
+        ),
+        point(
+            &[
+                0x15, 0xd5, 0xe2, 0xf1, 0x46, 0xfc, 0x98, 0xbf, 0xb0, 0x20, 0xc8, 0xc2, 0xdc, 0x08, 0xde, 0xd1,
+                0xb9, 0x64, 0x80, 0x6e, 0x44, 0x2c, 0x4b, 0x64, 0x42, 0x2f, 0x10, 0x73, 0x0c, 0xf9, 0x51, 0x51,
+            ],
+            &[
+                0xa5, 0xb7, 0x2e, 0x31, 0x91, 0x5f, 0xd4, 0xec, 0x7f, 0x90, 0xe1, 0x09, 0x78, 0x90, 0x23, 0xf9,
+                0xbb, 0x20, 0x4f, 0xb9, 0x7d, 0xd8, 0xc0, 0xf9, 0x48, 0x2a, 0x07, 0xcc, 0x2f, 0xf8, 0xec, 0xf2,
+            ],
+        ),
+        point(
+            &[
+                0xb0, 0xf9, 0xe4, 0xb9, 0xb2, 0x97, 0x90, 0xb6, 0x33, 0xbc, 0xc0, 0x4f, 0xd8, 0x60, 0xcb, 0x0f,
+                0x82, 0x3d, 0x8d, 0x1a, 0x4c, 0xc1, 0xa1, 0xc1, 0x41, 0x3c, 0x16, 0x06, 0xcc, 0x9a, 0x8e, 0x2c,
+            ],
+            &[
+                0x49, 0xe8, 0x2b, 0xf1, 0x84, 0x3a, 0xde, 0x6d, 0x41, 0xcb, 0xb0, 0xb9, 0x06, 0xfd, 0xe3, 0xf0,
+                0x33, 0x50, 0xcc, 0x02, 0xc1, 0x71, 0xce, 0xe7, 0x6c, 0x20, 0x66, 0xc4, 0xdf, 0x3d, 0x0d, 0xb4,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xfa, 0x50, 0xc0, 0xf6, 0x1d, 0x22, 0xe5, 0xf0, 0x7e, 0x3a, 0xce, 0xbb, 0x1a, 0xa0, 0x7b, 0x12,
+                0x8d, 0x00, 0x12, 0x20, 0x9a, 0x28, 0xb9, 0x77, 0x6d, 0x76, 0xa8, 0x79, 0x31, 0x80, 0xee, 0xf9,
+            ],
+            &[
+                0x6b, 0x84, 0xc6, 0x92, 0x23, 0x97, 0xeb, 0xa9, 0xb7, 0x2c, 0xd2, 0x87, 0x22, 0x81, 0xa6, 0x8a,
+                0x5e, 0x68, 0x32, 0x93, 0xa5, 0x7a, 0x21, 0x3b, 0x38, 0xcd, 0x8d, 0x7d, 0x3f, 0x4f, 0x28, 0x11,
+            ],
+        ),
+        point(
+            &[
+                0x63, 0x96, 0x4e, 0xee, 0x61, 0x90, 0x74, 0xe0, 0x78, 0x01, 0x40, 0xfe, 0x02, 0xe9, 0x08, 0x36,
+                0xe7, 0x23, 0x28, 0xd2, 0x44, 0x83, 0x86, 0xd4, 0x59, 0xc5, 0xbe, 0x23, 0x18, 0x7f, 0x50, 0x48,
+            ],
+            &[
+                0x3b, 0x6c, 0xfb, 0x3a, 0x6b, 0x89, 0xcf, 0x41, 0xa3, 0x9f, 0xf9, 0xb1, 0xc3, 0x4b, 0xfb, 0xc9,
+                0x3d, 0x58, 0x0b, 0x93, 0x4d, 0xde, 0x6c, 0x84, 0x38, 0x3a, 0x28, 0x4d, 0x89, 0x30, 0x9d, 0xf8,
+            ],
+        ),
+        point(
+            &[
+                0xf7, 0x50, 0x2e, 0x3c, 0x43, 0x79, 0xe3, 0x1b, 0xcd, 0xa3, 0x29, 0xf9, 0x3a, 0x1c, 0xa2, 0xb6,
+                0xa9, 0xc6, 0x0a, 0x40, 0x15, 0xca, 0xcb, 0x29, 0x71, 0xee, 0x0e, 0x33, 0x91, 0xda, 0x5e, 0x12,
+            ],
+            &[
+                0x3c, 0x57, 0xf5, 0xed, 0xd6, 0x7c, 0xfa, 0xfd, 0x59, 0x97, 0x09, 0x45, 0xc3, 0xd6, 0x72, 0x04,
+                0x7b, 0x86, 0xd3, 0x2e, 0xf7, 0x25, 0xce, 0xbc, 0x7a, 0x4b, 0x9c, 0x5e, 0x83, 0x85, 0xf4, 0xeb,
+            ],
+        ),
+        point(
+            &[
+                0x5a, 0x3c, 0xe2, 0x5b, 0x4d, 0x15, 0xb7, 0xe2, 0x2d, 0x14, 0x69, 0xdd, 0xf0, 0xfc, 0x9f, 0x75,
+                0xaf, 0xd7, 0xf1, 0x2a, 0xd3, 0xcb, 0xda, 0x31, 0xf8, 0x14, 0xba, 0x1e, 0xba, 0xdb, 0x2a, 0x65,
+            ],
+            &[
+                0x8b, 0x34, 0x12, 0x5b, 0x92, 0xe0, 0x5f, 0x63, 0x87, 0x3a, 0x6d, 0xbf, 0xbf, 0x3f, 0x99, 0xaf,
+                0x3e, 0xe2, 0x8b, 0xc3, 0xd8, 0x25, 0xfe, 0x8e, 0xd8, 0xb1, 0x70, 0xcf, 0x1d, 0x32, 0x7f, 0x1d,
+            ],
+        ),
+        point(
+            &[
+                0x12, 0xfe, 0x78, 0xf9, 0x83, 0xae, 0x58, 0x62, 0xa3, 0xa4, 0xf6, 0x62, 0x4e, 0x34, 0x55, 0xb3,
+                0xf4, 0xca, 0x5c, 0x4b, 0x94, 0xe5, 0x7c, 0x9f, 0x20, 0x74, 0x93, 0x31, 0x10, 0xb7, 0xd1, 0x05,
+            ],
+            &[
+                0x20, 0x62, 0xf1, 0xa3, 0x38, 0xd6, 0xbc, 0xf7, 0x78, 0x6b, 0x5a, 0xa1, 0x99, 0xa7, 0xcb, 0x77,
+                0x1d, 0xec, 0xe2, 0x65, 0xd6, 0xee, 0x90, 0xb8, 0x45, 0x8a, 0xc6, 0xfb, 0x9f, 0x79, 0x4a, 0x60,
+            ],
+        ),
+        point(
+            &[
+                0xd4, 0x20, 0x11, 0xd6, 0x01, 0x06, 0x13, 0x88, 0x0f, 0xec, 0x6b, 0x7f, 0x3f, 0x33, 0x2b, 0x20,
+                0x24, 0xab, 0x31, 0x8f, 0x2a, 0x9b, 0xa7, 0xed, 0x23, 0x73, 0x12, 0x07, 0x3e, 0x32, 0x47, 0x8e,
+            ],
+            &[
+                0xba, 0xe5, 0xd4, 0xe9, 0xa3, 0x7d, 0x4e, 0x0c, 0x1b, 0x2d, 0x5f, 0x5f, 0x44, 0xbf, 0x84, 0x7b,
+                0x6f, 0xb2, 0x4b, 0x2f, 0x35, 0x50, 0x8c, 0xc3, 0x1c, 0xe0, 0x56, 0x81, 0xd0, 0x4e, 0x88, 0xd4,
+            ],
+        ),
+        point(
+            &[
+                0x76, 0xaa, 0xc3, 0x13, 0x47, 0xdf, 0x47, 0x3d, 0x58, 0xc4, 0xbb, 0x10, 0x28, 0x08, 0x4b, 0x1a,
+                0x48, 0x0e, 0x6c, 0x50, 0xaa, 0x57, 0x2d, 0xaf, 0xf6, 0x21, 0xd8, 0x33, 0x9e, 0x0c, 0x5d, 0x05,
+            ],
+            &[
+                0xef, 0x55, 0x76, 0xef, 0x0d, 0x5c, 0x70, 0xef, 0xab, 0xef, 0x32, 0xc8, 0x32, 0x02, 0x62, 0x5f,
+                0x4b, 0x12, 0x25, 0xcf, 0x01, 0x5e, 0x6e, 0xe3, 0x55, 0x66, 0x19, 0xb7, 0x51, 0xec, 0xe6, 0x3e,
+            ],
+        ),
+        point(
+            &[
+                0x5c, 0xe6, 0x05, 0xaf, 0x98, 0xf9, 0x3e, 0xda, 0x69, 0x10, 0xbe, 0x34, 0xf0, 0xde, 0x41, 0xff,
+                0x85, 0xdb, 0xcb, 0x6e, 0x69, 0xa8, 0xfa, 0x00, 0x16, 0xa7, 0x33, 0x75, 0x4a, 0x9f, 0x44, 0xd0,
+            ],
+            &[
+                0x4c, 0xdd, 0xcf, 0x9b, 0xec, 0x22, 0x6b, 0xfe, 0x7b, 0xa5, 0x6b, 0xd0, 0x31, 0xc7, 0x6c, 0x58,
+                0xab, 0x3c, 0xb1, 0xbf, 0xa3, 0x2e, 0xcc, 0xc6, 0xc0, 0xd0, 0x5f, 0x34, 0x89, 0xd3, 0x01, 0x05,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xa8, 0xe2, 0x82, 0xff, 0x0c, 0x97, 0x06, 0x90, 0x72, 0x15, 0xff, 0x98, 0xe8, 0xfd, 0x41, 0x66,
+                0x15, 0x31, 0x1d, 0xe0, 0x44, 0x6f, 0x1e, 0x06, 0x2a, 0x73, 0xb0, 0x61, 0x0d, 0x06, 0x4e, 0x13,
+            ],
+            &[
+                0x7f, 0x97, 0x35, 0x5b, 0x8d, 0xb8, 0x1c, 0x09, 0xab, 0xfb, 0x7f, 0x3c, 0x5b, 0x25, 0x15, 0x88,
+                0x8b, 0x67, 0x9a, 0x3e, 0x50, 0xdd, 0x6b, 0xd6, 0xce, 0xf7, 0xc7, 0x31, 0x11, 0xf4, 0xcc, 0x0c,
+            ],
+        ),
+        point(
+            &[
+                0xca, 0xc6, 0xf2, 0xe7, 0xe2, 0x7f, 0xae, 0xcb, 0xcb, 0x87, 0x6f, 0x80, 0x5e, 0xa6, 0x6e, 0x63,
+                0xef, 0xbe, 0x9e, 0xaa, 0x75, 0x3d, 0x67, 0xc1, 0xc1, 0x5e, 0xb9, 0xea, 0x7f, 0x76, 0x53, 0xa1,
+            ],
+            &[
+                0xf7, 0xd4, 0x16, 0xe5, 0xe2, 0xaa, 0x6f, 0x19, 0x4c, 0xdb, 0x65, 0xd9, 0xa4, 0x2a, 0x34, 0x50,
+                0x81, 0xe8, 0x3a, 0xe5, 0x68, 0x81, 0x03, 0xa0, 0x68, 0xc1, 0x0a, 0xd0, 0xfe, 0xc5, 0xe5, 0x56,
+            ],
+        ),
+        point(
+            &[
+                0xae, 0x22, 0x07, 0xc5, 0xcd, 0xad, 0xe2, 0x63, 0x27, 0xf3, 0x7f, 0x0b, 0x1e, 0xe4, 0x0e, 0x50,
+                0xba, 0x28, 0x8f, 0x8d, 0xe6, 0x7e, 0x82, 0x9e, 0x22, 0xa1, 0x99, 0xb0, 0xba, 0x39, 0x79, 0xb5,
+            ],
+            &[
+                0xea, 0x91, 0xfe, 0x51, 0x0c, 0x07, 0x9f, 0x71, 0xad, 0x4c, 0x92, 0x45, 0x23, 0xad, 0x70, 0x60,
+                0xfc, 0xa8, 0x7b, 0x7d, 0x37, 0xd4, 0xf8, 0x89, 0x68, 0xf3, 0xcd, 0x66, 0x84, 0x50, 0xfa, 0x6f,
+            ],
+        ),
+        point(
+            &[
+                0xe6, 0xdf, 0xde, 0x46, 0xee, 0x37, 0xd2, 0x06, 0xef, 0xbc, 0x59, 0x32, 0xe5, 0x8e, 0x43, 0x25,
+                0x4a, 0xb7, 0x67, 0x29, 0x42, 0x38, 0xcb, 0x11, 0xcc, 0x9f, 0x4a, 0xb0, 0x86, 0x24, 0x00, 0x3d,
+            ],
+            &[
+                0x87, 0x27, 0xb3, 0xb7, 0xbe, 0x91, 0x39, 0x49, 0x8f, 0x2f, 0x48, 0xf7, 0xb8, 0x8f, 0x92, 0x20,
+                0x3b, 0x1c, 0xe5, 0xea, 0x52, 0x7f, 0xd7, 0xdd, 0x75, 0x48, 0x65, 0x0e, 0x22, 0x16, 0xb9, 0x3b,
+            ],
+        ),
+        point(
+            &[
+                0xcb, 0x8d, 0xed, 0x0c, 0xad, 0x72, 0xac, 0xe5, 0x9c, 0xec, 0x54, 0x10, 0x06, 0x58, 0x54, 0x61,
+                0xe0, 0xbe, 0x0c, 0x4f, 0xea, 0x21, 0x64, 0xfa, 0xe4, 0x10, 0x19, 0x10, 0x0e, 0xfc, 0xa8, 0x24,
+            ],
+            &[
+                0x33, 0xa5, 0x00, 0x8f, 0x74, 0x0d, 0x88, 0xc8, 0x5b, 0x31, 0x6c, 0x48, 0x7a, 0x2c, 0xcb, 0xc4,
+                0x96, 0xad, 0x1f, 0xdf, 0xc7, 0x93, 0x17, 0x42, 0xf1, 0x40, 0xbd, 0x05, 0x8f, 0x22, 0x73, 0x61,
+            ],
+        ),
+        point(
+            &[
+                0x49, 0xdb, 0xe4, 0xf7, 0xb2, 0x79, 0x2b, 0x64, 0xbc, 0x9e, 0xca, 0x37, 0xe8, 0xd6, 0x4c, 0x46,
+                0x0a, 0x00, 0xe4, 0x9c, 0xa3, 0x22, 0x1c, 0xef, 0x55, 0x9e, 0x11, 0xe1, 0xec, 0xe4, 0xdd, 0x6a,
+            ],
+            &[
+                0xb8, 0xb5, 0x72, 0x98, 0x47, 0x04, 0x81, 0xc7, 0x2c, 0x5f, 0xe3, 0x3d, 0xb6, 0x92, 0x25, 0x5a,
+                0x1c, 0x38, 0x55, 0x2f, 0xca, 0x05, 0xbb, 0x69, 0xb1, 0x3b, 0x72, 0xa4, 0x2a, 0x9e, 0x3e, 0xae,
+            ],
+        ),
+        point(
+            &[
+                0xb0, 0xc5, 0x3b, 0x29, 0x8a, 0xf1, 0x83, 0x67, 0x08, 0xe9, 0x49, 0x00, 0xd7, 0xe7, 0x6e, 0xd6,
+                0xb0, 0x4e, 0x08, 0x52, 0x21, 0xe4, 0xaa, 0xee, 0x94, 0x11, 0xb4, 0xda, 0x3b, 0xcb, 0xd3, 0x27,
+            ],
+            &[
+                0xee, 0x2a, 0x97, 0x40, 0x1f, 0xbd, 0x7e, 0xa1, 0x63, 0x8f, 0x6a, 0x60, 0x1b, 0x66, 0xb2, 0xaf,
+                0xd8, 0x2a, 0x22, 0x0c, 0x74, 0x63, 0x6a, 0x0f, 0x16, 0x16, 0xbc, 0x4a, 0x2d, 0x7b, 0xe4, 0x36,
+            ],
+        ),
+        point(
+            &[
+                0x3c, 0x4e, 0x08, 0x9c, 0xd9, 0xa6, 0x82, 0x3d, 0x66, 0xa4, 0x0c, 0xfc, 0x7a, 0xc9, 0x60, 0x82,
+                0xe2, 0x50, 0xe3, 0x14, 0x9c, 0xf2, 0x11, 0xd3, 0xb0, 0xe1, 0x10, 0x35, 0x48, 0xdc, 0xe1, 0x09,
+            ],
+            &[
+                0x43, 0xfb, 0xbe, 0x66, 0x9f, 0xe1, 0x91, 0xb4, 0x80, 0x75, 0x7b, 0xca, 0x15, 0x76, 0x4d, 0x37,
+                0x95, 0x79, 0xe1, 0x42, 0xd9, 0x7f, 0xe6, 0x97, 0xe2, 0xbf, 0x65, 0x92, 0x3a, 0x19, 0xae, 0xea,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x95, 0x93, 0x96, 0x98, 0x19, 0x43, 0x78, 0x5c, 0x3d, 0x3e, 0x57, 0xed, 0xf5, 0x01, 0x8c, 0xdb,
+                0xe0, 0x39, 0xe7, 0x30, 0xe4, 0x91, 0x8b, 0x3d, 0x88, 0x4f, 0xdf, 0xf0, 0x94, 0x75, 0xb7, 0xba,
+            ],
+            &[
+                0x2e, 0x7e, 0x55, 0x28, 0x88, 0xc3, 0x31, 0xdd, 0x8b, 0xa0, 0x38, 0x6a, 0x4b, 0x9c, 0xd6, 0x84,
+                0x9c, 0x65, 0x3f, 0x64, 0xc8, 0x70, 0x93, 0x85, 0xe9, 0xb8, 0xab, 0xf8, 0x75, 0x24, 0xf2, 0xfd,
+            ],
+        ),
+        point(
+            &[
+                0xcb, 0xee, 0x14, 0x05, 0xff, 0x0d, 0xa7, 0xde, 0xaf, 0xe3, 0x2c, 0xa7, 0xdd, 0x73, 0xd9, 0x5e,
+                0xd7, 0x02, 0x22, 0x6b, 0x39, 0x17, 0x47, 0xc7, 0x07, 0x27, 0x5a, 0x94, 0x0b, 0xc8, 0xf5, 0x3b,
+            ],
+            &[
+                0xf6, 0x21, 0x1f, 0x4f, 0x4e, 0x75, 0xf9, 0x02, 0xb5, 0x1f, 0x3e, 0x68, 0x9b, 0x82, 0x94, 0xcf,
+                0x0d, 0x9f, 0xf4, 0xf6, 0x81, 0x26, 0xf7, 0x28, 0x29, 0x22, 0xe6, 0xb2, 0x78, 0xc8, 0x7f, 0x45,
+            ],
+        ),
+        point(
+            &[
+                0x0a, 0xe9, 0x76, 0x75, 0xce, 0xb7, 0x2f, 0x7e, 0x78, 0x8f, 0x69, 0x0d, 0xfb, 0xcc, 0xcf, 0x14,
+                0x9f, 0x30, 0x9c, 0xcb, 0x6d, 0xdf, 0x72, 0xae, 0xa0, 0x9c, 0x5d, 0xd9, 0x0f, 0xd6, 0x99, 0x85,
+            ],
+            &[
+                0x91, 0x21, 0x99, 0x73, 0xf6, 0xe4, 0x8d, 0x14, 0xe9, 0xb8, 0xdf, 0xee, 0x05, 0x1a, 0x54, 0xc5,
+                0xd0, 0xb9, 0x9d, 0x41, 0x7a, 0xee, 0x1a, 0xff, 0x89, 0xc8, 0xeb, 0x41, 0x14, 0x09, 0xa0, 0x03,
+            ],
+        ),
+        point(
+            &[
+                0xad, 0xd5, 0xba, 0xd2, 0x8f, 0xaa, 0xf5, 0xac, 0xdd, 0x58, 0x0b, 0xfa, 0x0b, 0xa2, 0x52, 0xe0,
+                0x3d, 0xe3, 0xbe, 0xae, 0xfb, 0xd7, 0x1b, 0x9c, 0xf3, 0x77, 0xc8, 0x8b, 0x14, 0xb3, 0x11, 0xdd,
+            ],
+            &[
+                0xe9, 0xc4, 0x3c, 0xf4, 0xda, 0x3d, 0xc3, 0xa5, 0x97, 0x4e, 0x43, 0x4f, 0x83, 0x59, 0x81, 0x4f,
+                0x52, 0xd4, 0xe1, 0xe7, 0x66, 0x9b, 0x9b, 0x89, 0x02, 0xf9, 0x82, 0xf3, 0x49, 0xd6, 0xc3, 0x8d,
+            ],
+        ),
+        point(
+            &[
+                0x2b, 0x15, 0x86, 0x2a, 0x5a, 0xc1, 0x61, 0x2e, 0xc9, 0xb6, 0x5f, 0x17, 0x78, 0x02, 0x5d, 0x1f,
+                0xb7, 0x23, 0xc4, 0xc1, 0xfe, 0x3c, 0xc2, 0x9a, 0x9d, 0xc1, 0x93, 0xdf, 0xd9, 0x26, 0x2b, 0x90,
+            ],
+            &[
+                0x2e, 0xb0, 0x05, 0x3d, 0xaa, 0x0a, 0x33, 0xfa, 0xa7, 0xa3, 0x0d, 0x52, 0xda, 0x87, 0x49, 0x06,
+                0x6f, 0x53, 0x49, 0x70, 0xf9, 0x94, 0x89, 0xa4, 0x99, 0x19, 0x96, 0xe6, 0x48, 0x3d, 0x75, 0x57,
+            ],
+        ),
+        point(
+            &[
+                0x20, 0x9d, 0x6b, 0xcd, 0x76, 0x61, 0x63, 0xb5, 0x24, 0x8d, 0x44, 0x68, 0xc6, 0x6d, 0x17, 0x07,
+                0x14, 0xff, 0x12, 0xc6, 0xc4, 0x12, 0x61, 0x97, 0x4e, 0x75, 0xae, 0x79, 0x60, 0x78, 0xaf, 0xb0,
+            ],
+            &[
+                0x1a, 0x2f, 0x13, 0x42, 0x9e, 0x7b, 0x32, 0x80, 0xa0, 0x96, 0x5b, 0xab, 0xa9, 0x89, 0x8a, 0x5b,
+                0x01, 0xed, 0xb7, 0x93, 0x39, 0xf4, 0xe3, 0xe3, 0x85, 0xd1, 0xb7, 0x75, 0xa7, 0x40, 0xb3, 0x10,
+            ],
+        ),
+        point(
+            &[
+                0x6a, 0xf9, 0xea, 0xed, 0x1a, 0x96, 0xee, 0x67, 0x7e, 0xe9, 0x5c, 0x16, 0x16, 0xe4, 0x76, 0x9a,
+                0xf2, 0xd2, 0xc8, 0x94, 0x91, 0x04, 0x0e, 0xe5, 0x93, 0xf9, 0x71, 0x4c, 0xa8, 0xe7, 0xbe, 0x40,
+            ],
+            &[
+                0xb3, 0x81, 0x2a, 0x11, 0x69, 0x00, 0x66, 0x49, 0x67, 0x09, 0xea, 0x42, 0x83, 0x47, 0xdc, 0x81,
+                0x45, 0xe3, 0xf6, 0x66, 0xa0, 0xf5, 0x95, 0x69, 0xfa, 0x41, 0x6e, 0x02, 0x6e, 0x38, 0x7e, 0x1c,
+            ],
+        ),
+        point(
+            &[
+                0x53, 0xf2, 0x43, 0x2b, 0xa8, 0x17, 0x17, 0x14, 0x3f, 0xa9, 0xdf, 0x3d, 0xff, 0x41, 0xce, 0xd2,
+                0x4a, 0x29, 0xb3, 0x14, 0xbc, 0x5a, 0x8c, 0x96, 0xf5, 0xf6, 0x40, 0x0a, 0x0d, 0x7c, 0x09, 0x79,
+            ],
+            &[
+                0xbd, 0x52, 0xef, 0xfb, 0xc1, 0xf0, 0x79, 0xb7, 0xcc, 0xd4, 0xe3, 0xe0, 0x91, 0x1b, 0x07, 0xde,
+                0x4b, 0xd5, 0xa4, 0xf5, 0xc9, 0xe8, 0xb8, 0x45, 0xf9, 0xf7, 0xe9, 0x0c, 0x53, 0x7b, 0x36, 0xa2,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x64, 0x58, 0x7e, 0x23, 0x35, 0x47, 0x1e, 0xb8, 0x90, 0xee, 0x78, 0x96, 0xd7, 0xcf, 0xdc, 0x86,
+                0x6b, 0xac, 0xbd, 0xbd, 0x38, 0x39, 0x31, 0x7b, 0x34, 0x36, 0xf9, 0xb4, 0x56, 0x17, 0xe0, 0x73,
+            ],
+            &[
+                0xd9, 0x9f, 0xcd, 0xd5, 0xbf, 0x69, 0x02, 0xe2, 0xae, 0x96, 0xdd, 0x64, 0x47, 0xc2, 0x99, 0xa1,
+                0x85, 0xb9, 0x0a, 0x39, 0x13, 0x3a, 0xea, 0xb3, 0x58, 0x29, 0x9e, 0x5e, 0x9f, 0xaf, 0x65, 0x89,
+            ],
+        ),
+        point(
+            &[
+                0xb8, 0x66, 0xd6, 0xb1, 0x42, 0xdf, 0x94, 0x0f, 0x2c, 0xf2, 0x8b, 0x54, 0xc9, 0x2f, 0x0c, 0x12,
+                0x94, 0xe0, 0xb6, 0xa2, 0x2a, 0x91, 0xf2, 0xef, 0x44, 0xbc, 0xd8, 0x8c, 0x43, 0x84, 0x48, 0x0d,
+            ],
+            &[
+                0x19, 0x14, 0xb0, 0xb3, 0x42, 0x6a, 0xeb, 0x70, 0x89, 0xa2, 0x78, 0xd7, 0xea, 0x9a, 0xd7, 0xac,
+                0x24, 0xe5, 0x22, 0x80, 0x4b, 0x1d, 0x86, 0xd6, 0x0e, 0x65, 0x9b, 0x47, 0x0c, 0x4c, 0xaf, 0xa8,
+            ],
+        ),
+        point(
+            &[
+                0xbc, 0x47, 0x7b, 0xd5, 0x5a, 0x42, 0x03, 0xf8, 0x36, 0xa2, 0x13, 0xcf, 0xc5, 0x92, 0xa1, 0x7a,
+                0xc3, 0x46, 0x04, 0xc0, 0x7c, 0x00, 0x48, 0x59, 0xad, 0xf7, 0x14, 0x72, 0x0e, 0x10, 0x3d, 0xd6,
+            ],
+            &[
+                0xe3, 0x1e, 0x1e, 0x24, 0x29, 0xa8, 0xdd, 0x52, 0x6c, 0xba, 0xc5, 0x52, 0xc6, 0xde, 0xa6, 0x39,
+                0x5d, 0x29, 0x35, 0x72, 0xc6, 0x3b, 0x44, 0xac, 0x63, 0x90, 0x82, 0xd8, 0xd6, 0xf7, 0xc3, 0x43,
+            ],
+        ),
+        point(
+            &[
+                0xec, 0x2b, 0xb8, 0x90, 0x85, 0xde, 0x81, 0x9e, 0xc4, 0xd9, 0xd1, 0x64, 0x61, 0x02, 0xba, 0x87,
+                0xe2, 0xd5, 0x2a, 0xe4, 0xed, 0x4f, 0xe4, 0x55, 0xd2, 0x29, 0xcd, 0xa8, 0x1d, 0xb2, 0x0d, 0x6c,
+            ],
+            &[
+                0xcc, 0xec, 0xc1, 0x76, 0x61, 0xe0, 0x13, 0xa1, 0x33, 0x2f, 0x66, 0xf0, 0x65, 0x09, 0x40, 0xc6,
+                0x33, 0xa2, 0x36, 0x4b, 0xe8, 0x7e, 0xfa, 0x98, 0xa0, 0xe9, 0x9c, 0x4d, 0x62, 0x9c, 0xf4, 0xa0,
+            ],
+        ),
+        point(
+            &[
+                0x58, 0x9d, 0xb4, 0xfe, 0x5a, 0x6b, 0xb8, 0x38, 0x03, 0x03, 0xb4, 0x23, 0x26, 0x7b, 0xf8, 0xe8,
+                0x0a, 0xc5, 0xaf, 0x14, 0x04, 0xe6, 0x34, 0x90, 0x7d, 0x0d, 0xc3, 0xb0, 0xd4, 0x4e, 0xab, 0x31,
+            ],
+            &[
+                0x62, 0x55, 0x44, 0x5c, 0x10, 0x8a, 0xa2, 0xa4, 0xf6, 0x07, 0x06, 0x20, 0x24, 0xbd, 0x90, 0xf3,
+                0x1f, 0x61, 0x0e, 0x55, 0x21, 0x48, 0xf8, 0xdb, 0x94, 0x1a, 0xeb, 0xe7, 0x51, 0x36, 0x1f, 0x6a,
+            ],
+        ),
+        point(
+            &[
+                0xb1, 0xd2, 0x5d, 0x51, 0xb4, 0x55, 0x8f, 0x5f, 0xd0, 0xcc, 0xb8, 0x68, 0x3a, 0xf9, 0xa9, 0xcf,
+                0x62, 0xa1, 0x69, 0xc6, 0x91, 0x62, 0x7f, 0xa5, 0x92, 0xd8, 0x0b, 0x18, 0x36, 0x69, 0x5f, 0x94,
+            ],
+            &[
+                0x70, 0x6d, 0xda, 0x72, 0x03, 0x0e, 0x90, 0xb1, 0xf7, 0xbe, 0xa0, 0xac, 0x19, 0xab, 0xa8, 0x17,
+                0x60, 0xf6, 0xf1, 0x8d, 0xab, 0x86, 0x3e, 0xdc, 0xa5, 0xb7, 0xeb, 0x9a, 0x5e, 0xe3, 0x27, 0x36,
+            ],
+        ),
+        point(
+            &[
+                0x13, 0x39, 0xb3, 0x37, 0xd1, 0x6e, 0x2f, 0xa2, 0xea, 0x8a, 0x18, 0x60, 0xad, 0x6e, 0xdb, 0x7c,
+                0x42, 0x83, 0x1c, 0x1c, 0x56, 0x03, 0x36, 0xc6, 0x7b, 0xf9, 0x61, 0x72, 0x9a, 0x0c, 0x2c, 0x41,
+            ],
+            &[
+                0x9f, 0x9b, 0x29, 0x63, 0x62, 0xc7, 0xae, 0x5b, 0xa7, 0xa3, 0x24, 0x0f, 0xd1, 0x13, 0xa3, 0x40,
+                0x6b, 0x12, 0x27, 0xf8, 0x7d, 0xe9, 0x23, 0xa4, 0x18, 0x5f, 0x05, 0x4b, 0xa9, 0xf1, 0xbc, 0x2b,
+            ],
+        ),
+        point(
+            &[
+                0x71, 0xc4, 0xa7, 0xe3, 0x89, 0xe2, 0x96, 0xce, 0xd3, 0x9d, 0x75, 0xef, 0x5e, 0x54, 0x59, 0x05,
+                0xe5, 0x00, 0x50, 0x64, 0x0f, 0x50, 0xbe, 0xcf, 0x38, 0xa6, 0x0e, 0xcb, 0x23, 0xb0, 0x9d, 0x0f,
+            ],
+            &[
+                0x13, 0x13, 0xfa, 0xdb, 0x73, 0x7a, 0xf3, 0xba, 0x0a, 0xf3, 0xe0, 0xa2, 0x92, 0xf8, 0x10, 0xaa,
+                0x78, 0x6f, 0x2b, 0x08, 0x4a, 0x62, 0xff, 0xc7, 0x63, 0x7b, 0x1f, 0x01, 0x72, 0x0d, 0xdb, 0x62,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x13, 0x46, 0x4a, 0x57, 0xa7, 0x81, 0x02, 0xaa, 0x62, 0xb6, 0x97, 0x9a, 0xe8, 0x17, 0xf4, 0x63,
+                0x7f, 0xfc, 0xfe, 0xd3, 0xc4, 0xb1, 0xce, 0x30, 0xbc, 0xd6, 0x30, 0x3f, 0x6c, 0xaf, 0x66, 0x6b,
+            ],
+            &[
+                0x69, 0xbe, 0x15, 0x90, 0x04, 0x61, 0x45, 0x80, 0xef, 0x7e, 0x43, 0x34, 0x53, 0xcc, 0xb0, 0xca,
+                0x48, 0xf3, 0x00, 0xa8, 0x1d, 0x09, 0x42, 0xe1, 0x3f, 0x49, 0x5a, 0x90, 0x7f, 0x6e, 0xcc, 0x27,
+            ],
+        ),
+        point(
+            &[
+                0xeb, 0x3c, 0xf8, 0xf5, 0x32, 0x24, 0x53, 0x62, 0xec, 0x05, 0xc8, 0x8c, 0x85, 0xfe, 0x12, 0xd1,
+                0x91, 0x82, 0xbe, 0x7d, 0xce, 0xab, 0xe5, 0x77, 0xc7, 0x58, 0x49, 0xc6, 0x06, 0x50, 0x84, 0xae,
+            ],
+            &[
+                0xc8, 0x33, 0xc7, 0x82, 0x22, 0xd9, 0xd7, 0x00, 0x43, 0xfe, 0x63, 0xdc, 0xef, 0xdc, 0xa4, 0xa1,
+                0xf5, 0x2b, 0x45, 0xc5, 0xe7, 0xdb, 0xd2, 0xa6, 0x6f, 0x67, 0xc1, 0xff, 0xf9, 0x6b, 0x94, 0x80,
+            ],
+        ),
+        point(
+            &[
+                0xdd, 0xe9, 0xd5, 0x14, 0xdd, 0x9e, 0xe6, 0x96, 0x2c, 0x6e, 0xd6, 0xb2, 0xbf, 0x05, 0xb5, 0xcf,
+                0xdd, 0xea, 0x17, 0x1b, 0x94, 0xfc, 0x9a, 0xeb, 0xf2, 0x16, 0xb2, 0x09, 0x8e, 0xca, 0x5f, 0x51,
+            ],
+            &[
+                0xb8, 0x4e, 0x69, 0x13, 0x3c, 0xe2, 0x81, 0x11, 0xd8, 0x91, 0xf3, 0x4b, 0x0a, 0x7f, 0x8f, 0x09,
+                0x50, 0xfe, 0xeb, 0xe8, 0xde, 0x89, 0x57, 0x1f, 0x9a, 0xd6, 0x9a, 0x73, 0xd0, 0xc6, 0x38, 0xf7,
+            ],
+        ),
+        point(
+            &[
+                0xbd, 0xf1, 0xa6, 0x7d, 0x09, 0x2d, 0x99, 0x97, 0x4f, 0x7a, 0x60, 0xf2, 0x18, 0x45, 0x19, 0xb2,
+                0xa5, 0x76, 0xfc, 0xf9, 0x84, 0xa2, 0x01, 0xd9, 0xf8, 0xe5, 0xbc, 0xbc, 0xc2, 0xe9, 0xa5, 0xd0,
+            ],
+            &[
+                0x40, 0x95, 0x90, 0x2b, 0xab, 0x65, 0xa1, 0xaa, 0xa8, 0x0b, 0xe5, 0x4a, 0x86, 0xbf, 0x7b, 0xaa,
+                0xa6, 0x28, 0x0b, 0x61, 0xe5, 0x62, 0x64, 0x61, 0xcd, 0xb4, 0xf7, 0x01, 0x85, 0x62, 0xff, 0x7b,
+            ],
+        ),
+        point(
+            &[
+                0xfd, 0x1a, 0x62, 0x10, 0x23, 0x69, 0x93, 0x73, 0x6e, 0x9f, 0x40, 0x6e, 0xb1, 0x20, 0x4b, 0x17,
+                0x9a, 0xed, 0x51, 0x3e, 0x20, 0xad, 0x46, 0xec, 0x2f, 0x90, 0x6b, 0x05, 0x99, 0x9c, 0x88, 0xe4,
+            ],
+            &[
+                0x1a, 0xc9, 0x7b, 0x54, 0xb9, 0xc8, 0xc2, 0x0b, 0xfb, 0x13, 0xc0, 0x69, 0x54, 0x97, 0x77, 0x82,
+                0xdc, 0x6b, 0x71, 0xd4, 0x95, 0xcc, 0x00, 0xf2, 0x99, 0xc8, 0xc9, 0x16, 0x59, 0x5b, 0xc8, 0xdf,
+            ],
+        ),
+        point(
+            &[
+                0x0d, 0x53, 0x8c, 0xb1, 0xdb, 0xb0, 0xf4, 0xc8, 0xd5, 0xc4, 0x54, 0xf6, 0x8a, 0x28, 0xda, 0x59,
+                0xa3, 0x45, 0x75, 0x89, 0x24, 0xa4, 0xd6, 0xf0, 0x1c, 0xcc, 0x6a, 0x55, 0xa0, 0x9b, 0x0c, 0xca,
+            ],
+            &[
+                0xc8, 0x19, 0x6b, 0xac, 0x7a, 0x3e, 0xc1, 0x10, 0xcb, 0x0a, 0x8d, 0x5c, 0x40, 0xee, 0xd7, 0xcf,
+                0x85, 0x34, 0xcd, 0x99, 0xd8, 0x91, 0x34, 0xc9, 0x73, 0x1b, 0x14, 0x7e, 0x0c, 0x92, 0x9e, 0x05,
+            ],
+        ),
+        point(
+            &[
+                0x4e, 0xe4, 0x85, 0x31, 0xd8, 0xc2, 0x96, 0xb9, 0xf1, 0x3f, 0xfc, 0xe4, 0xf2, 0xc8, 0x6d, 0xc7,
+                0xcf, 0x2d, 0xa5, 0x73, 0x8d, 0x89, 0x2d, 0x68, 0xbd, 0x51, 0x5b, 0x5b, 0x5f, 0x80, 0x18, 0xce,
+            ],
+            &[
+                0xb6, 0x8f, 0x9e, 0xd4, 0x81, 0x0b, 0xf8, 0xb5, 0xfc, 0x7b, 0x44, 0x08, 0xd0, 0xc7, 0xc5, 0xe6,
+                0x71, 0x07, 0x49, 0x71, 0xb4, 0xe8, 0x06, 0x01, 0x3c, 0x35, 0xa6, 0x1b, 0x1e, 0x48, 0x38, 0x1f,
+            ],
+        ),
+        point(
+            &[
+                0x68, 0x85, 0x6a, 0x6e, 0xdd, 0xc4, 0xec, 0x29, 0xcd, 0x5b, 0xe2, 0x67, 0xb6, 0x44, 0x83, 0xb4,
+                0x8c, 0x3b, 0x41, 0x96, 0x47, 0x7d, 0xa6, 0x2a, 0xbd, 0xe5, 0xfc, 0x17, 0x3b, 0x27, 0xe7, 0x71,
+            ],
+            &[
+                0x77, 0xa3, 0x3d, 0xf1, 0x4f, 0x79, 0xa1, 0xfb, 0x13, 0xb6, 0xfd, 0x49, 0xc1, 0x9f, 0x7b, 0x4a,
+                0x33, 0x1d, 0x22, 0xf2, 0x93, 0xb0, 0x73, 0x3a, 0x61, 0x18, 0xd6, 0x2a, 0x07, 0xbb, 0xda, 0xb6,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0x8c, 0x28, 0xa9, 0x7b, 0xf8, 0x29, 0x8b, 0xc0, 0xd2, 0x3d, 0x8c, 0x74, 0x94, 0x52, 0xa3, 0x2e,
+                0x69, 0x4b, 0x65, 0xe3, 0x0a, 0x94, 0x72, 0xa3, 0x95, 0x4a, 0xb3, 0x0f, 0xe5, 0x32, 0x4c, 0xaa,
+            ],
+            &[
+                0x40, 0xa3, 0x04, 0x63, 0xa3, 0x30, 0x51, 0x93, 0x37, 0x8f, 0xed, 0xf3, 0x1f, 0x7c, 0xc0, 0xeb,
+                0x7a, 0xe7, 0x84, 0xf0, 0x45, 0x1c, 0xb9, 0x45, 0x9e, 0x71, 0xdc, 0x73, 0xcb, 0xef, 0x94, 0x82,
+            ],
+        ),
+        point(
+            &[
+                0xab, 0x1a, 0xc1, 0x87, 0x2a, 0x38, 0xa2, 0xf1, 0x96, 0xbe, 0xd5, 0xa6, 0x04, 0x7f, 0x0d, 0xa2,
+                0xc8, 0x13, 0x0f, 0xe8, 0xde, 0x49, 0xfc, 0x4d, 0x5d, 0xfb, 0x20, 0x1f, 0x76, 0x11, 0xd8, 0xe2,
+            ],
+            &[
+                0x13, 0xf4, 0xa3, 0x7a, 0x32, 0x4d, 0x17, 0xa1, 0xe9, 0xaa, 0x5f, 0x39, 0xdb, 0x6a, 0x42, 0xb6,
+                0xf7, 0xef, 0x93, 0xd3, 0x3e, 0x1e, 0x54, 0x5f, 0x01, 0xa5, 0x81, 0xf3, 0xc4, 0x29, 0xd1, 0x5b,
+            ],
+        ),
+        point(
+            &[
+                0x97, 0x29, 0x24, 0x70, 0x32, 0xc0, 0xdf, 0xcf, 0x45, 0xb4, 0x84, 0x1f, 0xcd, 0x72, 0xf6, 0xe9,
+                0xa2, 0x42, 0x26, 0x31, 0xfc, 0x34, 0x66, 0xcf, 0x86, 0x3e, 0x87, 0x15, 0x47, 0x54, 0xdd, 0x40,
+            ],
+            &[
+                0x91, 0xd1, 0xa2, 0x44, 0x26, 0x5f, 0xea, 0x1d, 0xcd, 0x15, 0xc7, 0x5d, 0xcb, 0xd4, 0xdf, 0x36,
+                0x90, 0xda, 0xe8, 0x52, 0x55, 0xac, 0xaf, 0x49, 0x38, 0x4b, 0x49, 0x2f, 0x2a, 0xa3, 0x61, 0x43,
+            ],
+        ),
+        point(
+            &[
+                0x25, 0x64, 0xfe, 0x9b, 0x5b, 0xee, 0xf8, 0x2d, 0x37, 0x03, 0xa6, 0x07, 0x25, 0x3f, 0x31, 0xef,
+                0x8e, 0xa1, 0xb3, 0x65, 0x77, 0x2d, 0xf4, 0x34, 0x22, 0x6a, 0xee, 0x64, 0x26, 0x51, 0xb3, 0xfa,
+            ],
+            &[
+                0x8a, 0xd9, 0xf7, 0xa6, 0x06, 0x78, 0x38, 0x90, 0x95, 0xfa, 0x14, 0xae, 0x12, 0x03, 0x92, 0x5f,
+                0x14, 0xf3, 0x7d, 0xab, 0x6b, 0x79, 0x81, 0x6e, 0xdb, 0x82, 0xe6, 0xa3, 0x01, 0xe5, 0x12, 0x2d,
+            ],
+        ),
+        point(
+            &[
+                0x89, 0x63, 0x7f, 0x97, 0x58, 0x0a, 0x79, 0x6e, 0x05, 0x07, 0x91, 0xad, 0x5a, 0x2f, 0x27, 0xaf,
+                0x18, 0x03, 0x64, 0x5d, 0x95, 0xdf, 0x02, 0x1a, 0x3c, 0x2d, 0x82, 0xeb, 0x8c, 0x2c, 0xa7, 0xff,
+            ],
+            &[
+                0x2d, 0x1f, 0xe1, 0x24, 0x8c, 0x88, 0x84, 0x24, 0xd5, 0x7b, 0x9c, 0xf1, 0x54, 0x35, 0x74, 0x89,
+                0xf8, 0x7b, 0xc6, 0xa3, 0x8e, 0x42, 0xea, 0xb7, 0xbe, 0xd4, 0x15, 0xe1, 0x70, 0x49, 0x3e, 0x68,
+            ],
+        ),
+        point(
+            &[
+                0x71, 0xef, 0xa4, 0xe2, 0x6a, 0x41, 0x79, 0xe1, 0x12, 0x86, 0x0b, 0x88, 0xfc, 0x98, 0x65, 0x8a,
+                0x4b, 0xdb, 0xc5, 0x9c, 0x7a, 0xb6, 0xd4, 0xf8, 0x05, 0x7c, 0x35, 0x33, 0x0c, 0x7a, 0x89, 0xee,
+            ],
+            &[
+                0x14, 0x5f, 0xa8, 0x1f, 0x8b, 0xb6, 0x24, 0xae, 0x9e, 0xfb, 0x2c, 0x32, 0xb1, 0x72, 0x94, 0xa2,
+                0x2a, 0xaa, 0xfa, 0xb8, 0x8e, 0x5e, 0x9a, 0x0b, 0x4f, 0x48, 0x93, 0x29, 0xc1, 0x36, 0x6a, 0x2e,
+            ],
+        ),
+        point(
+            &[
+                0x30, 0x81, 0x38, 0xe7, 0x1b, 0xe2, 0x5e, 0x09, 0x2f, 0xdc, 0x9d, 0xa0, 0x3d, 0x53, 0x57, 0x42,
+                0x1b, 0xc7, 0x28, 0x03, 0x56, 0xa1, 0x38, 0x1a, 0x61, 0x86, 0xd6, 0x3a, 0x0c, 0xa8, 0xdd, 0x7f,
+            ],
+            &[
+                0x28, 0xd1, 0xe2, 0xd2, 0x88, 0x28, 0xfc, 0x92, 0x5e, 0x39, 0xec, 0x45, 0xd1, 0x40, 0x8e, 0x18,
+                0xc8, 0x16, 0x56, 0x46, 0x43, 0x4a, 0xd9, 0x15, 0xe4, 0x15, 0xf2, 0x47, 0x8a, 0x92, 0xc7, 0xf2,
+            ],
+        ),
+        point(
+            &[
+                0xff, 0x3d, 0x61, 0x36, 0xff, 0xac, 0x5b, 0x0c, 0xbf, 0xc6, 0xc5, 0xc0, 0xc3, 0x0d, 0xc0, 0x1a,
+                0x7e, 0xa3, 0xd5, 0x6c, 0x20, 0xbd, 0x31, 0x03, 0xb1, 0x78, 0xe3, 0xd3, 0xae, 0x18, 0x00, 0x68,
+            ],
+            &[
+                0x13, 0x32, 0x39, 0xbe, 0x84, 0xe4, 0x00, 0x0e, 0x40, 0xd0, 0x37, 0x2c, 0xdd, 0x96, 0xad, 0xc1,
+                0x54, 0x76, 0x76, 0xf2, 0x40, 0x01, 0xf5, 0xe6, 0x70, 0xa6, 0xbb, 0x6e, 0x18, 0x8c, 0x60, 0x77,
+            ],
+        ),
+    ],
+    [
+        point(
+            &[
+                0xdd, 0x36, 0x25, 0xfa, 0xef, 0x5b, 0xa0, 0x60, 0x74, 0x66, 0x97, 0x16, 0xbb, 0xd3, 0x78, 0x8d,
+                0x89, 0xbd, 0xde, 0x81, 0x59, 0x59, 0x96, 0x80, 0x92, 0xf7, 0x6c, 0xc4, 0xeb, 0x9a, 0x97, 0x87,
+            ],
+            &[
+                0x7a, 0x18, 0x8f, 0xa3, 0x52, 0x0e, 0x30, 0xd4, 0x61, 0xda, 0x25, 0x01, 0x04, 0x57, 0x31, 0xca,
+                0x94, 0x14, 0x61, 0x98, 0x28, 0x83, 0x39, 0x59, 0x37, 0xf6, 0x8d, 0x00, 0xc6, 0x44, 0xa5, 0x73,
+            ],
+        ),
+        point(
+            &[
+                0x27, 0x6b, 0x9e, 0xff, 0x8f, 0x2a, 0xa8, 0x83, 0x65, 0xa4, 0x41, 0x50, 0x7e, 0xef, 0x12, 0x3b,
+                0xe1, 0x52, 0x0d, 0x4e, 0xba, 0x9d, 0xac, 0x51, 0x17, 0xf0, 0x14, 0xd8, 0xf7, 0x6a, 0x9a, 0x68,
+            ],
+            &[
+                0x39, 0xf8, 0xaa, 0xb0, 0xf2, 0xf7, 0xdb, 0x81, 0xbc, 0x0e, 0xbc, 0x0d, 0x08, 0xe5, 0xda, 0xb7,
+                0x3e, 0x84, 0x53, 0x14, 0xa0, 0xd6, 0x02, 0x9e, 0x81, 0xd6, 0x6a, 0xc5, 0xb8, 0xe7, 0xeb, 0x15,
+            ],
+        ),
+        point(
+            &[
+                0x73, 0xda, 0x00, 0xc1, 0x1c, 0x73, 0x35, 0xa8, 0x04, 0x42, 0xe4, 0xab, 0xdc, 0xeb, 0x4d, 0xa1,
+                0x59, 0x58, 0xd5, 0xc1, 0x00, 0x99, 0xfb, 0xe8, 0x1a, 0x9d, 0x2a, 0x73, 0x77, 0x1e, 0x3a, 0x93,
+            ],
+            &[
+                0x7b, 0x0d, 0xa3, 0x4c, 0x81, 0xfc, 0x00, 0xc9, 0xee, 0x36, 0x87, 0x7b, 0xfe, 0x96, 0xfe, 0xdc,
+                0x4f, 0x3f, 0x09, 0x4e, 0xca, 0x2a, 0xd6, 0x6e, 0x80, 0x19, 0x41, 0x8d, 0xcc, 0x83, 0x06, 0xcd,
+            ],
+        ),
+        point(
+            &[
+                0x58, 0x09, 0x45, 0xb2, 0x79, 0x8e, 0xe8, 0x0c, 0x3b, 0xdf, 0x42, 0x09, 0xe2, 0x4e, 0xa8, 0x70,
+                0xa2, 0xc5, 0xd0, 0x52, 0x57, 0x34, 0xfe, 0xed, 0x0b, 0x30, 0x79, 0x77, 0x5c, 0xe7, 0x76, 0xc1,
+            ],
+            &[
+                0x02, 0xe4, 0x96, 0x55, 0x53, 0xd7, 0x8e, 0x02, 0x17, 0xf1, 0x36, 0x21, 0x01, 0xa6, 0x23, 0x57,
+                0xd5, 0xf8, 0x4c, 0xa2, 0xc9, 0xda, 0x16, 0xf3, 0xeb, 0xe5, 0x73, 0x58, 0xca, 0x89, 0x7c, 0x27,
+            ],
+        ),
+        point(
+            &[
+                0xdd, 0x72, 0x9d, 0x24, 0x4f, 0x66, 0x25, 0x66, 0x07, 0x63, 0xf9, 0xae, 0x2e, 0xf0, 0x77, 0x5d,
+                0x77, 0xb0, 0x2a, 0x33, 0x6d, 0x35, 0xa0, 0x81, 0xca, 0x05, 0xc5, 0x37, 0xc5, 0x62, 0x17, 0x05,
+            ],
+            &[
+                0x03, 0x8f, 0xd3, 0x2c, 0x9b, 0x89, 0x84, 0xb3, 0xff, 0x97, 0x0c, 0xa1, 0xc4, 0xa8, 0xfc, 0xd1,
+                0x14, 0x9b, 0x14, 0xe6, 0x9c, 0xc0, 0x30, 0x76, 0x0c, 0xff, 0x2c, 0xd1, 0x74, 0x27, 0x20, 0xb6,
+            ],
+        ),
+        point(
+            &[
+                0x67, 0xe6, 0x6a, 0xaa, 0x7a, 0xa0, 0x97, 0x7f, 0xe2, 0x6c, 0x36, 0xd2, 0x36, 0xa1, 0x0e, 0x86,
+                0x82, 0xd7, 0x0d, 0xe9, 0xb3, 0x25, 0x04, 0x9c, 0x84, 0xd1, 0x00, 0xc5, 0xc6, 0x6b, 0xa2, 0x83,
+            ],
+            &[
+                0x46, 0xf6, 0x63, 0xa6, 0xfc, 0x79, 0xa6, 0xaf, 0x2d, 0x6e, 0x39, 0xd2, 0xb0, 0x5c, 0x7b, 0x13,
+                0x6f, 0xeb, 0x5d, 0x14, 0x5b, 0xf6, 0x95, 0x09, 0xd8, 0x79, 0x3d, 0xff, 0x53, 0xaf, 0x25, 0x7f,
+            ],
+        ),
+        point(
+            &[
+                0x0c, 0x41, 0xe1, 0x2b, 0xdc, 0x86, 0xc2, 0x43, 0x78, 0x25, 0xb4, 0x0d, 0xc3, 0x91, 0xad, 0xdf,
+                0xa6, 0xe7, 0x40, 0xb8, 0x79, 0xef, 0x24, 0xd7, 0x18, 0x45, 0x5c, 0xb9, 0x51, 0xf3, 0x5c, 0x55,
+            ],
+            &[
+                0x95, 0x06, 0xfe, 0x1e, 0x62, 0x7e, 0xb0, 0x88, 0x74, 0x77, 0x2e, 0x94, 0x26, 0x39, 0xb5, 0x1d,
+                0xfb, 0xcc, 0x9e, 0x2f, 0x48, 0x23, 0x16, 0xc1, 0x31, 0x2c, 0x27, 0x14, 0x21, 0x0e, 0xf6, 0xb8,
+            ],
+        ),
+        point(
+            &[
+                0x71, 0x1e, 0x24, 0xf1, 0xdf, 0x8a, 0xcf, 0xfa, 0x70, 0x7c, 0xf6, 0x99, 0x83, 0x62, 0x50, 0xae,
+                0xbe, 0x60, 0xe5, 0x5e, 0xb7, 0x0d, 0x4c, 0x52, 0x0e, 0x1a, 0xb8, 0x29, 0x7c, 0xfe, 0xa6, 0xff,
+            ],
+            &[
+                0x5d, 0xe9, 0x1a, 0x21, 0x2b, 0xc5, 0x2f, 0xa4, 0x60, 0xf1, 0xf8, 0x7b, 0x8f, 0xac, 0x0d, 0xa8,
+                0xed, 0x21, 0x05, 0xe2, 0x4e, 0x5a, 0x4b, 0x6f, 0x86, 0x01, 0x98, 0x21, 0x32, 0xbf, 0xed, 0xb9,
+            ],
+        ),
+    ],
+];
+
+// Only one table is used by `mul_by_generator`, depending on which `precomputed-tables-*`
+// features are enabled.
+
+/// Every second row of [`TABLE`]: `HALF_TABLE[i][j] = (j + 1) * 2^(16 * i) * G`.
+#[allow(dead_code)]
+pub(super) const HALF_TABLE: [[AffinePoint; 8]; 17] = every_nth_row(2);
+
+/// Every fourth row of [`TABLE`]: `QUARTER_TABLE[i][j] = (j + 1) * 2^(32 * i) * G`.
+#[allow(dead_code)]
+pub(super) const QUARTER_TABLE: [[AffinePoint; 8]; 9] = every_nth_row(4);
+
+/// Select rows `0, n, 2n, ...` of [`TABLE`].
+#[allow(dead_code)]
+const fn every_nth_row<const ROWS: usize>(n: usize) -> [[AffinePoint; 8]; ROWS] {
+    let mut rows = [[AffinePoint::IDENTITY; 8]; ROWS];
+    let mut i = 0;
+    while i < ROWS {
+        rows[i] = TABLE[i * n];
+        i += 1;
+    }
+    rows
+}
@@ -0,0 +1,185 @@
+//! Pippenger's bucket method for large multi-scalar multiplications.
+//!
+//! Each scalar is split into `c`-bit windows. For every window, points are accumulated into
+//! `2^c - 1` buckets according to their digit, and the buckets are then summed using a running
+//! sum so that bucket `i` is counted `i` times. The per-window results are finally combined with
+//! `c` doublings between each.
+//!
+//! Windows are independent of each other, so with the `parallel` feature they are computed on
+//! separate threads using `rayon`.
+
+use crate::arithmetic::{ProjectivePoint, Scalar};
+use crate::FieldBytes;
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Number of bits in a scalar.
+const SCALAR_BITS: usize = 256;
+
+impl ProjectivePoint {
+    /// Calculates `x1 * k1 + ... + xn * kn` using Pippenger's bucket method.
+    ///
+    /// This is considerably faster than [`LinearCombination::lincomb`] for large numbers of
+    /// terms (hundreds or more), and when the `parallel` feature is enabled its work is split
+    /// across threads.
+    ///
+    /// <div class="warning">
+    ///
+    /// This function runs in **variable time** and memory access patterns depend on the
+    /// scalars. It must only be used with public inputs, e.g. in batch signature or proof
+    /// verification.
+    ///
+    /// </div>
+    ///
+    /// [`LinearCombination::lincomb`]: elliptic_curve::ops::LinearCombination::lincomb
+    pub fn msm_vartime(points_and_scalars: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+        #[cfg(feature = "parallel")]
+        {
+            pippenger_parallel(points_and_scalars)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            pippenger_serial(points_and_scalars)
+        }
+    }
+}
+
+/// Compute the linear combination on the current thread.
+#[cfg(any(not(feature = "parallel"), test))]
+fn pippenger_serial(points_and_scalars: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let c = window_size(points_and_scalars.len());
+    let scalars = scalar_bytes(points_and_scalars);
+    let windows = (0..window_count(c))
+        .map(|w| window_sum(points_and_scalars, &scalars, w * c, c))
+        .collect::<Vec<_>>();
+
+    combine(&windows, c)
+}
+
+/// Compute the linear combination, processing each window on its own `rayon` task.
+#[cfg(feature = "parallel")]
+fn pippenger_parallel(points_and_scalars: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let c = window_size(points_and_scalars.len());
+    let scalars = scalar_bytes(points_and_scalars);
+    let windows = (0..window_count(c))
+        .into_par_iter()
+        .map(|w| window_sum(points_and_scalars, &scalars, w * c, c))
+        .collect::<Vec<_>>();
+
+    combine(&windows, c)
+}
+
+/// Choose the window size in bits for `n` terms, roughly `log2(n) * 2/3 + 2`.
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        let log2 = (usize::BITS - n.leading_zeros()) as usize;
+        (log2 * 2 / 3 + 2).min(16)
+    }
+}
+
+/// Number of `c`-bit windows needed to cover a scalar.
+fn window_count(c: usize) -> usize {
+    (SCALAR_BITS + c - 1) / c
+}
+
+/// Serialize the scalars once up front so that windows can be extracted cheaply.
+fn scalar_bytes(points_and_scalars: &[(ProjectivePoint, Scalar)]) -> Vec<FieldBytes> {
+    points_and_scalars
+        .iter()
+        .map(|(_, k)| k.to_bytes())
+        .collect()
+}
+
+/// Extract the `c`-bit digit starting at bit `offset` of a big endian scalar.
+fn digit(bytes: &FieldBytes, offset: usize, c: usize) -> usize {
+    (0..c)
+        .map(|i| offset + i)
+        .take_while(|&bit| bit < SCALAR_BITS)
+        .fold(0, |acc, bit| {
+            let byte = bytes[bytes.len() - 1 - bit / 8];
+            acc | ((((byte >> (bit % 8)) & 1) as usize) << (bit - offset))
+        })
+}
+
+/// Sum of `digit_i * x_i` over all terms for the window starting at bit `offset`.
+fn window_sum(
+    points_and_scalars: &[(ProjectivePoint, Scalar)],
+    scalars: &[FieldBytes],
+    offset: usize,
+    c: usize,
+) -> ProjectivePoint {
+    let mut buckets = vec![ProjectivePoint::IDENTITY; (1 << c) - 1];
+
+    for ((x, _), k) in points_and_scalars.iter().zip(scalars) {
+        let d = digit(k, offset, c);
+        if d != 0 {
+            buckets[d - 1] += x;
+        }
+    }
+
+    // Bucket `i` contributes `(i + 1) * bucket` to the sum.
+    let mut running = ProjectivePoint::IDENTITY;
+    let mut sum = ProjectivePoint::IDENTITY;
+    for bucket in buckets.iter().rev() {
+        running += bucket;
+        sum += &running;
+    }
+    sum
+}
+
+/// Combine per-window sums (least significant first) into the final result.
+fn combine(windows: &[ProjectivePoint], c: usize) -> ProjectivePoint {
+    windows
+        .iter()
+        .rev()
+        .fold(ProjectivePoint::IDENTITY, |acc, window| {
+            (0..c).fold(acc, |acc, _| acc.double()) + window
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pippenger_serial;
+    use crate::arithmetic::{ProjectivePoint, Scalar};
+    use alloc::vec::Vec;
+    use elliptic_curve::{ops::LinearCombination, rand_core::OsRng, Field, Group};
+
+    fn random_terms(n: usize) -> Vec<(ProjectivePoint, Scalar)> {
+        (0..n)
+            .map(|_| {
+                (
+                    ProjectivePoint::random(&mut OsRng),
+                    Scalar::random(&mut OsRng),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_lincomb() {
+        for n in [0, 1, 2, 31, 32, 100] {
+            let mut terms = random_terms(n);
+            if n > 2 {
+                terms[0].1 = Scalar::ZERO;
+                terms[1].1 = -Scalar::ONE;
+                terms[2].0 = ProjectivePoint::IDENTITY;
+            }
+
+            let expected = ProjectivePoint::lincomb(terms.as_slice());
+            assert_eq!(pippenger_serial(&terms), expected);
+            assert_eq!(ProjectivePoint::msm_vartime(&terms), expected);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_serial() {
+        let terms = random_terms(1000);
+        assert_eq!(super::pippenger_parallel(&terms), pippenger_serial(&terms));
+    }
+}
@@ -3,7 +3,7 @@
 #![allow(clippy::op_ref)]
 
 use super::{AffinePoint, FieldElement, Scalar, CURVE_EQUATION_B_SINGLE};
-use crate::{CompressedPoint, EncodedPoint, PublicKey, Secp256k1};
+use crate::{CompressedPoint, EncodedPoint, FieldBytes, PublicKey, Secp256k1};
 use core::{
     iter::Sum,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
@@ -15,6 +15,7 @@ use elliptic_curve::{
         prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
         Curve, Group, GroupEncoding,
     },
+    point::DecompressPoint,
     rand_core::RngCore,
     sec1::{FromEncodedPoint, ToEncodedPoint},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
@@ -77,12 +78,70 @@ impl ProjectivePoint {
             .unwrap_or_else(|| AffinePoint::IDENTITY)
     }
 
+    /// Returns the affine representation of this point, along with the inverse
+    /// of its projective `z`-coordinate.
+    ///
+    /// This computes the same inversion as [`ProjectivePoint::to_affine`] does,
+    /// for callers which need it afterwards. The inverse is `None` for the
+    /// identity, whose `z`-coordinate is zero.
+    #[cfg(feature = "expose-field")]
+    pub fn to_affine_with_zinv(&self) -> (AffinePoint, CtOption<crate::FieldElement>) {
+        let zinv = self.z.invert();
+        let affine = zinv
+            .map(|zinv| self.to_affine_internal(zinv))
+            .unwrap_or_else(|| AffinePoint::IDENTITY);
+        (affine, zinv.map(crate::FieldElement::from))
+    }
+
     pub(super) fn to_affine_internal(self, zinv: FieldElement) -> AffinePoint {
         let x = self.x * &zinv;
         let y = self.y * &zinv;
         AffinePoint::new(x.normalize(), y.normalize())
     }
 
+    /// Serialize this point as a 33-byte compressed SEC1 point, without going
+    /// through [`EncodedPoint`].
+    ///
+    /// The identity has no affine coordinates, and is encoded as all zeroes
+    /// (i.e. its `0x00` SEC1 tag, padded with zeroes to the full length). This
+    /// matches [`GroupEncoding::to_bytes`].
+    pub fn to_compressed_bytes(&self) -> [u8; 33] {
+        let affine = self.to_affine();
+        let mut bytes = [0u8; 33];
+        bytes[0] = affine.sec1_tag();
+        // The affine identity has an all-zero x-coordinate
+        bytes[1..].copy_from_slice(&affine.x.to_bytes());
+        bytes
+    }
+
+    /// Parse a 33-byte compressed SEC1 point, as serialized by
+    /// [`ProjectivePoint::to_compressed_bytes`].
+    ///
+    /// Returns `None` if the tag is not `0x02` or `0x03`, if `x` is not the
+    /// x-coordinate of a point on the curve, or if the bytes are not the
+    /// all-zero encoding of the identity.
+    pub fn from_compressed_bytes(bytes: &[u8; 33]) -> CtOption<Self> {
+        let tag = bytes[0];
+        let is_compressed = tag.ct_eq(&0x02) | tag.ct_eq(&0x03);
+        let is_identity = bytes[..].ct_eq(&[0u8; 33][..]);
+
+        let mut x = FieldBytes::default();
+        x.copy_from_slice(&bytes[1..]);
+
+        AffinePoint::decompress(&x, Choice::from(tag & 1))
+            .and_then(|point| CtOption::new(Self::from(point), is_compressed))
+            .or_else(|| CtOption::new(Self::IDENTITY, is_identity))
+    }
+
+    /// Is this point the identity point?
+    ///
+    /// The identity is the only point with `z = 0`, so this checks the
+    /// `z`-coordinate in constant time, without comparing against
+    /// [`ProjectivePoint::IDENTITY`] (which requires multiplications).
+    pub fn is_identity(&self) -> Choice {
+        self.z.normalizes_to_zero()
+    }
+
     /// Returns `-self`.
     fn neg(&self) -> ProjectivePoint {
         ProjectivePoint {
@@ -92,6 +151,33 @@ impl ProjectivePoint {
         }
     }
 
+    /// Returns `-self`, leaving `y` with magnitude 2 rather than weakly
+    /// normalizing it. See [`crate::hazmat::neg_unnormalized`].
+    pub(crate) fn neg_unnormalized(&self) -> ProjectivePoint {
+        ProjectivePoint {
+            x: self.x,
+            y: self.y.negate(1),
+            z: self.z,
+        }
+    }
+
+    /// Builds a point from raw projective coordinates without checking that
+    /// it is on the curve. See [`crate::hazmat::from_coordinates`].
+    #[cfg(feature = "expose-field")]
+    pub(crate) const fn from_coordinates_unchecked(
+        x: FieldElement,
+        y: FieldElement,
+        z: FieldElement,
+    ) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the raw projective coordinates `(X, Y, Z)` of this point.
+    #[cfg(feature = "expose-field")]
+    pub(crate) const fn coordinates(&self) -> (FieldElement, FieldElement, FieldElement) {
+        (self.x, self.y, self.z)
+    }
+
     /// Returns `self + other`.
     fn add(&self, other: &ProjectivePoint) -> ProjectivePoint {
         // We implement the complete addition formula from Renes-Costello-Batina 2015
@@ -218,6 +304,11 @@ impl ProjectivePoint {
     }
 
     /// Calculates SECP256k1 endomorphism: `self * lambda`.
+    ///
+    /// This is specific to secp256k1, and is what allows variable-base scalar
+    /// multiplication to be computed using the GLV method. Other curves such as
+    /// P-256 have no efficiently computable endomorphism, so generic code
+    /// should not rely on this method being available.
     pub fn endomorphism(&self) -> Self {
         Self {
             x: self.x * &ENDOMORPHISM_BETA,
@@ -226,6 +317,45 @@ impl ProjectivePoint {
         }
     }
 
+    /// Returns `self * k` for a small integer `k`, e.g. a cofactor or an index.
+    ///
+    /// This uses a double-and-add over only the significant bits of `k`,
+    /// which is much cheaper than a full scalar multiplication for small `k`.
+    ///
+    /// This runs in **variable time** with respect to `k`, which must not be
+    /// secret. The complete formulas are used, so the result is correct for
+    /// all inputs.
+    pub fn mul_u64(&self, k: u64) -> Self {
+        let bits = u64::BITS - k.leading_zeros();
+        (0..bits).rev().fold(Self::IDENTITY, |acc, i| {
+            let acc = acc.double();
+            if (k >> i) & 1 == 1 {
+                acc.add(self)
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Re-randomizes the projective representation of this point in place.
+    ///
+    /// Multiplies `X`, `Y` and `Z` by a random nonzero field element `lambda`,
+    /// which leaves the represented point unchanged (these are homogeneous
+    /// coordinates). This can be used as a countermeasure against differential
+    /// power analysis before performing a scalar multiplication.
+    pub fn randomize(&mut self, mut rng: impl RngCore) {
+        let lambda = loop {
+            let lambda = FieldElement::random(&mut rng);
+            if !bool::from(lambda.normalizes_to_zero()) {
+                break lambda;
+            }
+        };
+
+        self.x = self.x * &lambda;
+        self.y = self.y * &lambda;
+        self.z = self.z * &lambda;
+    }
+
     /// Check whether `self` is equal to an affine point.
     ///
     /// This is a lot faster than first converting `self` to an `AffinePoint` and then doing the
@@ -415,7 +545,7 @@ impl Group for ProjectivePoint {
     }
 
     fn is_identity(&self) -> Choice {
-        self.z.normalizes_to_zero()
+        self.is_identity()
     }
 
     #[must_use]
@@ -675,18 +805,40 @@ impl TryFrom<&ProjectivePoint> for PublicKey {
 mod tests {
     use super::{AffinePoint, ProjectivePoint};
     use crate::{
-        test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+        test_vectors::group::{ADD_TEST_VECTORS, DOUBLE_TEST_VECTORS, MUL_TEST_VECTORS},
         Scalar,
     };
     use elliptic_curve::group::{ff::PrimeField, prime::PrimeCurveAffine};
     use elliptic_curve::ops::MulByGenerator;
     use elliptic_curve::Field;
-    use elliptic_curve::{group, BatchNormalize};
+    use elliptic_curve::{group, group::GroupEncoding, BatchNormalize};
     use rand_core::OsRng;
 
     #[cfg(feature = "alloc")]
     use alloc::vec::Vec;
 
+    #[test]
+    fn is_identity() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let random = ProjectivePoint::random(&mut OsRng);
+        let points = [
+            (ProjectivePoint::IDENTITY, true),
+            (ProjectivePoint::GENERATOR, false),
+            (random, false),
+            (random - random, true),
+            (ProjectivePoint::GENERATOR * Scalar::ZERO, true),
+        ];
+
+        for (point, expected) in points {
+            assert_eq!(bool::from(point.is_identity()), expected);
+            assert_eq!(
+                bool::from(point.is_identity()),
+                bool::from(point.ct_eq(&ProjectivePoint::IDENTITY))
+            );
+        }
+    }
+
     #[test]
     fn affine_to_projective() {
         let basepoint_affine = AffinePoint::GENERATOR;
@@ -704,6 +856,26 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "expose-field")]
+    #[test]
+    fn to_affine_with_zinv() {
+        use crate::FieldElement;
+
+        for _ in 0..10 {
+            // A random multiple of the generator has a non-trivial `z`
+            let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+            let (affine, zinv) = point.to_affine_with_zinv();
+            let zinv = zinv.unwrap();
+
+            assert_eq!(affine, point.to_affine());
+            assert_eq!(zinv * FieldElement::from(point.z), FieldElement::ONE);
+        }
+
+        let (affine, zinv) = ProjectivePoint::IDENTITY.to_affine_with_zinv();
+        assert!(bool::from(affine.is_identity()));
+        assert!(bool::from(zinv.is_none()));
+    }
+
     #[test]
     fn batch_normalize_array() {
         let k: Scalar = Scalar::random(&mut OsRng);
@@ -848,6 +1020,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vector_repeated_double() {
+        let mut p = ProjectivePoint::GENERATOR;
+
+        for (i, (expected_x, expected_y)) in DOUBLE_TEST_VECTORS.iter().enumerate() {
+            let affine = p.to_affine();
+            assert_eq!(affine.x.to_bytes(), *expected_x, "2^{} * G", i);
+            assert_eq!(affine.y.to_bytes(), *expected_y, "2^{} * G", i);
+
+            p = p.double();
+        }
+    }
+
     #[test]
     fn projective_add_vs_double() {
         let generator = ProjectivePoint::GENERATOR;
@@ -882,6 +1067,64 @@ mod tests {
         assert_eq!(generator.double() - &generator, generator);
     }
 
+    #[test]
+    fn compressed_bytes_round_trip() {
+        let generator = ProjectivePoint::GENERATOR;
+        let bytes = generator.to_compressed_bytes();
+        assert_eq!(bytes, generator.to_encoded_point(true).as_bytes());
+        assert_eq!(
+            ProjectivePoint::from_compressed_bytes(&bytes).unwrap(),
+            generator
+        );
+
+        let point = generator * Scalar::random(&mut OsRng);
+        let bytes = point.to_compressed_bytes();
+        assert_eq!(bytes, point.to_bytes().as_slice());
+        assert_eq!(
+            ProjectivePoint::from_compressed_bytes(&bytes).unwrap(),
+            point
+        );
+
+        let identity = ProjectivePoint::IDENTITY.to_compressed_bytes();
+        assert_eq!(identity, [0u8; 33]);
+        assert_eq!(
+            ProjectivePoint::from_compressed_bytes(&identity).unwrap(),
+            ProjectivePoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn compressed_bytes_rejects_invalid() {
+        let mut bytes = ProjectivePoint::GENERATOR.to_compressed_bytes();
+        for tag in [0x00, 0x01, 0x04, 0x06] {
+            bytes[0] = tag;
+            assert!(bool::from(
+                ProjectivePoint::from_compressed_bytes(&bytes).is_none()
+            ));
+        }
+
+        // Identity tag with nonzero trailing bytes
+        let mut identity = [0u8; 33];
+        identity[32] = 1;
+        assert!(bool::from(
+            ProjectivePoint::from_compressed_bytes(&identity).is_none()
+        ));
+    }
+
+    #[test]
+    fn mul_u64() {
+        let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+        assert_eq!(point.mul_u64(5), point + point + point + point + point);
+        assert_eq!(point.mul_u64(0), ProjectivePoint::IDENTITY);
+        assert_eq!(point.mul_u64(1), point);
+        assert_eq!(point.mul_u64(8), point.double().double().double());
+        assert_eq!(point.mul_u64(u64::MAX), point * Scalar::from(u64::MAX));
+        assert_eq!(
+            ProjectivePoint::IDENTITY.mul_u64(12345),
+            ProjectivePoint::IDENTITY
+        );
+    }
+
     #[test]
     fn test_vector_scalar_mult() {
         let generator = ProjectivePoint::GENERATOR;
@@ -924,4 +1167,95 @@ mod tests {
             AffinePoint::GENERATOR.neg()
         );
     }
+
+    #[test]
+    fn randomize() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+        let mut randomized = point;
+        randomized.randomize(&mut OsRng);
+
+        assert!(bool::from(randomized.ct_eq(&point)));
+        assert_eq!(randomized.to_affine(), point.to_affine());
+        assert_ne!(randomized.z.normalize(), point.z.normalize());
+
+        let mut identity = ProjectivePoint::IDENTITY;
+        identity.randomize(&mut OsRng);
+        assert_eq!(identity, ProjectivePoint::IDENTITY);
+    }
+
+    #[test]
+    fn group_encoding_round_trip() {
+        let identity = ProjectivePoint::IDENTITY.to_bytes();
+        assert_eq!(identity.as_slice(), &[0; 33]);
+        assert_eq!(
+            ProjectivePoint::from_bytes(&identity).unwrap(),
+            ProjectivePoint::IDENTITY
+        );
+
+        for _ in 0..100 {
+            let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+            let bytes = point.to_bytes();
+            assert!(bytes[0] == 0x02 || bytes[0] == 0x03);
+            assert_eq!(ProjectivePoint::from_bytes(&bytes).unwrap(), point);
+            assert_eq!(
+                ProjectivePoint::from_bytes_unchecked(&bytes).unwrap(),
+                point
+            );
+            assert_eq!((-point).to_bytes()[1..], bytes[1..]);
+        }
+    }
+
+    #[test]
+    fn group_encoding_rejects_invalid() {
+        // x = 5 has no corresponding y on secp256k1
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x02;
+        bytes[32] = 0x05;
+        assert!(bool::from(
+            ProjectivePoint::from_bytes(&bytes.into()).is_none()
+        ));
+
+        // Invalid tag
+        let mut bytes = ProjectivePoint::GENERATOR.to_bytes();
+        bytes[0] = 0x06;
+        assert!(bool::from(ProjectivePoint::from_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn ct_eq_matches_affine() {
+        use super::FieldElement;
+        use elliptic_curve::{group::Group, subtle::ConstantTimeEq};
+
+        // Same point, but with all coordinates scaled by a random non-zero `λ`
+        fn rescale(p: &ProjectivePoint) -> ProjectivePoint {
+            let lambda = FieldElement::random(&mut OsRng);
+            ProjectivePoint {
+                x: p.x * &lambda,
+                y: p.y * &lambda,
+                z: p.z * &lambda,
+            }
+        }
+
+        let identity = ProjectivePoint::IDENTITY;
+        let mut points = [identity; 6];
+        points[1] = ProjectivePoint::GENERATOR;
+        points[2] = -ProjectivePoint::GENERATOR;
+        for p in &mut points[3..] {
+            *p = ProjectivePoint::random(&mut OsRng);
+        }
+
+        for a in &points {
+            for b in &points {
+                let expected = a.to_affine() == b.to_affine();
+                assert_eq!(bool::from(a.ct_eq(b)), expected);
+                assert_eq!(bool::from(rescale(a).ct_eq(&rescale(b))), expected);
+                assert_eq!(rescale(a) == *b, expected);
+            }
+        }
+
+        assert_eq!(rescale(&identity), identity);
+        assert_ne!(rescale(&identity), ProjectivePoint::GENERATOR);
+    }
 }
@@ -6,13 +6,14 @@ mod wide;
 
 pub(crate) use self::wide::WideScalar;
 
-use crate::{FieldBytes, Secp256k1, WideBytes, ORDER, ORDER_HEX};
+use crate::{Error, FieldBytes, Secp256k1, WideBytes, ORDER, ORDER_HEX};
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Shr, ShrAssign, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{prelude::*, Limb, Word, U256, U512},
+    array::{Array, ArraySize},
+    bigint::{prelude::*, Limb, Odd, Word, U256, U512},
     ff::{self, Field, PrimeField},
     ops::{Invert, Reduce, ReduceNonZero},
     rand_core::{CryptoRngCore, RngCore},
@@ -38,6 +39,9 @@ use num_bigint::{BigUint, ToBigUint};
 /// n = FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE BAAEDCE6 AF48A03B BFD25E8C D0364141
 const MODULUS: [Word; U256::LIMBS] = ORDER.to_words();
 
+/// Modulus as an [`Odd`] integer, as required by safegcd inversion.
+const ODD_MODULUS: Odd<U256> = Odd::<U256>::from_be_hex(ORDER_HEX);
+
 /// Constant representing the modulus / 2
 const FRAC_MODULUS_2: U256 = ORDER.shr_vartime(1);
 
@@ -87,11 +91,41 @@ impl Scalar {
         self.0.is_zero()
     }
 
+    /// Checks if the scalar is one, in constant time.
+    pub fn is_one(&self) -> Choice {
+        self.ct_eq(&Self::ONE)
+    }
+
+    /// Is `w` less than the curve order `n`, i.e. a canonical scalar encoding?
+    ///
+    /// This is the check behind [`PrimeField::from_repr`], as well as the
+    /// `r < n` and `s < n` range checks on signature components.
+    pub fn ct_lt_order(w: &U256) -> Choice {
+        w.ct_lt(&Secp256k1::ORDER)
+    }
+
+    /// Is this scalar in the upper half of the field, i.e. greater than `n / 2`?
+    ///
+    /// This is the check behind [`IsHigh::is_high`] and low-S signature
+    /// normalization.
+    pub fn ct_is_high(&self) -> Choice {
+        self.0.ct_gt(&FRAC_MODULUS_2)
+    }
+
     /// Returns the SEC1 encoding of this scalar.
     pub fn to_bytes(&self) -> FieldBytes {
         self.0.to_be_byte_array()
     }
 
+    /// Returns the SEC1 encoding of this scalar as a plain byte array.
+    ///
+    /// This is the same as [`Scalar::to_bytes`]. Scalars are stored as
+    /// little endian limbs rather than as bytes, so the encoding can't be
+    /// borrowed and is always computed.
+    pub fn to_be_array(&self) -> [u8; 32] {
+        self.to_bytes().into()
+    }
+
     /// Negates the scalar.
     pub const fn negate(&self) -> Self {
         Self(self.0.neg_mod(&ORDER))
@@ -102,6 +136,16 @@ impl Scalar {
         Self(self.0.add_mod(&rhs.0, &ORDER))
     }
 
+    /// Returns 2*self.
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Returns 3*self.
+    pub const fn triple(&self) -> Self {
+        self.double().add(self)
+    }
+
     /// Returns self - rhs mod n.
     pub const fn sub(&self, rhs: &Self) -> Self {
         Self(self.0.sub_mod(&rhs.0, &ORDER))
@@ -117,6 +161,21 @@ impl Scalar {
         self.mul(self)
     }
 
+    /// Returns an infinite iterator over the powers of this scalar: `1, x, x², x³, …`.
+    ///
+    /// Each item costs one multiplication. This is useful for evaluating polynomials,
+    /// e.g. by zipping the powers with the coefficients and summing the products.
+    pub fn powers(&self) -> impl Iterator<Item = Scalar> {
+        let x = *self;
+        core::iter::successors(Some(Self::ONE), move |power| Some(power * &x))
+    }
+
+    /// Returns the first `n` powers of `base`: `1, base, …, base^(n-1)`.
+    #[cfg(feature = "alloc")]
+    pub fn powers_of(base: &Self, n: usize) -> alloc::vec::Vec<Self> {
+        base.powers().take(n).collect()
+    }
+
     /// Right shifts the scalar.
     ///
     /// Note: not constant-time with respect to the `shift` parameter.
@@ -125,54 +184,62 @@ impl Scalar {
     }
 
     /// Inverts the scalar.
+    ///
+    /// Uses the constant-time Bernstein-Yang "safegcd" algorithm:
+    /// <https://eprint.iacr.org/2019/266>
     pub fn invert(&self) -> CtOption<Self> {
-        // Using an addition chain from
-        // https://briansmith.org/ecc-inversion-addition-chains-01#secp256k1_scalar_inversion
-        let x_1 = *self;
-        let x_10 = self.pow2k(1);
-        let x_11 = x_10.mul(&x_1);
-        let x_101 = x_10.mul(&x_11);
-        let x_111 = x_10.mul(&x_101);
-        let x_1001 = x_10.mul(&x_111);
-        let x_1011 = x_10.mul(&x_1001);
-        let x_1101 = x_10.mul(&x_1011);
-
-        let x6 = x_1101.pow2k(2).mul(&x_1011);
-        let x8 = x6.pow2k(2).mul(&x_11);
-        let x14 = x8.pow2k(6).mul(&x6);
-        let x28 = x14.pow2k(14).mul(&x14);
-        let x56 = x28.pow2k(28).mul(&x28);
-
-        #[rustfmt::skip]
-            let res = x56
-            .pow2k(56).mul(&x56)
-            .pow2k(14).mul(&x14)
-            .pow2k(3).mul(&x_101)
-            .pow2k(4).mul(&x_111)
-            .pow2k(4).mul(&x_101)
-            .pow2k(5).mul(&x_1011)
-            .pow2k(4).mul(&x_1011)
-            .pow2k(4).mul(&x_111)
-            .pow2k(5).mul(&x_111)
-            .pow2k(6).mul(&x_1101)
-            .pow2k(4).mul(&x_101)
-            .pow2k(3).mul(&x_111)
-            .pow2k(5).mul(&x_1001)
-            .pow2k(6).mul(&x_101)
-            .pow2k(10).mul(&x_111)
-            .pow2k(4).mul(&x_111)
-            .pow2k(9).mul(&x8)
-            .pow2k(5).mul(&x_1001)
-            .pow2k(6).mul(&x_1011)
-            .pow2k(4).mul(&x_1101)
-            .pow2k(5).mul(&x_11)
-            .pow2k(6).mul(&x_1101)
-            .pow2k(10).mul(&x_1101)
-            .pow2k(4).mul(&x_1001)
-            .pow2k(6).mul(&x_1)
-            .pow2k(8).mul(&x6);
-
-        CtOption::new(res, !self.is_zero())
+        let inv = CtOption::from(self.0.inv_odd_mod(&ODD_MODULUS));
+        CtOption::new(
+            Self(inv.unwrap_or(U256::ZERO)),
+            inv.is_some() & !self.is_zero(),
+        )
+    }
+
+    /// Returns the square root of self in the scalar field, if it exists.
+    ///
+    /// The group order `n` satisfies `n ≡ 1 mod 64` (i.e. `n - 1 = 2^6 * t` with
+    /// `t` odd, hence [`PrimeField::S`] is 6), so the simpler `(n + 1) / 4`
+    /// exponentiation doesn't apply. This uses constant-time Tonelli-Shanks for
+    /// `q mod 16 = 1`:
+    /// <https://eprint.iacr.org/2012/685.pdf> (page 12, algorithm 5)
+    #[allow(clippy::many_single_char_names)]
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // Note: `pow_vartime` is constant-time with respect to `self`
+        let w = self.pow_vartime([
+            0x777fa4bd19a06c82,
+            0xfd755db9cd5e9140,
+            0xffffffffffffffff,
+            0x1ffffffffffffff,
+        ]);
+
+        let mut v = Self::S;
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = Self::ROOT_OF_UNITY;
+
+        for max_v in (1..=Self::S).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v = Choice::from(1);
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.is_one();
+                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.is_one());
+            z = z.square();
+            b *= z;
+            v = k;
+        }
+
+        CtOption::new(x, x.square().ct_eq(self))
     }
 
     /// Returns the scalar modulus as a `BigUint` object.
@@ -204,19 +271,82 @@ impl Scalar {
         }
     }
 
+    /// Interpret `bytes` as a big endian integer and reduce it modulo the
+    /// secp256k1 curve order `n`.
+    ///
+    /// This is the explicit way to convert a scalar encoded for another curve,
+    /// such as a P-256 scalar, since the two curves have different orders.
+    /// Values encoded for the other curve which are not less than `n` do not
+    /// survive the round trip unchanged.
+    pub fn reduce_from_field_bytes(bytes: &FieldBytes) -> Self {
+        <Self as Reduce<U256>>::reduce_bytes(bytes)
+    }
+
+    /// Interpret `bytes` as a big endian integer and reduce it modulo the
+    /// secp256k1 curve order `n`.
+    ///
+    /// This is an alias of [`Scalar::reduce_from_field_bytes`], under the name
+    /// used for the same operation by other ecosystems, e.g. arkworks'
+    /// `PrimeField::from_be_bytes_mod_order`.
+    pub fn from_be_bytes_mod_order(bytes: &FieldBytes) -> Self {
+        Self::reduce_from_field_bytes(bytes)
+    }
+
+    /// Converts the output of a finalized message digest into a scalar.
+    ///
+    /// As with ECDSA's `bits2int`, digests longer than 32 bytes are truncated
+    /// to their leftmost 256 bits and shorter ones are zero-extended, and the
+    /// result is then reduced modulo the curve order.
+    pub fn from_digest_bytes<N: ArraySize>(digest: &Array<u8, N>) -> Self {
+        let len = digest.len().min(32);
+        let mut bytes = FieldBytes::default();
+        bytes[32 - len..].copy_from_slice(&digest[..len]);
+        <Self as Reduce<U256>>::reduce_bytes(&bytes)
+    }
+
+    /// Finalizes the given digest and converts its output into a scalar.
+    ///
+    /// See [`Scalar::from_digest_bytes`].
+    #[cfg(feature = "sha2")]
+    pub fn from_digest<D: sha2::digest::Digest>(digest: D) -> Self {
+        Self::from_digest_bytes(&digest.finalize())
+    }
+
+    /// Parse a scalar from 64 big endian hexadecimal digits (upper or lower case).
+    ///
+    /// Returns [`Error::InvalidLength`] if `hex` is not 64 characters long,
+    /// [`Error::InvalidHex`] if it contains a non-hexadecimal character, and
+    /// [`Error::NonCanonicalScalar`] if the value is not less than the curve order.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let hex = hex.as_bytes();
+        if hex.len() != 64 {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut bytes = FieldBytes::default();
+        for (byte, digits) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+            let hi = decode_hex_digit(digits[0]).ok_or(Error::InvalidHex)?;
+            let lo = decode_hex_digit(digits[1]).ok_or(Error::InvalidHex)?;
+            *byte = (hi << 4) | lo;
+        }
+
+        Option::<Self>::from(Self::from_repr(bytes)).ok_or(Error::NonCanonicalScalar)
+    }
+
     /// Attempts to parse the given byte array as a scalar.
     /// Does not check the result for being in the correct range.
     pub(crate) const fn from_bytes_unchecked(bytes: &[u8; 32]) -> Self {
         Self(U256::from_be_slice(bytes))
     }
+}
 
-    /// Raises the scalar to the power `2^k`.
-    fn pow2k(&self, k: usize) -> Self {
-        let mut x = *self;
-        for _j in 0..k {
-            x = x.square();
-        }
-        x
+/// Decode a single hexadecimal digit.
+fn decode_hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
     }
 }
 
@@ -244,53 +374,15 @@ impl Field for Scalar {
 
     #[must_use]
     fn double(&self) -> Self {
-        self.add(self)
+        Scalar::double(self)
     }
 
     fn invert(&self) -> CtOption<Self> {
         Scalar::invert(self)
     }
 
-    /// Tonelli-Shank's algorithm for q mod 16 = 1
-    /// <https://eprint.iacr.org/2012/685.pdf> (page 12, algorithm 5)
-    #[allow(clippy::many_single_char_names)]
     fn sqrt(&self) -> CtOption<Self> {
-        // Note: `pow_vartime` is constant-time with respect to `self`
-        let w = self.pow_vartime([
-            0x777fa4bd19a06c82,
-            0xfd755db9cd5e9140,
-            0xffffffffffffffff,
-            0x1ffffffffffffff,
-        ]);
-
-        let mut v = Self::S;
-        let mut x = *self * w;
-        let mut b = x * w;
-        let mut z = Self::ROOT_OF_UNITY;
-
-        for max_v in (1..=Self::S).rev() {
-            let mut k = 1;
-            let mut tmp = b.square();
-            let mut j_less_than_v = Choice::from(1);
-
-            for j in 2..max_v {
-                let tmp_is_one = tmp.ct_eq(&Self::ONE);
-                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
-                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
-                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
-                j_less_than_v &= !j.ct_eq(&v);
-                k = u32::conditional_select(&j, &k, tmp_is_one);
-                z = Self::conditional_select(&z, &new_z, j_less_than_v);
-            }
-
-            let result = x * z;
-            x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
-            z = z.square();
-            b *= z;
-            v = k;
-        }
-
-        CtOption::new(x, x.square().ct_eq(self))
+        self.sqrt()
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -331,7 +423,7 @@ impl PrimeField for Scalar {
     /// [0, p).
     fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
         let inner = U256::from_be_byte_array(bytes);
-        CtOption::new(Self(inner), inner.ct_lt(&Secp256k1::ORDER))
+        CtOption::new(Self(inner), Self::ct_lt_order(&inner))
     }
 
     fn to_repr(&self) -> FieldBytes {
@@ -480,7 +572,7 @@ impl Invert for Scalar {
 
 impl IsHigh for Scalar {
     fn is_high(&self) -> Choice {
-        self.0.ct_gt(&FRAC_MODULUS_2)
+        self.ct_is_high()
     }
 }
 
@@ -639,6 +731,14 @@ impl Mul<&Scalar> for &Scalar {
     }
 }
 
+impl Mul<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        Scalar::mul(self, &other)
+    }
+}
+
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
@@ -786,6 +886,20 @@ impl<'de> Deserialize<'de> for Scalar {
     }
 }
 
+/// Produces a scalar by reducing 32 bytes of fuzzer input modulo the group order, so every
+/// input yields a valid (in range) scalar.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Scalar {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = <[u8; 32] as arbitrary::Arbitrary>::arbitrary(u)?;
+        Ok(Self::reduce_from_field_bytes(&bytes.into()))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 32] as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Scalar;
@@ -803,7 +917,7 @@ mod tests {
     use num_bigint::{BigUint, ToBigUint};
     use num_traits::Zero;
     use proptest::prelude::*;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     #[cfg(feature = "alloc")]
     use alloc::vec::Vec;
@@ -908,6 +1022,54 @@ mod tests {
         }
     }
 
+    /// Square roots of known residues, and rejection of known non-residues.
+    #[test]
+    fn sqrt_known_residues() {
+        for n in [0u64, 4, 9, 1 << 32] {
+            let scalar = Scalar::from(n);
+            let sqrt = scalar.sqrt().unwrap();
+            assert_eq!(sqrt.square(), scalar);
+        }
+
+        // `2` is a square root of 4, so the result is either 2 or -2
+        let two = Scalar::from(2u64);
+        let sqrt = Scalar::from(4u64).sqrt().unwrap();
+        assert!(sqrt == two || sqrt == -two);
+
+        // n ≡ 1 mod 4, so -1 is a quadratic residue
+        let minus_one = -Scalar::ONE;
+        assert_eq!(minus_one.sqrt().unwrap().square(), minus_one);
+
+        // Small known non-residues, and the multiplicative generator
+        for n in [5u64, 7, 10] {
+            assert!(bool::from(Scalar::from(n).sqrt().is_none()));
+        }
+        assert!(bool::from(
+            Scalar::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+    }
+
+    #[test]
+    fn is_zero_and_is_one() {
+        assert!(bool::from(Scalar::ZERO.is_zero()));
+        assert!(!bool::from(Scalar::ZERO.is_one()));
+        assert!(bool::from(Scalar::ONE.is_one()));
+        assert!(!bool::from(Scalar::ONE.is_zero()));
+
+        for scalar in [
+            Scalar::from(2u64),
+            -Scalar::ONE,
+            Scalar::ONE + Scalar::ONE - Scalar::ONE,
+        ] {
+            assert_eq!(bool::from(scalar.is_zero()), scalar == Scalar::ZERO);
+            assert_eq!(bool::from(scalar.is_one()), scalar == Scalar::ONE);
+        }
+
+        // Arithmetic results which wrap around the order
+        assert!(bool::from((-Scalar::ONE + Scalar::ONE).is_zero()));
+        assert!(bool::from((-Scalar::ONE * -Scalar::ONE).is_one()));
+    }
+
     /// Basic tests that `invert` works.
     #[test]
     fn invert() {
@@ -930,6 +1092,67 @@ mod tests {
         );
     }
 
+    /// `invert` (safegcd) must agree with Fermat inversion `self^(n - 2)`.
+    #[test]
+    fn invert_matches_fermat() {
+        const N_MINUS_2: [u64; 4] = [
+            0xbfd2_5e8c_d036_413f,
+            0xbaae_dce6_af48_a03b,
+            0xffff_ffff_ffff_fffe,
+            0xffff_ffff_ffff_ffff,
+        ];
+
+        assert!(bool::from(Scalar::ZERO.invert().is_none()));
+        assert_eq!(Scalar::ONE.invert().unwrap(), Scalar::ONE);
+
+        let minus_one = -Scalar::ONE;
+        assert_eq!(minus_one.invert().unwrap(), minus_one);
+
+        for _ in 0..100 {
+            let k = Scalar::random(&mut OsRng);
+            assert_eq!(k.invert().unwrap(), k.pow_vartime(N_MINUS_2));
+        }
+    }
+
+    /// The inverse of a `NonZeroScalar` is available without unwrapping.
+    #[test]
+    fn non_zero_scalar_invert() {
+        let r = NonZeroScalar::random(&mut OsRng);
+        let inv: NonZeroScalar = r.invert();
+        let inv_vartime: NonZeroScalar = r.invert_vartime();
+
+        assert_eq!(*inv * *r, Scalar::ONE);
+        assert_eq!(*inv_vartime, *inv);
+    }
+
+    /// Parsing a `NonZeroScalar` rejects zero, out of range values and
+    /// incorrect lengths, rather than panicking.
+    #[test]
+    fn non_zero_scalar_try_from_slice() {
+        use crate::SecretKey;
+
+        let order = ORDER.to_be_byte_array();
+        let order_minus_one = ORDER.wrapping_sub(&U256::ONE).to_be_byte_array();
+
+        for bytes in [&[0u8; 32][..], &order[..], &[0xff; 32][..]] {
+            assert!(NonZeroScalar::try_from(bytes).is_err());
+            assert!(SecretKey::from_slice(bytes).is_err());
+        }
+
+        for bytes in [&[1u8; 31][..], &[1; 33][..], &[][..]] {
+            assert!(NonZeroScalar::try_from(bytes).is_err());
+        }
+
+        let scalar = NonZeroScalar::try_from(&order_minus_one[..]).unwrap();
+        assert_eq!(*scalar, -Scalar::ONE);
+        assert_eq!(
+            SecretKey::from_slice(&order_minus_one)
+                .unwrap()
+                .to_nonzero_scalar(),
+            scalar
+        );
+    }
+
     /// Basic tests that `invert_vartime` works.
     #[test]
     fn invert_vartime() {
@@ -979,6 +1202,29 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[test]
+    fn negation_identities() {
+        use elliptic_curve::subtle::{Choice, ConditionallyNegatable};
+
+        for x in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut OsRng),
+        ] {
+            assert_eq!(-x + x, Scalar::ZERO);
+            assert_eq!(-(-x), x);
+            assert_eq!(x.negate(), -x);
+            assert_eq!(-&x, -x);
+
+            let mut y = x;
+            y.conditional_negate(Choice::from(0));
+            assert_eq!(y, x);
+            y.conditional_negate(Choice::from(1));
+            assert_eq!(y, -x);
+        }
+    }
+
     #[test]
     fn negate() {
         let zero_neg = -Scalar::ZERO;
@@ -1000,6 +1246,52 @@ mod tests {
         assert_eq!(modulus_minus_one_neg, Scalar::ONE);
     }
 
+    #[test]
+    fn ct_eq() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let x = Scalar::random(&mut OsRng);
+        let y = x + Scalar::ONE;
+        assert!(bool::from(x.ct_eq(&x)));
+        assert!(!bool::from(x.ct_eq(&y)));
+        assert!(bool::from((y - Scalar::ONE).ct_eq(&x)));
+
+        let x = NonZeroScalar::random(&mut OsRng);
+        let y = -x;
+        assert!(bool::from(x.ct_eq(&x)));
+        assert!(!bool::from(x.ct_eq(&y)));
+    }
+
+    #[test]
+    fn powers() {
+        let x = Scalar::random(&mut OsRng);
+        let mut powers = x.powers().take(4);
+        let first: [Scalar; 4] = core::array::from_fn(|_| powers.next().unwrap());
+        assert_eq!(first, [Scalar::ONE, x, x * x, x * x * x]);
+
+        // Evaluate 3 + 2x + x² via the powers
+        let coefficients = [Scalar::from(3u32), Scalar::from(2u32), Scalar::ONE];
+        let eval: Scalar = coefficients
+            .iter()
+            .zip(x.powers())
+            .map(|(c, p)| c * &p)
+            .sum();
+        assert_eq!(eval, Scalar::from(3u32) + x.double() + x.square());
+
+        assert_eq!(Scalar::ZERO.powers().nth(1), Some(Scalar::ZERO));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn powers_of() {
+        let x = Scalar::random(&mut OsRng);
+        assert_eq!(
+            Scalar::powers_of(&x, 4),
+            vec![Scalar::ONE, x, x * x, x * x * x]
+        );
+        assert!(Scalar::powers_of(&x, 0).is_empty());
+    }
+
     #[test]
     fn add_result_within_256_bits() {
         // A regression for a bug where reduction was not applied
@@ -1121,6 +1413,309 @@ mod tests {
         assert!(!s.is_zero());
     }
 
+    #[test]
+    fn ct_comparison_boundaries() {
+        let half = ORDER.shr_vartime(1);
+        let order_minus_one = ORDER.wrapping_sub(&U256::ONE);
+
+        assert!(bool::from(Scalar::ct_lt_order(&U256::ZERO)));
+        assert!(bool::from(Scalar::ct_lt_order(&half)));
+        assert!(bool::from(Scalar::ct_lt_order(&order_minus_one)));
+        assert!(!bool::from(Scalar::ct_lt_order(&ORDER)));
+        assert!(!bool::from(Scalar::ct_lt_order(&U256::MAX)));
+
+        assert!(!bool::from(Scalar::ZERO.ct_is_high()));
+        assert!(!bool::from(Scalar(half).ct_is_high()));
+        assert!(bool::from(
+            Scalar(half.wrapping_add(&U256::ONE)).ct_is_high()
+        ));
+        assert!(bool::from(Scalar(order_minus_one).ct_is_high()));
+        assert_eq!(
+            Scalar(order_minus_one).ct_is_high().unwrap_u8(),
+            Scalar(order_minus_one).is_high().unwrap_u8()
+        );
+    }
+
+    #[test]
+    fn mul_operator_forms() {
+        let a = Scalar::random(&mut OsRng);
+        let b = Scalar::random(&mut OsRng);
+        let expected = Scalar::mul(&a, &b);
+
+        assert_eq!(a * b, expected);
+        assert_eq!(a * &b, expected);
+        assert_eq!(&a * b, expected);
+        assert_eq!(&a * &b, expected);
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, expected);
+
+        let mut c = a;
+        c *= &b;
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn double_and_triple() {
+        let one = Scalar::ONE;
+        assert_eq!(one.double(), one + one);
+        assert_eq!(one.triple(), one.double() + one);
+        assert_eq!(one.triple(), Scalar::from(3u32));
+
+        // Results are reduced modulo the order
+        let minus_one = -Scalar::ONE;
+        assert_eq!(minus_one.double(), -Scalar::from(2u32));
+        assert_eq!(minus_one.triple(), -Scalar::from(3u32));
+
+        for _ in 0..100 {
+            let x = Scalar::random(&mut OsRng);
+            assert_eq!(x.double(), x + x);
+            assert_eq!(x.triple(), x + x + x);
+        }
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn from_digest_bytes() {
+        use elliptic_curve::consts::U16;
+        use sha2::{Digest, Sha256, Sha512};
+
+        let msg = b"digests finalized elsewhere convert to the same scalar";
+
+        let digest = Sha256::digest(msg);
+        let expected = Scalar::from_digest_bytes(&digest);
+        assert_eq!(Scalar::from_digest(Sha256::new_with_prefix(msg)), expected);
+        assert_eq!(
+            expected,
+            <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::clone_from_slice(&digest))
+        );
+
+        // Longer digests are truncated to their leftmost 256 bits
+        let digest = Sha512::digest(msg);
+        assert_eq!(
+            Scalar::from_digest(Sha512::new_with_prefix(msg)),
+            Scalar::from_digest_bytes(&digest)
+        );
+        assert_eq!(
+            Scalar::from_digest_bytes(&digest),
+            <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::clone_from_slice(&digest[..32]))
+        );
+
+        // Shorter digests are zero-extended on the left
+        let short = Array::<u8, U16>::from([0xff; 16]);
+        assert_eq!(Scalar::from_digest_bytes(&short), Scalar::from(u128::MAX));
+
+        // Outputs at least the order are reduced
+        let max = FieldBytes::from([0xff; 32]);
+        assert_eq!(
+            Scalar::from_digest_bytes(&max),
+            <Scalar as Reduce<U256>>::reduce(U256::MAX)
+        );
+    }
+
+    #[test]
+    fn from_hex() {
+        use crate::Error;
+
+        const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+        const ORDER_HEX: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+
+        assert_eq!(Scalar::from_hex(ZERO), Ok(Scalar::ZERO));
+        assert_eq!(
+            Scalar::from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140"),
+            Ok(-Scalar::ONE)
+        );
+        assert_eq!(
+            Scalar::from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+            Ok(-Scalar::ONE)
+        );
+
+        // Wrong length
+        assert_eq!(Scalar::from_hex(""), Err(Error::InvalidLength));
+        assert_eq!(Scalar::from_hex(&ZERO[1..]), Err(Error::InvalidLength));
+        assert_eq!(
+            Scalar::from_hex(&ORDER_HEX[..62]),
+            Err(Error::InvalidLength)
+        );
+
+        // Invalid characters
+        assert_eq!(
+            Scalar::from_hex("0x00000000000000000000000000000000000000000000000000000000000000"),
+            Err(Error::InvalidHex)
+        );
+        assert_eq!(
+            Scalar::from_hex("000000000000000000000000000000000000000000000000000000000000000g"),
+            Err(Error::InvalidHex)
+        );
+
+        // Non-canonical: the order itself, and all ones
+        assert_eq!(Scalar::from_hex(ORDER_HEX), Err(Error::NonCanonicalScalar));
+        assert_eq!(
+            Scalar::from_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+            Err(Error::NonCanonicalScalar)
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_in_range() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut data = [0u8; 1000 * 32];
+        OsRng.fill_bytes(&mut data);
+        // Include inputs which are not less than the order, to exercise the reduction
+        data[..32].fill(0xff);
+        data[32..64].copy_from_slice(&ORDER.to_be_byte_array());
+
+        let mut u = Unstructured::new(&data);
+        for _ in 0..1000 {
+            let scalar = Scalar::arbitrary(&mut u).unwrap();
+            assert!(bool::from(Scalar::from_repr(scalar.to_bytes()).is_some()));
+        }
+        assert!(u.is_empty());
+    }
+
+    #[test]
+    fn reduce_from_field_bytes() {
+        // The P-256 order `n'` is less than the secp256k1 order, so `n' + 1`,
+        // which P-256 reduces to 1, is unchanged
+        let p256_order_plus_one =
+            U256::from_be_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632552");
+        let scalar = Scalar::reduce_from_field_bytes(&p256_order_plus_one.to_be_byte_array());
+        assert_eq!(scalar.to_bytes(), p256_order_plus_one.to_be_byte_array());
+        assert_ne!(scalar, Scalar::ONE);
+
+        // The secp256k1 order plus one does reduce
+        let order_plus_one = ORDER.wrapping_add(&U256::ONE);
+        assert_eq!(
+            Scalar::reduce_from_field_bytes(&order_plus_one.to_be_byte_array()),
+            Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn to_be_array() {
+        let scalar = Scalar::random(&mut OsRng);
+        let array = scalar.to_be_array();
+        let bytes = scalar.to_bytes();
+        let slice: &[u8] = bytes.as_ref();
+
+        assert_eq!(slice.len(), 32);
+        assert_eq!(array, slice);
+        assert_eq!(Scalar::from_repr(array.into()).unwrap(), scalar);
+    }
+
+    #[test]
+    fn from_be_bytes_mod_order() {
+        let order_plus_one = ORDER.wrapping_add(&U256::ONE).to_be_byte_array();
+        for bytes in [[0u8; 32].into(), [0xffu8; 32].into(), order_plus_one] {
+            assert_eq!(
+                Scalar::from_be_bytes_mod_order(&bytes),
+                Scalar::reduce_from_field_bytes(&bytes)
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_conformance() {
+        // U256: values are reduced modulo the order
+        assert_eq!(<Scalar as Reduce<U256>>::reduce(ORDER), Scalar::ZERO);
+        assert_eq!(
+            <Scalar as Reduce<U256>>::reduce(ORDER.wrapping_add(&U256::ONE)),
+            Scalar::ONE
+        );
+        assert_eq!(
+            <Scalar as Reduce<U256>>::reduce(U256::MAX),
+            Scalar::from_repr(
+                U256::from_be_hex(
+                    "000000000000000000000000000000014551231950B75FC4402DA1732FC9BEBE"
+                )
+                .to_be_byte_array()
+            )
+            .unwrap()
+        );
+
+        // U512: wide values are reduced modulo the order
+        let order_squared = U512::from_be_hex(concat!(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFD755DB9CD5E9140777FA4BD19A06C8283",
+            "9D671CD581C69BC5E697F5E45BCD07C52EC373A8BDC598B4493F50A1380E1281"
+        ));
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce(order_squared),
+            Scalar::ZERO
+        );
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce(order_squared.wrapping_add(&U512::from_u64(5))),
+            Scalar::from(5u64)
+        );
+
+        // Both agree on values which fit in 256 bits
+        for _ in 0..100 {
+            let mut bytes = [0u8; 64];
+            OsRng.fill_bytes(&mut bytes[32..]);
+            let narrow = U256::from_be_slice(&bytes[32..]);
+            let wide = U512::from_be_slice(&bytes);
+            assert_eq!(
+                <Scalar as Reduce<U256>>::reduce(narrow),
+                <Scalar as Reduce<U512>>::reduce(wide)
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_boundary_vectors() {
+        // (big endian input, expected value mod n)
+        let vectors = [
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140",
+            ),
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364142",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+            ),
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "000000000000000000000000000000014551231950B75FC4402DA1732FC9BEBE",
+            ),
+        ];
+
+        for (input, expected) in vectors {
+            let bytes = U256::from_be_hex(input).to_be_byte_array();
+            let expected = U256::from_be_hex(expected).to_be_byte_array();
+
+            assert_eq!(Scalar::reduce_from_field_bytes(&bytes).to_bytes(), expected);
+            assert_eq!(
+                <Scalar as Reduce<U256>>::reduce_bytes(&bytes).to_bytes(),
+                expected
+            );
+
+            // The reference reduction agrees
+            let reference = bytes_to_biguint(&bytes.into()) % Scalar::modulus_as_biguint();
+            assert_eq!(Scalar::from(&reference).to_bytes(), expected);
+        }
+
+        // `2n - 1` and `2n` don't fit in 256 bits, and are only reachable by
+        // wide reduction
+        let two_n_minus_one = U512::from_be_hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFD755DB9CD5E9140777FA4BD19A06C8281"
+        ));
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce(two_n_minus_one),
+            -Scalar::ONE
+        );
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce(two_n_minus_one.wrapping_add(&U512::ONE)),
+            Scalar::ZERO
+        );
+    }
+
     prop_compose! {
         fn scalar()(bytes in any::<[u8; 32]>()) -> Scalar {
             <Scalar as Reduce<U256>>::reduce_bytes(&bytes.into())
@@ -1141,6 +1736,15 @@ mod tests {
             assert_eq!(a, a_back);
         }
 
+        #[test]
+        fn fuzzy_reduce_bytes(bytes in any::<[u8; 32]>()) {
+            let res_bi = bytes_to_biguint(&bytes) % &Scalar::modulus_as_biguint();
+            let res_ref = Scalar::from(&res_bi);
+            let res_test = Scalar::reduce_from_field_bytes(&bytes.into());
+
+            assert_eq!(res_ref, res_test);
+        }
+
         #[test]
         fn fuzzy_add(a in scalar(), b in scalar()) {
             let a_bi = a.to_biguint().unwrap();
@@ -35,6 +35,14 @@
 //! // Both participants arrive on the same shared secret
 //! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
 //! ```
+//!
+//! # Key derivation
+//!
+//! The raw shared secret is the x-coordinate of the shared point and is not
+//! uniformly random, so it should not be used directly as a symmetric key.
+//! [`SharedSecret::extract`] runs the HKDF-Extract step over it with an
+//! optional salt, returning an `Hkdf` instance which can expand it into keys
+//! of any length (e.g. for ECIES).
 
 pub use elliptic_curve::ecdh::diffie_hellman;
 
@@ -51,3 +59,66 @@ impl From<&AffinePoint> for SharedSecret {
         affine.x.to_bytes().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::diffie_hellman;
+    use crate::{test_vectors::ecdh::ECDH_TEST_VECTORS, EncodedPoint, NonZeroScalar, PublicKey};
+    use elliptic_curve::sec1::FromEncodedPoint;
+
+    #[test]
+    fn ecdh_test_vectors() {
+        for vector in ECDH_TEST_VECTORS {
+            let secret = NonZeroScalar::try_from(&vector.d[..]).unwrap();
+            let point = EncodedPoint::from_affine_coordinates(
+                &vector.q_x.into(),
+                &vector.q_y.into(),
+                false,
+            );
+            let public = PublicKey::from_encoded_point(&point).unwrap();
+
+            let shared = diffie_hellman(secret, public.as_affine());
+            assert_eq!(shared.raw_secret_bytes().as_slice(), &vector.z);
+        }
+    }
+
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn hkdf_extract_agrees() {
+        use crate::ecdh::EphemeralSecret;
+        use rand_core::OsRng;
+        use sha2::Sha256;
+
+        let alice = EphemeralSecret::random(&mut OsRng);
+        let bob = EphemeralSecret::random(&mut OsRng);
+        let alice_public: PublicKey = alice.public_key();
+        let bob_public: PublicKey = bob.public_key();
+
+        let salt = b"k256 ecdh test salt";
+        let info = b"ecies encryption key";
+
+        let mut alice_key = [0u8; 32];
+        alice
+            .diffie_hellman(&bob_public)
+            .extract::<Sha256>(Some(salt))
+            .expand(info, &mut alice_key)
+            .unwrap();
+
+        let mut bob_key = [0u8; 32];
+        bob.diffie_hellman(&alice_public)
+            .extract::<Sha256>(Some(salt))
+            .expand(info, &mut bob_key)
+            .unwrap();
+
+        assert_eq!(alice_key, bob_key);
+
+        // A different salt produces a different key
+        let mut other_key = [0u8; 32];
+        alice
+            .diffie_hellman(&bob_public)
+            .extract::<Sha256>(None)
+            .expand(info, &mut other_key)
+            .unwrap();
+        assert_ne!(alice_key, other_key);
+    }
+}
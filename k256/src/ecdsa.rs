@@ -1,7 +1,7 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA).
 //!
 //! This module contains support for computing and verifying ECDSA signatures.
-//! To use it, you will need to enable one of the two following Cargo features:
+//! To use it, you will need to enable one of the following Cargo features:
 //!
 //! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
 //!   ECDSA/secp256k1 signature). Does not require the `arithmetic` feature.
@@ -13,6 +13,11 @@
 //! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
 //!   [`VerifyingKey`] types which natively implement ECDSA/secp256k1 signing and
 //!   verification.
+//! - `signing`: provides only the [`SigningKey`] type.
+//! - `verifying`: provides only the [`VerifyingKey`] type. This omits the
+//!   RFC6979 deterministic nonce generation (and its HMAC-DRBG) which is only
+//!   needed for signing, and is intended for verify-only targets such as
+//!   secure boot firmware.
 //!
 //! Most users of this library who want to sign/verify signatures will want to
 //! enable the `ecdsa` and `sha256` Cargo features.
@@ -91,17 +96,33 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Keccak-256 prehashes (Ethereum)
+//!
+//! The `DigestPrimitive` impl for [`Secp256k1`] always uses
+//! SHA-256, which is what [`signature::Signer`] and [`signature::Verifier`]
+//! hash messages with. Ethereum instead hashes messages with Keccak-256 (which
+//! is *not* the same as SHA3-256).
+//!
+//! To sign and verify such messages, compute the 32-byte Keccak-256 output
+//! yourself and pass it to the [`signature::hazmat::PrehashSigner`] and
+//! [`signature::hazmat::PrehashVerifier`] impls on [`SigningKey`] and
+//! [`VerifyingKey`] (or `SigningKey::sign_prehash_recoverable` and
+//! `VerifyingKey::recover_from_prehash` when a [`RecoveryId`] is needed).
 
 pub use ecdsa_core::{
     signature::{self, Error},
     EcdsaCurve, RecoveryId,
 };
 
-#[cfg(any(feature = "ecdsa", feature = "sha256"))]
-pub use ecdsa_core::hazmat;
+#[cfg(any(feature = "signing", feature = "verifying", feature = "sha256"))]
+pub mod hazmat;
 
 use crate::Secp256k1;
 
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+use alloc::vec::Vec;
+
 /// ECDSA/secp256k1 signature (fixed-size)
 pub type Signature = ecdsa_core::Signature<Secp256k1>;
 
@@ -112,12 +133,26 @@ impl EcdsaCurve for Secp256k1 {
     const NORMALIZE_S: bool = true;
 }
 
+/// Cheaply check whether `bytes` is a fixed-size `r || s` signature whose
+/// components `r` and `s` are both in the range `[1, n)`.
+///
+/// This is intended as a fast reject for untrusted input, e.g. on servers
+/// which must resist denial of service: it involves only comparisons, whereas
+/// verification requires a scalar inversion and multiplications.
+///
+/// Every [`Signature`] satisfies this, since the same check is performed when
+/// it is constructed. A well-formed signature may of course still fail to
+/// verify.
+pub fn is_well_formed(bytes: &[u8]) -> bool {
+    Signature::from_slice(bytes).is_ok()
+}
+
 /// ECDSA/secp256k1 signing key
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "signing")]
 pub type SigningKey = ecdsa_core::SigningKey<Secp256k1>;
 
 /// ECDSA/secp256k1 verification key (i.e. public key)
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "verifying")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<Secp256k1>;
 
 #[cfg(feature = "sha256")]
@@ -125,8 +160,821 @@ impl hazmat::DigestPrimitive for Secp256k1 {
     type Digest = sha2::Sha256;
 }
 
+#[cfg(all(feature = "signing", feature = "sha256"))]
+primeorder::impl_streaming_signer!(SigningKey, Signature, sha2::Sha256, "SHA-256");
+
+/// Sign `msg` using RFC6979 deterministic nonces mixed with caller-supplied
+/// `entropy`, as described in [RFC6979 § 3.6].
+///
+/// This is equivalent to signing with [`signature::RandomizedSigner`], but
+/// takes the additional entropy as raw bytes, which is useful on targets
+/// without an RNG implementing [`rand_core::CryptoRngCore`]. Even if `entropy`
+/// is predictable or repeated, signatures remain as secure as plain RFC6979.
+///
+/// [RFC6979 § 3.6]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.6
+/// [`rand_core::CryptoRngCore`]: elliptic_curve::rand_core::CryptoRngCore
+#[cfg(all(feature = "signing", feature = "sha256"))]
+pub fn sign_with_added_entropy(
+    signing_key: &SigningKey,
+    msg: &[u8],
+    entropy: &[u8; 32],
+) -> Result<Signature, Error> {
+    use sha2::{Digest, Sha256};
+
+    let z = Sha256::digest(msg);
+    let (signature, _) = hazmat::sign_prehashed_rfc6979::<Secp256k1, Sha256>(
+        signing_key.as_nonzero_scalar(),
+        &z,
+        entropy,
+    )?;
+
+    Ok(signature.normalize_s())
+}
+
+/// Sign `msg` like [`signature::Signer::sign`], checking the result before
+/// returning it to guard against fault injection attacks.
+///
+/// The signature is verified under the signing key's [`VerifyingKey`] before it
+/// is returned. Verification recomputes the nonce point as `u₁·G + u₂·Q` rather
+/// than `k·G`, so it doesn't share the computation a fault would have to
+/// corrupt. A detected fault is reported as an [`Error`] instead of releasing a
+/// faulty signature, which could leak the secret key.
+#[cfg(all(feature = "signing", feature = "verifying", feature = "sha256"))]
+pub fn sign_checked(signing_key: &SigningKey, msg: &[u8]) -> Result<Signature, Error> {
+    use signature::{Signer, Verifier};
+
+    let signature: Signature = signing_key.try_sign(msg)?;
+    signing_key.verifying_key().verify(msg, &signature)?;
+    Ok(signature)
+}
+
+/// Recover the [`VerifyingKey`] which produced `signature` over `msg`, and
+/// confirm that the signature verifies under the recovered key.
+///
+/// This provides Ethereum `ecrecover`-style semantics in a single call. Note
+/// that a signature always verifies under the key recovered from it, so an
+/// incorrect [`RecoveryId`] results in either an error or a *different* key:
+/// callers must still compare the result against the key (or address) they
+/// expect.
+#[cfg(all(feature = "verifying", feature = "sha256"))]
+pub fn recover_and_verify(
+    signature: &Signature,
+    recovery_id: RecoveryId,
+    msg: &[u8],
+) -> Result<VerifyingKey, Error> {
+    use signature::Verifier;
+
+    let verifying_key = VerifyingKey::recover_from_msg(msg, signature, recovery_id)?;
+    verifying_key.verify(msg, signature)?;
+    Ok(verifying_key)
+}
+
+/// Recover the [`VerifyingKey`] which produced `signature`, given the
+/// signature's nonce point `R` and the message's reduced prehash `z`.
+///
+/// Protocols which transmit `R` alongside the signature can use this to
+/// compute `r⁻¹(s⋅R - z⋅G)` directly, rather than lifting `r` to a point and
+/// guessing a [`RecoveryId`].
+///
+/// Returns an [`Error`] if the x-coordinate of `r_point` does not reduce to the
+/// signature's `r`, or if the recovered key would be the identity.
+#[cfg(feature = "verifying")]
+pub fn recover_with_r(
+    signature: &Signature,
+    z: &crate::Scalar,
+    r_point: &crate::AffinePoint,
+) -> Result<VerifyingKey, Error> {
+    use crate::{ProjectivePoint, Scalar};
+    use elliptic_curve::{
+        bigint::U256,
+        ops::{Invert, Reduce},
+        point::AffineCoordinates,
+    };
+
+    let (r, s) = signature.split_scalars();
+    if bool::from(r_point.is_identity())
+        || <Scalar as Reduce<U256>>::reduce_bytes(&r_point.x()) != *r
+    {
+        return Err(Error::new());
+    }
+
+    let r_inv = *Invert::invert(&r);
+    let u1 = -(r_inv * z);
+    let u2 = r_inv * *s;
+    let point = ProjectivePoint::mul_add(&u1, &ProjectivePoint::from(*r_point), &u2);
+    VerifyingKey::from_affine(point.to_affine())
+}
+
+/// Verify `signature` over the message's reduced prehash `z`, returning the
+/// recomputed value of `r` on success.
+///
+/// This computes `R = z⋅s⁻¹⋅G + r⋅s⁻¹⋅Q` and checks that its x-coordinate
+/// reduced modulo `n` equals `r`, as [`VerifyingKey::verify_prehash`] does
+/// (including rejecting non-normalized high `s` values). The reduced
+/// x-coordinate is returned so that callers can record it, e.g. for audit logs.
+///
+/// [`VerifyingKey::verify_prehash`]: signature::hazmat::PrehashVerifier::verify_prehash
+#[cfg(feature = "verifying")]
+pub fn verify_prehash_recompute(
+    verifying_key: &VerifyingKey,
+    z: &crate::Scalar,
+    signature: &Signature,
+) -> Result<crate::Scalar, Error> {
+    use crate::{ProjectivePoint, Scalar};
+    use elliptic_curve::{
+        bigint::U256,
+        ops::{Invert, LinearCombination, Reduce},
+        point::AffineCoordinates,
+        scalar::IsHigh,
+    };
+
+    let (r, s) = signature.split_scalars();
+    if Secp256k1::NORMALIZE_S && bool::from(s.is_high()) {
+        return Err(Error::new());
+    }
+
+    let s_inv = *Invert::invert(&s);
+    let point = ProjectivePoint::lincomb(&[
+        (ProjectivePoint::GENERATOR, *z * s_inv),
+        (
+            ProjectivePoint::from(*verifying_key.as_affine()),
+            *r * s_inv,
+        ),
+    ]);
+
+    let x = <Scalar as Reduce<U256>>::reduce_bytes(&point.to_affine().x());
+    if bool::from(point.is_identity()) || x != *r {
+        return Err(Error::new());
+    }
+
+    Ok(x)
+}
+
+/// Check whether `verifying_key` is one of the keys recoverable from
+/// `signature` for the message's reduced prehash `z`.
+///
+/// Each plausible [`RecoveryId`] (see [`recovery_ids`]) is tried in turn, and
+/// this returns as soon as one recovers `verifying_key`, without collecting
+/// the candidates. This is not constant-time, and is intended for public data.
+#[cfg(feature = "verifying")]
+pub fn verifying_key_is_candidate(
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+    z: &crate::Scalar,
+) -> bool {
+    // `z` is already reduced, so its encoding is a prehash which reduces back to it
+    let prehash = z.to_bytes();
+
+    recovery_ids(signature)
+        .filter_map(RecoveryId::from_byte)
+        .any(|recovery_id| {
+            VerifyingKey::recover_from_prehash(&prehash, signature, recovery_id)
+                .is_ok_and(|key| &key == verifying_key)
+        })
+}
+
+/// Select `a` if `choice` is 0 and `b` if it is 1, in constant time.
+///
+/// This is equivalent to `ConditionallySelectable::conditional_select`, which
+/// can't be implemented for [`Signature`] in this crate since the type is
+/// defined in `ecdsa-core`. [`crate::schnorr::Signature`] implements the trait
+/// directly.
+#[cfg(any(feature = "signing", feature = "verifying"))]
+pub fn conditional_select_signature(
+    a: &Signature,
+    b: &Signature,
+    choice: elliptic_curve::subtle::Choice,
+) -> Signature {
+    use crate::NonZeroScalar;
+    use elliptic_curve::subtle::ConditionallySelectable;
+
+    let (a_r, a_s) = a.split_scalars();
+    let (b_r, b_s) = b.split_scalars();
+    let r = NonZeroScalar::conditional_select(&a_r, &b_r, choice);
+    let s = NonZeroScalar::conditional_select(&a_s, &b_s, choice);
+    Signature::from_scalars(r, s).expect("both components are non-zero scalars")
+}
+
+/// Parse a 65-byte compact recoverable signature laid out as
+/// `recid || r || s`, as used when interoperating with libsecp256k1.
+///
+/// The leading byte is the raw [`RecoveryId`] in the range `0..=3`. Note this
+/// is not Ethereum's `v` or Bitcoin's signed-message header, which offset it by
+/// 27 (or more).
+pub fn recoverable_from_compact_libsecp(
+    bytes: &[u8; 65],
+) -> Result<(Signature, RecoveryId), Error> {
+    let recovery_id = RecoveryId::from_byte(bytes[0]).ok_or_else(Error::new)?;
+    let signature = Signature::from_slice(&bytes[1..])?;
+    Ok((signature, recovery_id))
+}
+
+/// Serialize a signature and its [`RecoveryId`] as a 65-byte compact
+/// recoverable signature laid out as `recid || r || s`.
+///
+/// This is the inverse of [`recoverable_from_compact_libsecp`].
+pub fn recoverable_to_compact_libsecp(signature: &Signature, recovery_id: RecoveryId) -> [u8; 65] {
+    let mut bytes = [0u8; 65];
+    bytes[0] = recovery_id.to_byte();
+    bytes[1..].copy_from_slice(&signature.to_bytes());
+    bytes
+}
+
+/// Verifier for many signatures whose messages are hashed by the caller.
+///
+/// Each entry is added with a digest state which has already absorbed its
+/// message, so a state which has absorbed a shared prefix can be cloned and
+/// extended with each message's suffix instead of rehashing the prefix every
+/// time. ECDSA has no batch verification equation, so each signature is still
+/// checked individually by [`BatchVerifier::verify`].
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+pub struct BatchVerifier<D = sha2::Sha256> {
+    items: Vec<(VerifyingKey, D, Signature)>,
+}
+
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+impl<D> BatchVerifier<D>
+where
+    VerifyingKey: signature::DigestVerifier<D, Signature>,
+{
+    /// Create an empty batch.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Queue a signature to be checked against the given key and digest state.
+    pub fn add(&mut self, verifying_key: VerifyingKey, digest: D, signature: Signature) {
+        self.items.push((verifying_key, digest, signature));
+    }
+
+    /// Number of signatures in the batch.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Is the batch empty?
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verify every signature in the batch, failing if any of them are invalid.
+    pub fn verify(self) -> Result<(), Error> {
+        self.items
+            .into_iter()
+            .try_for_each(|(verifying_key, digest, signature)| {
+                signature::DigestVerifier::verify_digest(&verifying_key, digest, &signature)
+            })
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+impl<D> Default for BatchVerifier<D>
+where
+    VerifyingKey: signature::DigestVerifier<D, Signature>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Recover the public key which produced every signature in `items`.
+///
+/// Each item is a [`Signature`] along with the message it was computed over.
+/// Candidate keys are recovered from the first item using every possible
+/// [`RecoveryId`], and a candidate is kept only if every other item also
+/// recovers to it.
+///
+/// Returns `None` if `items` is empty, if the signatures were not all produced
+/// by the same key, or if the items are insufficient to narrow the candidates
+/// down to a single key.
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+pub fn recover_common_key(items: &[(Signature, Vec<u8>)]) -> Option<VerifyingKey> {
+    let ((first_sig, first_msg), rest) = items.split_first()?;
+    let mut common_key = None;
+
+    for candidate in recover_candidates(first_msg, first_sig)
+        .into_iter()
+        .flatten()
+    {
+        let in_all = rest
+            .iter()
+            .all(|(sig, msg)| recover_candidates(msg, sig).contains(&Some(candidate)));
+
+        if in_all {
+            match common_key {
+                Some(key) if key != candidate => return None,
+                _ => common_key = Some(candidate),
+            }
+        }
+    }
+
+    common_key
+}
+
+/// Recover every distinct key which `signature` verifies under for `msg`.
+///
+/// This tries each [`RecoveryId`] (see [`recovery_ids`] for the ones which
+/// are plausible), and collects the recovered keys into a `Vec`, with
+/// duplicates removed. The signer's key is always among them.
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+pub fn candidate_verifying_keys_vec(msg: &[u8], signature: &Signature) -> Vec<VerifyingKey> {
+    let mut keys = Vec::with_capacity(4);
+
+    for key in recover_candidates(msg, signature).into_iter().flatten() {
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    keys
+}
+
+/// Enumerate the [`RecoveryId`] bytes which are plausible for `signature`.
+///
+/// An ID is yielded only if the x-coordinate it selects (`r`, or `r + n` for
+/// x-reduced IDs) is in range and lifts to a point on the curve. For a
+/// signature produced by an honest signer this is almost always exactly the
+/// two IDs `0` and `1`, as `r + n` exceeds the field modulus for all but a
+/// negligible fraction of `r` values.
+///
+/// This depends only on `r`: the message is needed to tell which of the
+/// yielded IDs recovers the signer's key.
+#[cfg(feature = "verifying")]
+pub fn recovery_ids(signature: &Signature) -> impl Iterator<Item = u8> {
+    use crate::{AffinePoint, U256};
+    use elliptic_curve::{bigint::ArrayEncoding, point::DecompressPoint, subtle::Choice};
+
+    let r_bytes = signature.r().to_bytes();
+    let r = U256::from_be_byte_array(r_bytes);
+
+    // `r + n` is a valid x-coordinate iff it is less than `p`, i.e. iff
+    // `r < p - n`. In that case the sum doesn't overflow, since `p < 2^256`.
+    let x_reduced_in_range = r < Secp256k1::FIELD_MODULUS.wrapping_sub(&Secp256k1::ORDER);
+    let r_plus_n_bytes = r.wrapping_add(&Secp256k1::ORDER).to_be_byte_array();
+
+    let valid: [bool; 4] = core::array::from_fn(|id| {
+        let is_x_reduced = id & 2 != 0;
+        let x_bytes = if is_x_reduced {
+            if !x_reduced_in_range {
+                return false;
+            }
+            &r_plus_n_bytes
+        } else {
+            &r_bytes
+        };
+
+        let y_is_odd = Choice::from((id & 1) as u8);
+        AffinePoint::decompress(x_bytes, y_is_odd).is_some().into()
+    });
+
+    (0u8..4).filter(move |&id| valid[usize::from(id)])
+}
+
+/// Validate a SEC1-encoded verifying key: check that it decodes to a point on
+/// the curve other than the identity, returning an [`Error`] otherwise.
+///
+/// This provides a uniform validation hook for code ported from curves with a
+/// cofactor, which must also check that public keys are in the prime order
+/// subgroup. secp256k1 has prime order, so that check is trivial here.
+///
+/// Use [`verifying_key_from_sec1_bytes`] to learn why a key was rejected.
+#[cfg(feature = "verifying")]
+pub fn validate_verifying_key(bytes: &[u8]) -> Result<(), Error> {
+    primeorder::validate_public_key::<Secp256k1>(bytes)
+        .map(|_| ())
+        .map_err(|_| Error::new())
+}
+
+/// Parse a SEC1-encoded [`VerifyingKey`], reporting why malformed input was rejected.
+///
+/// This accepts the same encodings as `VerifyingKey::from_sec1_bytes`, but
+/// returns a [`crate::Error`] describing the failure rather than an opaque
+/// [`Error`].
+#[cfg(feature = "verifying")]
+pub fn verifying_key_from_sec1_bytes(bytes: &[u8]) -> Result<VerifyingKey, crate::Error> {
+    use crate::{AffinePoint, EncodedPoint};
+    use elliptic_curve::sec1::{FromEncodedPoint, Tag};
+
+    let tag = bytes
+        .first()
+        .ok_or(crate::Error::InvalidLength)
+        .and_then(|&byte| Tag::from_u8(byte).map_err(|_| crate::Error::InvalidEncoding))?;
+
+    if tag == Tag::Identity {
+        return Err(crate::Error::IdentityPoint);
+    }
+
+    if bytes.len() != tag.message_len(32) {
+        return Err(crate::Error::InvalidLength);
+    }
+
+    let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| crate::Error::InvalidEncoding)?;
+    let point = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+        .ok_or(crate::Error::PointNotOnCurve)?;
+
+    VerifyingKey::from_affine(point).map_err(|_| crate::Error::IdentityPoint)
+}
+
+/// Parse a big endian serialized secret scalar as a [`SigningKey`], reporting
+/// why malformed input was rejected.
+#[cfg(feature = "signing")]
+pub fn signing_key_from_slice(bytes: &[u8]) -> Result<SigningKey, crate::Error> {
+    use crate::{FieldBytes, NonZeroScalar, Scalar};
+    use elliptic_curve::ff::PrimeField;
+
+    let bytes = FieldBytes::try_from(bytes).map_err(|_| crate::Error::InvalidLength)?;
+    let scalar =
+        Option::<Scalar>::from(Scalar::from_repr(bytes)).ok_or(crate::Error::NonCanonicalScalar)?;
+    let scalar = Option::<NonZeroScalar>::from(NonZeroScalar::new(scalar))
+        .ok_or(crate::Error::ZeroScalar)?;
+
+    Ok(SigningKey::from(scalar))
+}
+
+/// Parse a DER-encoded `SubjectPublicKeyInfo` as a [`VerifyingKey`], reporting
+/// why malformed input was rejected.
+///
+/// This accepts the same documents as `VerifyingKey::from_public_key_der`.
+/// Errors in the embedded SEC1 point are reported as by
+/// [`verifying_key_from_sec1_bytes`].
+#[cfg(all(feature = "verifying", feature = "pkcs8"))]
+pub fn verifying_key_from_public_key_der(bytes: &[u8]) -> Result<VerifyingKey, crate::Error> {
+    use crate::pkcs8::{AssociatedOid, SubjectPublicKeyInfoRef};
+
+    let spki = SubjectPublicKeyInfoRef::try_from(bytes).map_err(|_| crate::Error::InvalidDer)?;
+    spki.algorithm
+        .assert_oids(elliptic_curve::ALGORITHM_OID, Secp256k1::OID)
+        .map_err(|_| crate::Error::UnexpectedAlgorithm)?;
+
+    let point = spki
+        .subject_public_key
+        .as_bytes()
+        .ok_or(crate::Error::InvalidDer)?;
+    verifying_key_from_sec1_bytes(point)
+}
+
+/// Parse a DER-encoded PKCS#8 `PrivateKeyInfo` as a [`SigningKey`], reporting
+/// why malformed input was rejected.
+///
+/// This accepts the same documents as `SigningKey::from_pkcs8_der`. Errors in
+/// the secret scalar are reported as by [`signing_key_from_slice`].
+#[cfg(all(feature = "signing", feature = "pkcs8"))]
+pub fn signing_key_from_pkcs8_der(bytes: &[u8]) -> Result<SigningKey, crate::Error> {
+    use crate::{
+        pkcs8::{der::Decode, AssociatedOid, PrivateKeyInfoRef},
+        EncodedPoint,
+    };
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use sec1::EcPrivateKey;
+
+    let private_key_info =
+        PrivateKeyInfoRef::try_from(bytes).map_err(|_| crate::Error::InvalidDer)?;
+    private_key_info
+        .algorithm
+        .assert_oids(elliptic_curve::ALGORITHM_OID, Secp256k1::OID)
+        .map_err(|_| crate::Error::UnexpectedAlgorithm)?;
+
+    let ec_private_key = EcPrivateKey::from_der(private_key_info.private_key.as_bytes())
+        .map_err(|_| crate::Error::InvalidDer)?;
+    if ec_private_key
+        .parameters
+        .is_some_and(|params| params.named_curve() != Some(Secp256k1::OID))
+    {
+        return Err(crate::Error::UnexpectedAlgorithm);
+    }
+
+    let signing_key = signing_key_from_slice(ec_private_key.private_key)?;
+
+    if let Some(public_key) = ec_private_key.public_key {
+        let public_key =
+            EncodedPoint::from_bytes(public_key).map_err(|_| crate::Error::InvalidEncoding)?;
+        let expected = signing_key
+            .verifying_key()
+            .to_encoded_point(public_key.is_compressed());
+        if public_key != expected {
+            return Err(crate::Error::PublicKeyMismatch);
+        }
+    }
+
+    Ok(signing_key)
+}
+
+/// Recover the candidate keys for each possible [`RecoveryId`].
+#[cfg(all(feature = "alloc", feature = "verifying", feature = "sha256"))]
+fn recover_candidates(msg: &[u8], signature: &Signature) -> [Option<VerifyingKey>; 4] {
+    core::array::from_fn(|i| {
+        RecoveryId::from_byte(i as u8)
+            .and_then(|recid| VerifyingKey::recover_from_msg(msg, signature, recid).ok())
+    })
+}
+
 #[cfg(all(test, feature = "ecdsa", feature = "arithmetic"))]
 mod tests {
+    use crate::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    #[test]
+    fn streaming_signer() {
+        use crate::ecdsa::{NewSigner, StreamingSigner};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let msg = b"streaming signers hash the message incrementally before signing it";
+
+        let mut signer = StreamingSigner::new(&signing_key);
+        for chunk in msg.chunks(7) {
+            signer.update(chunk);
+        }
+
+        let expected: Signature = signing_key.sign(msg);
+        assert_eq!(signer.finalize_sign(), expected);
+
+        let mut signer = signing_key.new_signer();
+        for chunk in msg.chunks(13) {
+            signer.update(chunk);
+        }
+        assert_eq!(signer.finalize_sign(), expected);
+    }
+
+    #[test]
+    fn verifying_key_from_signing_key() {
+        use crate::{ecdsa::VerifyingKey, ProjectivePoint};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let from_ref = VerifyingKey::from(&signing_key);
+        assert_eq!(signing_key.verifying_key(), &from_ref);
+        assert_eq!(
+            from_ref.as_affine(),
+            &(ProjectivePoint::GENERATOR * *signing_key.as_nonzero_scalar()).to_affine()
+        );
+    }
+
+    #[test]
+    fn public_key_conversions() {
+        use crate::{ecdsa::VerifyingKey, AffinePoint, PublicKey};
+        use elliptic_curve::{group::prime::PrimeCurveAffine, sec1::ToEncodedPoint};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let verifying_key = *signing_key.verifying_key();
+
+        let public_key = PublicKey::from(verifying_key);
+        assert_eq!(public_key.as_affine(), verifying_key.as_affine());
+        assert_eq!(
+            public_key.to_encoded_point(true),
+            verifying_key.to_encoded_point(true)
+        );
+        assert_eq!(VerifyingKey::from(public_key), verifying_key);
+        assert_eq!(VerifyingKey::from(&public_key), verifying_key);
+
+        let affine = AffinePoint::from(public_key);
+        assert_eq!(PublicKey::try_from(affine).unwrap(), public_key);
+        assert_eq!(VerifyingKey::from_affine(affine).unwrap(), verifying_key);
+
+        // The identity is not a valid public key
+        assert!(PublicKey::try_from(AffinePoint::identity()).is_err());
+        assert!(VerifyingKey::from_affine(AffinePoint::identity()).is_err());
+    }
+
+    #[test]
+    fn validate_verifying_key() {
+        use super::validate_verifying_key;
+        use elliptic_curve::sec1::ToEncodedPoint;
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        for compress in [true, false] {
+            let encoded = signing_key.verifying_key().to_encoded_point(compress);
+            assert!(validate_verifying_key(encoded.as_bytes()).is_ok());
+        }
+
+        // The identity-encoded key
+        assert!(validate_verifying_key(&[0x00]).is_err());
+
+        // x = 5 has no corresponding y on secp256k1
+        let mut not_on_curve = [0u8; 33];
+        not_on_curve[0] = 0x02;
+        not_on_curve[32] = 0x05;
+        assert!(validate_verifying_key(&not_on_curve).is_err());
+    }
+
+    #[test]
+    fn recover_with_r() {
+        use super::recover_with_r;
+        use crate::{ecdsa::VerifyingKey, AffinePoint, Scalar};
+        use elliptic_curve::{bigint::U256, ops::Reduce, point::DecompressPoint, subtle::Choice};
+        use sha2::{Digest, Sha256};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let prehash = Sha256::digest(b"transmitted nonce point");
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&prehash);
+        let (signature, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        assert!(!recid.is_x_reduced());
+
+        let r_point = AffinePoint::decompress(
+            &signature.r().to_bytes(),
+            Choice::from(recid.is_y_odd() as u8),
+        )
+        .unwrap();
+
+        let expected = VerifyingKey::recover_from_prehash(&prehash, &signature, recid).unwrap();
+        assert_eq!(recover_with_r(&signature, &z, &r_point).unwrap(), expected);
+        assert_eq!(&expected, signing_key.verifying_key());
+
+        // The other point with the same x-coordinate recovers a different key
+        assert_ne!(recover_with_r(&signature, &z, &-r_point).unwrap(), expected);
+
+        // A point which doesn't match `r` is rejected
+        assert!(recover_with_r(&signature, &z, &AffinePoint::GENERATOR).is_err());
+        assert!(recover_with_r(&signature, &z, &AffinePoint::IDENTITY).is_err());
+    }
+
+    #[test]
+    fn is_well_formed() {
+        use super::is_well_formed;
+        use hex_literal::hex;
+
+        const N: [u8; 32] =
+            hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        const N_MINUS_ONE: [u8; 32] =
+            hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140");
+        let mut one = [0u8; 32];
+        one[31] = 1;
+
+        let concat = |r: &[u8; 32], s: &[u8; 32]| {
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(r);
+            bytes[32..].copy_from_slice(s);
+            bytes
+        };
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let signature: Signature = signing_key.sign(b"well-formed");
+        assert!(is_well_formed(&signature.to_bytes()));
+        assert!(is_well_formed(&concat(&one, &N_MINUS_ONE)));
+        assert!(is_well_formed(&concat(&N_MINUS_ONE, &one)));
+
+        // Out of range components
+        assert!(!is_well_formed(&concat(&[0; 32], &one)));
+        assert!(!is_well_formed(&concat(&one, &[0; 32])));
+        assert!(!is_well_formed(&concat(&N, &one)));
+        assert!(!is_well_formed(&concat(&one, &N)));
+        assert!(!is_well_formed(&[0xff; 64]));
+
+        // Wrong length
+        assert!(!is_well_formed(&signature.to_bytes()[..63]));
+        assert!(!is_well_formed(&[]));
+    }
+
+    #[test]
+    fn recovery_ids() {
+        use super::recovery_ids;
+        use crate::{AffinePoint, Scalar};
+        use elliptic_curve::{point::DecompressPoint, subtle::Choice};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        for msg in [&b"first"[..], b"second", b"third"] {
+            let (signature, recid) = signing_key.sign_recoverable(msg).unwrap();
+            assert_eq!(recovery_ids(&signature).count(), 2);
+            assert!(recovery_ids(&signature).eq([0, 1]));
+            assert!(recovery_ids(&signature).any(|id| id == recid.to_byte()));
+        }
+
+        // An `r` which isn't the x-coordinate of any point has no valid IDs
+        let r = (1u64..)
+            .map(Scalar::from)
+            .find(|x| {
+                AffinePoint::decompress(&x.to_bytes(), Choice::from(0))
+                    .is_none()
+                    .into()
+            })
+            .unwrap();
+        let signature = Signature::from_scalars(r.to_bytes(), Scalar::ONE.to_bytes()).unwrap();
+        assert_eq!(recovery_ids(&signature).count(), 0);
+    }
+
+    #[test]
+    fn sign_checked() {
+        use crate::ecdsa::{signature::Verifier, VerifyingKey};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let msg = b"fault-checked signatures match regular ones";
+
+        let signature = super::sign_checked(&signing_key, msg).unwrap();
+        let expected: Signature = signing_key.sign(msg);
+        assert_eq!(signature, expected);
+        assert!(VerifyingKey::from(&signing_key)
+            .verify(msg, &signature)
+            .is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn debug_redacts_secrets() {
+        use crate::SecretKey;
+        use alloc::string::String;
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let secrets: [String; 3] = [
+            format!("{:?}", signing_key),
+            format!("{:#?}", signing_key.as_nonzero_scalar()),
+            format!("{:?}", SecretKey::from(signing_key.as_nonzero_scalar())),
+        ];
+
+        for debug in &secrets {
+            assert!(!debug.contains("42"), "secret leaked: {}", debug);
+            assert!(!debug.contains("0x"), "secret leaked: {}", debug);
+        }
+
+        assert!(secrets[0].starts_with("SigningKey"));
+        assert!(secrets[1].starts_with("NonZeroScalar"));
+
+        // Public values keep their full `Debug` output.
+        let public = format!("{:?}", signing_key.verifying_key().as_affine());
+        assert!(public.starts_with("AffinePoint"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_verifier_shared_prefix() {
+        use crate::ecdsa::{signature::Verifier, BatchVerifier};
+        use sha2::{Digest, Sha256};
+
+        let prefix = b"shared message prefix which is only hashed once: ";
+        let keys = [
+            SigningKey::from_slice(&[0x42; 32]).unwrap(),
+            SigningKey::from_slice(&[0x43; 32]).unwrap(),
+        ];
+        let prefix_state = Sha256::new_with_prefix(prefix);
+
+        let mut batch = BatchVerifier::new();
+        for (i, suffix) in [&b"first"[..], b"second", b"third"].iter().enumerate() {
+            let signing_key = &keys[i % keys.len()];
+            let msg = [&prefix[..], suffix].concat();
+            let signature: Signature = signing_key.sign(&msg);
+
+            // Hashing the full message gives the same result
+            signing_key
+                .verifying_key()
+                .verify(&msg, &signature)
+                .unwrap();
+
+            let mut digest = prefix_state.clone();
+            digest.update(suffix);
+            batch.add(*signing_key.verifying_key(), digest, signature);
+        }
+        assert_eq!(batch.len(), 3);
+        assert!(batch.verify().is_ok());
+
+        // A signature over a different suffix is rejected
+        let signature: Signature = keys[0].sign(&[&prefix[..], b"first"].concat());
+        let mut digest = prefix_state.clone();
+        digest.update(b"tampered");
+
+        let mut batch = BatchVerifier::new();
+        batch.add(*keys[0].verifying_key(), digest, signature);
+        assert!(batch.verify().is_err());
+    }
+
+    #[test]
+    fn sign_with_added_entropy() {
+        use crate::ecdsa::{sign_with_added_entropy, signature::Verifier};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        let msg = b"signing with caller-supplied entropy";
+
+        let sig1 = sign_with_added_entropy(&signing_key, msg, &[0x01; 32]).unwrap();
+        let sig2 = sign_with_added_entropy(&signing_key, msg, &[0x02; 32]).unwrap();
+        assert_ne!(sig1, sig2);
+        assert!(verifying_key.verify(msg, &sig1).is_ok());
+        assert!(verifying_key.verify(msg, &sig2).is_ok());
+
+        // The same entropy produces the same signature
+        let sig3 = sign_with_added_entropy(&signing_key, msg, &[0x01; 32]).unwrap();
+        assert_eq!(sig1, sig3);
+
+        // Added entropy changes the nonce relative to plain RFC6979
+        let deterministic: Signature = signing_key.sign(msg);
+        assert_ne!(sig1, deterministic);
+    }
+
+    #[test]
+    fn conditional_select_signature() {
+        use crate::ecdsa::conditional_select_signature;
+        use elliptic_curve::subtle::Choice;
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let sig_a: Signature = signing_key.sign(b"message a");
+        let sig_b: Signature = signing_key.sign(b"message b");
+        assert_ne!(sig_a, sig_b);
+
+        assert_eq!(
+            conditional_select_signature(&sig_a, &sig_b, Choice::from(0)),
+            sig_a
+        );
+        assert_eq!(
+            conditional_select_signature(&sig_a, &sig_b, Choice::from(1)),
+            sig_b
+        );
+    }
+
     mod normalize {
         use crate::ecdsa::Signature;
 
@@ -257,6 +1105,756 @@ mod tests {
             assert_eq!(signing_key.verifying_key(), &verifying_key);
             assert!(verifying_key.verify_digest(digest, &sig).is_ok());
         }
+
+        /// `recover_from_digest` is generic over the digest: recovery must use
+        /// the same hash function the signer did.
+        #[test]
+        fn recovery_with_different_digests() {
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let verifying_key = signing_key.verifying_key();
+            let msg = b"recovering keys under different hash functions";
+
+            // SHA-256
+            let digest = Sha256::new_with_prefix(msg);
+            let (sig, recid) = signing_key.sign_digest_recoverable(digest.clone()).unwrap();
+            let recovered = VerifyingKey::recover_from_digest(digest, &sig, recid).unwrap();
+            assert_eq!(&recovered, verifying_key);
+
+            // Keccak-256 (Ethereum)
+            let digest = Keccak256::new_with_prefix(msg);
+            let (sig, recid) = signing_key.sign_digest_recoverable(digest.clone()).unwrap();
+            let recovered = VerifyingKey::recover_from_digest(digest, &sig, recid).unwrap();
+            assert_eq!(&recovered, verifying_key);
+
+            // Recovering with the wrong digest yields some other key, if any
+            let recovered =
+                VerifyingKey::recover_from_digest(Sha256::new_with_prefix(msg), &sig, recid);
+            assert!(recovered.map_or(true, |key| &key != verifying_key));
+
+            // Double SHA-256 (Bitcoin) isn't a `Digest` itself, and goes
+            // through the prehash API instead
+            let prehash = Sha256::digest(Sha256::digest(msg));
+            let (sig, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+            let recovered = VerifyingKey::recover_from_prehash(&prehash, &sig, recid).unwrap();
+            assert_eq!(&recovered, verifying_key);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn common_key_recovery() {
+            use crate::ecdsa::{recover_common_key, signature::Signer};
+            use alloc::vec::Vec;
+            use rand_core::OsRng;
+
+            let signing_key = SigningKey::random(&mut OsRng);
+            let mut items = (0..5u8)
+                .map(|i| {
+                    let msg = vec![i; 32];
+                    let sig: Signature = signing_key.sign(&msg);
+                    (sig, msg)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                recover_common_key(&items).as_ref(),
+                Some(signing_key.verifying_key())
+            );
+
+            let other_key = SigningKey::random(&mut OsRng);
+            let msg = b"signed by someone else".to_vec();
+            let sig: Signature = other_key.sign(&msg);
+            items.push((sig, msg));
+
+            assert_eq!(recover_common_key(&items), None);
+            assert_eq!(recover_common_key(&[]), None);
+        }
+
+        #[test]
+        fn verify_prehash_recompute() {
+            use crate::{
+                ecdsa::{signature::Signer, verify_prehash_recompute},
+                Scalar, U256,
+            };
+            use elliptic_curve::ops::Reduce;
+
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let verifying_key = signing_key.verifying_key();
+            let msg = b"recomputing r for audit logs";
+            let sig: Signature = signing_key.sign(msg);
+            let z = <Scalar as Reduce<U256>>::reduce_bytes(&Sha256::digest(msg));
+
+            let r = verify_prehash_recompute(verifying_key, &z, &sig).unwrap();
+            assert_eq!(r, *sig.r());
+
+            // Wrong message, wrong key, and the non-normalized `-s`
+            assert!(verify_prehash_recompute(verifying_key, &(z + Scalar::ONE), &sig).is_err());
+            let other_key = SigningKey::from_slice(&[0x43; 32]).unwrap();
+            assert!(verify_prehash_recompute(other_key.verifying_key(), &z, &sig).is_err());
+            let (r, s) = sig.split_scalars();
+            let sig_high = Signature::from_scalars(r, -s).unwrap();
+            assert!(verify_prehash_recompute(verifying_key, &z, &sig_high).is_err());
+        }
+
+        #[test]
+        fn verifying_key_is_candidate() {
+            use crate::{
+                ecdsa::{signature::Signer, verifying_key_is_candidate},
+                Scalar, U256,
+            };
+            use elliptic_curve::ops::Reduce;
+            use rand_core::OsRng;
+
+            let signing_key = SigningKey::random(&mut OsRng);
+            let msg = b"is this key a candidate for the signature?";
+            let sig: Signature = signing_key.sign(msg);
+            let z = <Scalar as Reduce<U256>>::reduce_bytes(&Sha256::digest(msg));
+
+            assert!(verifying_key_is_candidate(
+                &sig,
+                signing_key.verifying_key(),
+                &z
+            ));
+
+            let other_key = SigningKey::random(&mut OsRng);
+            assert!(!verifying_key_is_candidate(
+                &sig,
+                other_key.verifying_key(),
+                &z
+            ));
+
+            // A different prehash recovers different keys
+            assert!(!verifying_key_is_candidate(
+                &sig,
+                signing_key.verifying_key(),
+                &(z + Scalar::ONE)
+            ));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn candidate_verifying_keys() {
+            use crate::ecdsa::{candidate_verifying_keys_vec, recovery_ids, signature::Signer};
+            use alloc::vec::Vec;
+
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let msg = b"candidate keys for a signature";
+            let sig: Signature = signing_key.sign(msg);
+
+            let keys = candidate_verifying_keys_vec(msg, &sig);
+            assert!(keys.contains(signing_key.verifying_key()));
+
+            // The unique keys recovered from each plausible ID, in order
+            let mut expected = Vec::new();
+            for id in recovery_ids(&sig) {
+                let recid = RecoveryId::from_byte(id).unwrap();
+                if let Ok(key) = VerifyingKey::recover_from_msg(msg, &sig, recid) {
+                    if !expected.contains(&key) {
+                        expected.push(key);
+                    }
+                }
+            }
+            assert_eq!(keys, expected);
+
+            for (i, key) in keys.iter().enumerate() {
+                assert!(!keys[i + 1..].contains(key));
+            }
+        }
+    }
+
+    mod randomized {
+        use crate::ecdsa::{signature, Signature, SigningKey};
+
+        primeorder::impl_randomized_signer_tests!(SigningKey, Signature);
+
+        #[test]
+        fn randomized_signatures_are_low_s() {
+            // Signatures are normalized to low-S whichever nonce was used
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            for seed in 0..64 {
+                let sig = sign_seeded(&signing_key, seed, b"randomized low-S signatures");
+                assert_eq!(sig.normalize_s(), sig);
+            }
+        }
+    }
+
+    mod der {
+        use crate::ecdsa::{DerSignature, Signature};
+        use hex_literal::hex;
+
+        // RFC6979 test vector for d = 1 and "Satoshi Nakamoto", whose `r` has its
+        // high bit set and so needs a leading zero byte
+        const SATOSHI_DER: [u8; 71] = hex!(
+            "3045022100934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8
+             02202442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        );
+
+        primeorder::impl_der_signature_tests!(Signature, DerSignature, SATOSHI_DER);
+
+        #[test]
+        fn satoshi_der_matches_fixed_size() {
+            let signature = Signature::from_slice(&hex!(
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8
+                 2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+            ))
+            .unwrap();
+            assert_eq!(signature.to_der().as_bytes(), SATOSHI_DER);
+            assert_eq!(Signature::from_der(&SATOSHI_DER).unwrap(), signature);
+        }
+    }
+
+    mod ethereum {
+        use crate::ecdsa::{
+            signature::{hazmat::PrehashVerifier, Verifier},
+            RecoveryId, SigningKey, VerifyingKey,
+        };
+        use elliptic_curve::sec1::ToEncodedPoint;
+        use hex_literal::hex;
+        use sha3::{Digest, Keccak256};
+
+        /// `personal_sign` example from the web3.js documentation for
+        /// `web3.eth.accounts.sign("Some data", privateKey)`.
+        #[test]
+        fn personal_sign() {
+            let signing_key = SigningKey::from_slice(&hex!(
+                "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            ))
+            .unwrap();
+            let msg = b"Some data";
+
+            let prehash = Keccak256::new()
+                .chain_update(b"\x19Ethereum Signed Message:\n9")
+                .chain_update(msg)
+                .finalize();
+            assert_eq!(
+                prehash.as_slice(),
+                &hex!("1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655")
+            );
+
+            let (signature, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd
+                     6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029"
+                )
+            );
+            // Ethereum encodes the recovery ID as `v = 27 + recid`
+            assert_eq!(27 + recid.to_byte(), 0x1c);
+
+            let verifying_key = signing_key.verifying_key();
+            assert!(verifying_key.verify_prehash(&prehash, &signature).is_ok());
+
+            let recovered = VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::from_byte(0x1c - 27).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(&recovered, verifying_key);
+
+            // The address is the last 20 bytes of the Keccak-256 hash of the
+            // untagged uncompressed public key
+            let encoded = recovered.to_encoded_point(false);
+            let address = Keccak256::digest(&encoded.as_bytes()[1..]);
+            assert_eq!(
+                &address[12..],
+                &hex!("2c7536E3605D9C16a7a3D7b1898e529396a65c23")
+            );
+
+            // The same signature doesn't verify under the default SHA-256 prehash
+            assert!(verifying_key.verify(msg, &signature).is_err());
+        }
+    }
+
+    mod compact_libsecp {
+        use crate::ecdsa::{
+            recoverable_from_compact_libsecp, recoverable_to_compact_libsecp, RecoveryId,
+            SigningKey, VerifyingKey,
+        };
+        use hex_literal::hex;
+
+        const PREHASH: [u8; 32] =
+            hex!("1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655");
+
+        /// The `personal_sign` example from the web3.js documentation (see the
+        /// `ethereum` tests above): an RFC6979 deterministic signature over
+        /// `PREHASH` with the secret key below, serialized in libsecp256k1's
+        /// recoverable compact layout `recid || r || s`.
+        const COMPACT: [u8; 65] = hex!(
+            "01
+             b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd
+             6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029"
+        );
+
+        #[test]
+        fn round_trip() {
+            let signing_key = SigningKey::from_slice(&hex!(
+                "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            ))
+            .unwrap();
+
+            let (signature, recid) = recoverable_from_compact_libsecp(&COMPACT).unwrap();
+            assert_eq!(recid, RecoveryId::from_byte(1).unwrap());
+            assert_eq!(recoverable_to_compact_libsecp(&signature, recid), COMPACT);
+
+            // The same signature is produced by this crate
+            assert_eq!(
+                signing_key.sign_prehash_recoverable(&PREHASH).unwrap(),
+                (signature, recid)
+            );
+
+            let recovered =
+                VerifyingKey::recover_from_prehash(&PREHASH, &signature, recid).unwrap();
+            assert_eq!(&recovered, signing_key.verifying_key());
+        }
+
+        #[test]
+        fn reject_invalid() {
+            // Recovery IDs are in the range 0..=3
+            let mut bytes = COMPACT;
+            bytes[0] = 4;
+            assert!(recoverable_from_compact_libsecp(&bytes).is_err());
+
+            // Ethereum-style `v = 27 + recid` isn't accepted
+            bytes[0] = 28;
+            assert!(recoverable_from_compact_libsecp(&bytes).is_err());
+
+            // `r` must be non-zero
+            let mut bytes = COMPACT;
+            bytes[1..33].fill(0);
+            assert!(recoverable_from_compact_libsecp(&bytes).is_err());
+        }
+    }
+
+    mod key_import {
+        use crate::{
+            ecdsa::{signing_key_from_slice, verifying_key_from_sec1_bytes, SigningKey},
+            Error,
+        };
+        use elliptic_curve::{bigint::ArrayEncoding, sec1::ToEncodedPoint};
+        use hex_literal::hex;
+
+        const COMPRESSED_BASEPOINT: [u8; 33] =
+            hex!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
+
+        #[test]
+        fn valid_keys() {
+            let signing_key = signing_key_from_slice(&[1; 32]).unwrap();
+            let verifying_key = signing_key.verifying_key();
+            for compress in [true, false] {
+                let encoded = verifying_key.to_encoded_point(compress);
+                assert_eq!(
+                    &verifying_key_from_sec1_bytes(encoded.as_bytes()).unwrap(),
+                    verifying_key
+                );
+            }
+            assert_eq!(signing_key, SigningKey::from_slice(&[1; 32]).unwrap());
+        }
+
+        #[test]
+        fn verifying_key_errors() {
+            assert_eq!(
+                verifying_key_from_sec1_bytes(&[]),
+                Err(Error::InvalidLength)
+            );
+            assert_eq!(
+                verifying_key_from_sec1_bytes(&COMPRESSED_BASEPOINT[..32]),
+                Err(Error::InvalidLength)
+            );
+            assert_eq!(
+                verifying_key_from_sec1_bytes(&[0x00]),
+                Err(Error::IdentityPoint)
+            );
+
+            let mut bad_tag = COMPRESSED_BASEPOINT;
+            bad_tag[0] = 0x06;
+            assert_eq!(
+                verifying_key_from_sec1_bytes(&bad_tag),
+                Err(Error::InvalidEncoding)
+            );
+
+            // x = 5 has no corresponding y on secp256k1
+            let mut not_on_curve = [0u8; 33];
+            not_on_curve[0] = 0x02;
+            not_on_curve[32] = 0x05;
+            assert_eq!(
+                verifying_key_from_sec1_bytes(&not_on_curve),
+                Err(Error::PointNotOnCurve)
+            );
+        }
+
+        #[test]
+        fn signing_key_errors() {
+            assert_eq!(signing_key_from_slice(&[1; 31]), Err(Error::InvalidLength));
+            assert_eq!(signing_key_from_slice(&[1; 33]), Err(Error::InvalidLength));
+            assert_eq!(signing_key_from_slice(&[0; 32]), Err(Error::ZeroScalar));
+            assert_eq!(
+                signing_key_from_slice(&[0xff; 32]),
+                Err(Error::NonCanonicalScalar)
+            );
+            assert_eq!(
+                signing_key_from_slice(&crate::Secp256k1::ORDER.to_be_byte_array()),
+                Err(Error::NonCanonicalScalar)
+            );
+        }
+
+        #[cfg(all(feature = "alloc", feature = "pkcs8"))]
+        #[test]
+        fn der_public_key_errors() {
+            use crate::{
+                ecdsa::{verifying_key_from_public_key_der, VerifyingKey},
+                pkcs8::{DecodePublicKey, EncodePublicKey},
+            };
+
+            let signing_key = signing_key_from_slice(&[1; 32]).unwrap();
+            let der = signing_key.verifying_key().to_public_key_der().unwrap();
+            let der = der.as_bytes();
+            assert_eq!(
+                &VerifyingKey::from_public_key_der(der).unwrap(),
+                signing_key.verifying_key()
+            );
+            assert_eq!(
+                &verifying_key_from_public_key_der(der).unwrap(),
+                signing_key.verifying_key()
+            );
+
+            let truncated = &der[..der.len() - 1];
+            assert!(VerifyingKey::from_public_key_der(truncated).is_err());
+            assert_eq!(
+                verifying_key_from_public_key_der(truncated),
+                Err(Error::InvalidDer)
+            );
+            assert_eq!(
+                verifying_key_from_public_key_der(&[]),
+                Err(Error::InvalidDer)
+            );
+
+            // The SEC1 public key is the content of the trailing BIT STRING
+            let point_len = [false, true]
+                .into_iter()
+                .map(|compress| signing_key.verifying_key().to_encoded_point(compress))
+                .find(|point| der.ends_with(point.as_bytes()))
+                .unwrap()
+                .len();
+            let mut bad_tag = der.to_vec();
+            bad_tag[der.len() - point_len] = 0x06;
+            assert!(VerifyingKey::from_public_key_der(&bad_tag).is_err());
+            assert_eq!(
+                verifying_key_from_public_key_der(&bad_tag),
+                Err(Error::InvalidEncoding)
+            );
+
+            // Swap the curve OID (1.3.132.0.10) for P-384's (1.3.132.0.34)
+            let mut wrong_curve = der.to_vec();
+            let oid = [0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a];
+            let oid_index = der.windows(oid.len()).position(|w| w == oid).unwrap();
+            wrong_curve[oid_index + oid.len() - 1] = 0x22;
+            assert!(VerifyingKey::from_public_key_der(&wrong_curve).is_err());
+            assert_eq!(
+                verifying_key_from_public_key_der(&wrong_curve),
+                Err(Error::UnexpectedAlgorithm)
+            );
+        }
+
+        #[cfg(all(feature = "alloc", feature = "pkcs8"))]
+        #[test]
+        fn pkcs8_private_key_errors() {
+            use crate::{
+                ecdsa::signing_key_from_pkcs8_der,
+                pkcs8::{DecodePrivateKey, EncodePrivateKey},
+            };
+
+            let signing_key = signing_key_from_slice(&[1; 32]).unwrap();
+            let der = signing_key.to_pkcs8_der().unwrap();
+            let der = der.as_bytes();
+            assert_eq!(SigningKey::from_pkcs8_der(der).unwrap(), signing_key);
+            assert_eq!(signing_key_from_pkcs8_der(der).unwrap(), signing_key);
+
+            let truncated = &der[..der.len() - 1];
+            assert!(SigningKey::from_pkcs8_der(truncated).is_err());
+            assert!(SigningKey::from_pkcs8_der(&[]).is_err());
+            assert_eq!(
+                signing_key_from_pkcs8_der(truncated),
+                Err(Error::InvalidDer)
+            );
+            assert_eq!(signing_key_from_pkcs8_der(&[]), Err(Error::InvalidDer));
+
+            // Swap the curve OID (1.3.132.0.10) for P-384's (1.3.132.0.34)
+            let oid = [0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a];
+            let oid_index = der.windows(oid.len()).position(|w| w == oid).unwrap();
+            let mut wrong_curve = der.to_vec();
+            wrong_curve[oid_index + oid.len() - 1] = 0x22;
+            assert_eq!(
+                signing_key_from_pkcs8_der(&wrong_curve),
+                Err(Error::UnexpectedAlgorithm)
+            );
+
+            // The secret scalar is the OCTET STRING inside the inner ECPrivateKey
+            let scalar_tlv = [[0x04, 0x20].as_slice(), &[1; 32]].concat();
+            let scalar_index = der
+                .windows(scalar_tlv.len())
+                .position(|w| w == scalar_tlv)
+                .unwrap()
+                + 2;
+            let with_scalar = |scalar: [u8; 32]| {
+                let mut der = der.to_vec();
+                der[scalar_index..scalar_index + 32].copy_from_slice(&scalar);
+                der
+            };
+            assert_eq!(
+                signing_key_from_pkcs8_der(&with_scalar([0; 32])),
+                Err(Error::ZeroScalar)
+            );
+            assert_eq!(
+                signing_key_from_pkcs8_der(&with_scalar([0xff; 32])),
+                Err(Error::NonCanonicalScalar)
+            );
+
+            // The embedded public key still belongs to the original scalar
+            assert_eq!(
+                signing_key_from_pkcs8_der(&with_scalar([2; 32])),
+                Err(Error::PublicKeyMismatch)
+            );
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "serde"))]
+    mod serde {
+        use crate::ecdsa::{signature::Signer, Signature, SigningKey};
+        use hex_literal::hex;
+
+        fn example_signature() -> Signature {
+            SigningKey::from_slice(&[0x42; 32]).unwrap().sign(b"serde")
+        }
+
+        #[test]
+        fn json_round_trip() {
+            let signature = example_signature();
+            let json = serde_json::to_string(&signature).unwrap();
+            assert_eq!(
+                json.to_lowercase(),
+                format!("\"{}\"", hex::encode(signature.to_bytes()))
+            );
+            assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+        }
+
+        #[test]
+        fn bincode_round_trip() {
+            let signature = example_signature();
+            let bytes = bincode::serialize(&signature).unwrap();
+            assert_eq!(
+                bincode::deserialize::<Signature>(&bytes).unwrap(),
+                signature
+            );
+            assert!(bincode::deserialize::<Signature>(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        #[test]
+        fn reject_malformed() {
+            // Wrong length
+            let short = format!("\"{}\"", hex::encode([1u8; 63]));
+            assert!(serde_json::from_str::<Signature>(&short).is_err());
+
+            // `r` is zero
+            let mut bytes = [1u8; 64];
+            bytes[..32].fill(0);
+            let zero_r = format!("\"{}\"", hex::encode(bytes));
+            assert!(serde_json::from_str::<Signature>(&zero_r).is_err());
+
+            // `s` is equal to the curve order
+            let mut bytes = [1u8; 64];
+            bytes[32..].copy_from_slice(&hex!(
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"
+            ));
+            let s_overflow = format!("\"{}\"", hex::encode(bytes));
+            assert!(serde_json::from_str::<Signature>(&s_overflow).is_err());
+        }
+    }
+
+    mod recover_and_verify {
+        use crate::ecdsa::{recover_and_verify, RecoveryId, SigningKey};
+
+        #[test]
+        fn recovers_signing_key() {
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let msg = b"ecrecover";
+            let (signature, recid) = signing_key.sign_recoverable(msg).unwrap();
+
+            assert_eq!(
+                &recover_and_verify(&signature, recid, msg).unwrap(),
+                signing_key.verifying_key()
+            );
+
+            // Flipping the y-parity bit recovers some other key
+            let tampered = RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced());
+            let other = recover_and_verify(&signature, tampered, msg).unwrap();
+            assert_ne!(&other, signing_key.verifying_key());
+
+            // `r + n` is not a valid x-coordinate for this signature
+            let tampered = RecoveryId::new(recid.is_y_odd(), !recid.is_x_reduced());
+            assert!(recover_and_verify(&signature, tampered, msg).is_err());
+
+            // A different message recovers a different key
+            let other = recover_and_verify(&signature, recid, b"tampered").unwrap();
+            assert_ne!(&other, signing_key.verifying_key());
+        }
+    }
+
+    /// Deterministic RFC6979 signatures with SHA-256, low-S normalized.
+    ///
+    /// These are the secp256k1 vectors widely used by Bitcoin libraries
+    /// (e.g. python-ecdsa, Trezor), the counterpart of the P-256 vectors from
+    /// RFC6979 Appendix A.2.5.
+    #[cfg(feature = "sha256")]
+    mod rfc6979 {
+        use crate::ecdsa::{
+            signature::{Signer, Verifier},
+            Signature, SigningKey,
+        };
+        use hex_literal::hex;
+
+        struct Rfc6979TestVector {
+            d: [u8; 32],
+            msg: &'static [u8],
+            sig: [u8; 64],
+        }
+
+        const RFC6979_TEST_VECTORS: &[Rfc6979TestVector] = &[
+            Rfc6979TestVector {
+                d: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                msg: b"Satoshi Nakamoto",
+                sig: hex!(
+                    "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8
+                     2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+                ),
+            },
+            Rfc6979TestVector {
+                d: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                msg: b"All those moments will be lost in time, like tears in rain. Time to die...",
+                sig: hex!(
+                    "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b
+                     547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21"
+                ),
+            },
+            Rfc6979TestVector {
+                d: hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+                msg: b"Satoshi Nakamoto",
+                sig: hex!(
+                    "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0
+                     6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5"
+                ),
+            },
+            Rfc6979TestVector {
+                d: hex!("f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181"),
+                msg: b"Alan Turing",
+                sig: hex!(
+                    "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c
+                     58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea"
+                ),
+            },
+            Rfc6979TestVector {
+                d: hex!("e91671c46231f833a6406ccbea0e3e392c76c167bac1cb013f6f1013980455c2"),
+                msg: b"There is a computer disease that anybody who works with computers knows about. It's a very serious disease and it interferes completely with the work. The trouble with computers is that you 'play' with them!",
+                sig: hex!(
+                    "b552edd27580141f3b2a5463048cb7cd3e047b97c9f98076c32dbdf85a68718b
+                     279fa72dd19bfae05577e06c7c0c1900c371fcd5893f7e1d56a37d30174671f6"
+                ),
+            },
+        ];
+
+        #[test]
+        fn deterministic_signatures() {
+            for vector in RFC6979_TEST_VECTORS {
+                let signing_key = SigningKey::from_bytes(&vector.d.into()).unwrap();
+                let signature: Signature = signing_key.sign(vector.msg);
+                assert_eq!(signature.to_bytes().as_slice(), &vector.sig);
+                assert!(signing_key
+                    .verifying_key()
+                    .verify(vector.msg, &signature)
+                    .is_ok());
+            }
+        }
+    }
+
+    mod sign {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, Secp256k1};
+        ecdsa_core::new_signing_test!(Secp256k1, ECDSA_TEST_VECTORS);
+    }
+
+    mod verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, Secp256k1};
+        ecdsa_core::new_verification_test!(Secp256k1, ECDSA_TEST_VECTORS);
+    }
+
+    /// Verification computes `u1 * G + u2 * Q` with [`LinearCombination`], which
+    /// splits both scalars with the GLV endomorphism and interleaves the four
+    /// half-width multiplications. Check it against plain double-and-add.
+    mod endomorphism {
+        use crate::{
+            ecdsa::{signature::hazmat::PrehashVerifier, Signature, SigningKey, VerifyingKey},
+            test_vectors::ecdsa::ECDSA_TEST_VECTORS,
+            EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256,
+        };
+        use elliptic_curve::{
+            ops::{Invert, LinearCombination, Reduce},
+            point::AffineCoordinates,
+        };
+        use rand_core::{OsRng, RngCore};
+
+        fn double_and_add(point: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
+            let mut acc = ProjectivePoint::IDENTITY;
+            for byte in k.to_bytes() {
+                for i in (0..8).rev() {
+                    acc = acc.double();
+                    if (byte >> i) & 1 == 1 {
+                        acc += point;
+                    }
+                }
+            }
+            acc
+        }
+
+        fn check(verifying_key: &VerifyingKey, prehash: &[u8], signature: &Signature) {
+            let q = ProjectivePoint::from(*verifying_key.as_affine());
+            let z = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::try_from(prehash).unwrap());
+            let (r, s) = signature.split_scalars();
+            let s_inv = *Invert::invert(&s);
+            let (u1, u2) = (z * s_inv, *r * s_inv);
+
+            let glv = ProjectivePoint::lincomb(&[(ProjectivePoint::GENERATOR, u1), (q, u2)]);
+            let plain = double_and_add(&ProjectivePoint::GENERATOR, &u1) + double_and_add(&q, &u2);
+            assert_eq!(glv, plain);
+
+            let x = <Scalar as Reduce<U256>>::reduce_bytes(&glv.to_affine().x());
+            assert_eq!(x, *r);
+            assert!(verifying_key.verify_prehash(prehash, signature).is_ok());
+        }
+
+        #[test]
+        fn matches_plain_multiplication() {
+            for vector in ECDSA_TEST_VECTORS {
+                let point = EncodedPoint::from_affine_coordinates(
+                    &FieldBytes::try_from(vector.q_x).unwrap(),
+                    &FieldBytes::try_from(vector.q_y).unwrap(),
+                    false,
+                );
+                let verifying_key = VerifyingKey::from_encoded_point(&point).unwrap();
+                let signature = Signature::from_scalars(
+                    <[u8; 32]>::try_from(vector.r).unwrap(),
+                    <[u8; 32]>::try_from(vector.s).unwrap(),
+                )
+                .unwrap();
+                check(&verifying_key, vector.m, &signature);
+            }
+
+            for _ in 0..16 {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 32];
+                OsRng.fill_bytes(&mut prehash);
+                let (signature, _) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+                check(signing_key.verifying_key(), &prehash, &signature);
+            }
+        }
     }
 
     mod wycheproof {
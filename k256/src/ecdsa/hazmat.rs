@@ -0,0 +1,108 @@
+//! Low-level ECDSA/secp256k1 primitives.
+//!
+//! # ⚠️ Warning: Hazmat!
+//!
+//! YOU PROBABLY DON'T WANT TO USE THESE!
+//!
+//! In addition to the generic primitives re-exported from [`ecdsa_core::hazmat`],
+//! this module provides the building blocks needed by multi-party ECDSA
+//! protocols which compute signatures from secret shares, e.g. 2-of-2 ECDSA:
+//!
+//! 1. Each party contributes a nonce share, and the joint nonce point
+//!    `R = k₁k₂·G` is computed with [`lift_nonce_point`].
+//! 2. [`r_from_point`] extracts the `r` component of the signature from `R`.
+//! 3. Each party computes [`partial_s`] with its share of the secret key, and
+//!    the partial values are summed to obtain `s`.
+//!
+//! Computing `k⁻¹` without revealing `k` (and protecting the shares against
+//! malicious parties) is the responsibility of the protocol: these functions
+//! perform no checks beyond those documented.
+
+pub use ecdsa_core::hazmat::*;
+
+#[cfg(feature = "arithmetic")]
+use {
+    crate::{AffinePoint, NonZeroScalar, ProjectivePoint, Scalar, U256},
+    elliptic_curve::{ops::Reduce, point::AffineCoordinates, subtle::CtOption},
+};
+
+/// Multiply the nonce point received from another party by this party's
+/// nonce share, i.e. compute `R = k·P`.
+///
+/// Starting from [`ProjectivePoint::GENERATOR`], applying this once per party
+/// yields the joint nonce point `R = (k₁···kₙ)·G`.
+#[cfg(feature = "arithmetic")]
+pub fn lift_nonce_point(k_share: &NonZeroScalar, point: &ProjectivePoint) -> AffinePoint {
+    (point * k_share.as_ref()).to_affine()
+}
+
+/// Compute the `r` component of an ECDSA signature from the nonce point `R`:
+/// its `x`-coordinate reduced modulo the curve order.
+///
+/// Returns none if `r` is zero, including when `R` is the identity.
+#[cfg(feature = "arithmetic")]
+pub fn r_from_point(point: &AffinePoint) -> CtOption<NonZeroScalar> {
+    NonZeroScalar::new(<Scalar as Reduce<U256>>::reduce_bytes(&point.x()))
+}
+
+/// Compute one party's additive share of the `s` component of an ECDSA
+/// signature: `k⁻¹·(z + r·dᵢ)`.
+///
+/// When the secret key is split additively as `d = d₁ + d₂`, exactly one party
+/// must pass the message scalar `z`, and the others must pass [`Scalar::ZERO`],
+/// so that the sum of the shares is `s = k⁻¹·(z + r·d)`.
+///
+/// The resulting `s` may be high: use [`Signature::normalize_s`] to obtain the
+/// low-S form required by Bitcoin.
+///
+/// [`Signature::normalize_s`]: super::Signature::normalize_s
+#[cfg(feature = "arithmetic")]
+pub fn partial_s(k_inv: &Scalar, z: &Scalar, r: &Scalar, d_share: &Scalar) -> Scalar {
+    k_inv * &(z + &(r * d_share))
+}
+
+#[cfg(all(test, feature = "verifying", feature = "sha256"))]
+mod tests {
+    use super::{lift_nonce_point, partial_s, r_from_point};
+    use crate::{
+        ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+        AffinePoint, NonZeroScalar, ProjectivePoint, Scalar, U256,
+    };
+    use elliptic_curve::{ops::Reduce, rand_core::OsRng};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn two_party_signature() {
+        let prehash = Sha256::digest(b"2-of-2 ECDSA signature computed from secret shares");
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&prehash);
+
+        // Secret key split additively: d = d₁ + d₂
+        let d1 = NonZeroScalar::random(&mut OsRng);
+        let d2 = NonZeroScalar::random(&mut OsRng);
+        let public_key = ProjectivePoint::GENERATOR * (*d1 + *d2);
+        let verifying_key = VerifyingKey::from_affine(public_key.to_affine()).unwrap();
+
+        // Nonce split multiplicatively: k = k₁k₂
+        let k1 = NonZeroScalar::random(&mut OsRng);
+        let k2 = NonZeroScalar::random(&mut OsRng);
+        let r2 = lift_nonce_point(&k2, &ProjectivePoint::GENERATOR);
+        let big_r = lift_nonce_point(&k1, &r2.into());
+        assert_eq!(
+            big_r,
+            (ProjectivePoint::GENERATOR * (*k1 * *k2)).to_affine()
+        );
+        let r = r_from_point(&big_r).unwrap();
+
+        // A real protocol computes k⁻¹ without either party learning k
+        let k_inv = (*k1 * *k2).invert().unwrap();
+        let s = partial_s(&k_inv, &z, &r, &d1) + partial_s(&k_inv, &Scalar::ZERO, &r, &d2);
+
+        let signature = Signature::from_scalars(r, s).unwrap().normalize_s();
+        assert!(verifying_key.verify_prehash(&prehash, &signature).is_ok());
+    }
+
+    #[test]
+    fn r_from_identity() {
+        assert!(bool::from(r_from_point(&AffinePoint::IDENTITY).is_none()));
+    }
+}
@@ -0,0 +1,68 @@
+//! Error types.
+
+use core::fmt;
+
+/// Errors which can occur when importing keys.
+///
+/// Unlike the opaque [`elliptic_curve::Error`], this type reports why the
+/// input was rejected. Signing and verification failures are still reported
+/// using [`signature::Error`](https://docs.rs/signature).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Input is not the correct length for its encoding.
+    InvalidLength,
+
+    /// SEC1 tag byte is invalid.
+    InvalidEncoding,
+
+    /// Input contains a character which is not a hexadecimal digit.
+    InvalidHex,
+
+    /// Coordinates do not describe a point on the secp256k1 curve.
+    PointNotOnCurve,
+
+    /// Point is the identity (i.e. point at infinity).
+    IdentityPoint,
+
+    /// Scalar is not less than the curve order.
+    NonCanonicalScalar,
+
+    /// Scalar is zero.
+    ZeroScalar,
+
+    /// ASN.1 DER structure is malformed.
+    InvalidDer,
+
+    /// Key is not an `id-ecPublicKey` on the secp256k1 curve.
+    UnexpectedAlgorithm,
+
+    /// Public key embedded in a private key does not match the secret scalar.
+    PublicKeyMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidLength => "invalid length",
+            Error::InvalidEncoding => "invalid SEC1 encoding",
+            Error::InvalidHex => "invalid hexadecimal digit",
+            Error::PointNotOnCurve => "point is not on the secp256k1 curve",
+            Error::IdentityPoint => "point is the identity",
+            Error::NonCanonicalScalar => "scalar is not less than the curve order",
+            Error::ZeroScalar => "scalar is zero",
+            Error::InvalidDer => "invalid DER encoding",
+            Error::UnexpectedAlgorithm => "key is not an elliptic curve key for secp256k1",
+            Error::PublicKeyMismatch => "public key does not match private key",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<Error> for elliptic_curve::Error {
+    fn from(_: Error) -> elliptic_curve::Error {
+        elliptic_curve::Error
+    }
+}
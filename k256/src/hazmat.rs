@@ -0,0 +1,130 @@
+//! Low-level arithmetic primitives.
+//!
+//! # ⚠️ Warning: Hazmat!
+//!
+//! These functions and types are easy to misuse: they skip checks or expose internal
+//! representations which the rest of this crate relies on for correctness, and using them
+//! incorrectly may produce silently wrong results.
+//!
+//! Prefer the types exported from the crate root unless you have measured a need for these.
+
+use crate::ProjectivePoint;
+
+/// Lazily reduced field element.
+///
+/// Each value carries an implicit "magnitude": an upper bound on how far it may be from its
+/// fully reduced form. Additions increase the magnitude, [`FieldElement::negate`] takes the
+/// caller's bound on it, and [`FieldElement::normalize_weak`] / [`FieldElement::normalize`]
+/// bring it back down. Exceeding the limits of the underlying implementation (checked only in
+/// debug builds) yields incorrect results.
+///
+/// The fully normalized [`crate::FieldElement`] handles all of this automatically.
+#[cfg(feature = "expose-field")]
+pub use crate::arithmetic::FieldElement;
+
+/// Computes `-a` without weakly normalizing the resulting y-coordinate.
+///
+/// This saves a normalization when the result is immediately consumed by another operation,
+/// e.g. when subtracting in a long chain of additions.
+///
+/// # Preconditions
+///
+/// The result must only be used as an input to addition, doubling, equality comparison or
+/// conversion to affine coordinates. In particular it must not be negated again, with either
+/// [`neg_unnormalized`] or `-`: negation assumes a weakly normalized y-coordinate, and violating
+/// this yields an incorrect point.
+pub fn neg_unnormalized(a: &ProjectivePoint) -> ProjectivePoint {
+    a.neg_unnormalized()
+}
+
+/// Builds a point from raw projective coordinates `(X, Y, Z)`, representing the affine point
+/// `(X / Z, Y / Z)`, or the identity when `Z = 0`.
+///
+/// This is intended for importing precomputed tables and for implementing custom formulas on
+/// top of [`coordinates`].
+///
+/// # Preconditions
+///
+/// The coordinates are **not validated**: the caller must ensure they satisfy the projective
+/// curve equation `Y²·Z = X³ + 7·Z³`, and that `X = 0` and `Y != 0` when `Z = 0`. Any other
+/// input produces a value which the rest of this crate will silently compute incorrect results
+/// with. Use [`crate::AffinePoint`]'s checked constructors when the input is untrusted.
+#[cfg(feature = "expose-field")]
+pub fn from_coordinates(
+    x: crate::FieldElement,
+    y: crate::FieldElement,
+    z: crate::FieldElement,
+) -> ProjectivePoint {
+    ProjectivePoint::from_coordinates_unchecked(x.into(), y.into(), z.into())
+}
+
+/// Returns the raw projective coordinates `(X, Y, Z)` of `point`.
+///
+/// A point has many projective representations, and which one is returned depends on how the
+/// point was computed: compare points with `==` or [`ProjectivePoint::to_affine`] rather than
+/// by their coordinates.
+#[cfg(feature = "expose-field")]
+pub fn coordinates(
+    point: &ProjectivePoint,
+) -> (
+    crate::FieldElement,
+    crate::FieldElement,
+    crate::FieldElement,
+) {
+    let (x, y, z) = point.coordinates();
+    (x.into(), y.into(), z.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::neg_unnormalized;
+    use crate::ProjectivePoint;
+    use elliptic_curve::{rand_core::OsRng, Group};
+
+    #[test]
+    fn neg_unnormalized_matches_neg() {
+        for _ in 0..100 {
+            let a = ProjectivePoint::random(&mut OsRng);
+            let b = ProjectivePoint::random(&mut OsRng);
+            let neg_a = neg_unnormalized(&a);
+            assert_eq!(neg_a, -a);
+            assert_eq!(b + neg_a, b - a);
+            assert_eq!(neg_a.double(), -a.double());
+            assert_eq!((b + neg_a).to_affine(), (b - a).to_affine());
+            assert_eq!(a + neg_a, ProjectivePoint::IDENTITY);
+        }
+    }
+
+    #[cfg(feature = "expose-field")]
+    #[test]
+    fn from_coordinates_generator() {
+        use super::{coordinates, from_coordinates};
+        use crate::FieldElement;
+        use hex_literal::hex;
+
+        let x = FieldElement::from_bytes(
+            &hex!("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798").into(),
+        )
+        .unwrap();
+        let y = FieldElement::from_bytes(
+            &hex!("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8").into(),
+        )
+        .unwrap();
+
+        let g = from_coordinates(x, y, FieldElement::ONE);
+        assert_eq!(g, ProjectivePoint::GENERATOR);
+
+        let (gx, gy, gz) = coordinates(&ProjectivePoint::GENERATOR);
+        assert_eq!((gx, gy, gz), (x, y, FieldElement::ONE));
+
+        // Rescaling all coordinates represents the same point
+        let lambda = FieldElement::from_u64(0x1234_5678);
+        let scaled = from_coordinates(x * lambda, y * lambda, lambda);
+        assert_eq!(scaled, g);
+        assert_eq!(scaled.double(), g.double());
+
+        // The identity round trips
+        let (ix, iy, iz) = coordinates(&ProjectivePoint::IDENTITY);
+        assert_eq!(from_coordinates(ix, iy, iz), ProjectivePoint::IDENTITY);
+    }
+}
@@ -23,24 +23,40 @@
 //!
 //! - [`AffinePoint`]
 //! - [`Scalar`]
+//! - [`ecdsa::Signature`] (as the 64-byte `r || s`, hex encoded in text-based formats)
 //! - [`ecdsa::VerifyingKey`]
 //!
 //! Please see type-specific documentation for more information.
+//!
+//! ## `arbitrary` support
+//!
+//! When the `arbitrary` feature of this crate is enabled, `arbitrary::Arbitrary`
+//! is impl'd for [`Scalar`] and [`AffinePoint`], for use in fuzz targets. Every
+//! input produces a valid value: scalars are reduced modulo the group order, and
+//! points are computed as a multiple of the generator.
 
 #[cfg(feature = "alloc")]
 #[allow(unused_imports)]
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+mod error;
+
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
 
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
+#[cfg(feature = "arithmetic")]
+pub mod hazmat;
+
 #[cfg(feature = "schnorr")]
 pub mod schnorr;
 
@@ -49,11 +65,13 @@ pub mod test_vectors;
 
 pub use elliptic_curve::{self, bigint::U256};
 
+pub use crate::error::Error;
+
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
 
 #[cfg(feature = "expose-field")]
-pub use arithmetic::FieldElement;
+pub use arithmetic::NormalizedFieldElement as FieldElement;
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
@@ -74,6 +92,9 @@ const ORDER_HEX: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8
 /// Order of the secp256k1 elliptic curve.
 const ORDER: U256 = U256::from_be_hex(ORDER_HEX);
 
+/// Modulus of the secp256k1 base field in hexadecimal.
+const FIELD_MODULUS_HEX: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+
 /// secp256k1 (K-256) elliptic curve.
 ///
 /// Specified in Certicom's SECG in "SEC 2: Recommended Elliptic Curve Domain Parameters":
@@ -88,6 +109,17 @@ const ORDER: U256 = U256::from_be_hex(ORDER_HEX);
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Secp256k1;
 
+impl Secp256k1 {
+    /// Modulus of the base field: `p = 2^256 - 2^32 - 977`.
+    pub const FIELD_MODULUS: U256 = U256::from_be_hex(FIELD_MODULUS_HEX);
+
+    /// Order of the elliptic curve group (i.e. scalar modulus).
+    ///
+    /// This is the same value as [`elliptic_curve::Curve::ORDER`], provided so
+    /// it can be used without importing the trait.
+    pub const ORDER: U256 = ORDER;
+}
+
 impl elliptic_curve::Curve for Secp256k1 {
     /// 32-byte serialized field elements.
     type FieldBytesSize = U32;
@@ -122,6 +154,13 @@ pub type CompressedPoint = Array<u8, U33>;
 /// SEC1-encoded secp256k1 (K-256) curve point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<Secp256k1>;
 
+/// Coordinates of a SEC1-encoded secp256k1 (K-256) curve point, as returned by
+/// [`EncodedPoint::coordinates`].
+///
+/// These are obtained by parsing the encoding only: the coordinates are not
+/// checked to be a point on the curve.
+pub type Coordinates<'a> = elliptic_curve::sec1::Coordinates<'a, U32>;
+
 /// secp256k1 (K-256) field element serialized as bytes.
 ///
 /// Byte array containing a serialized field element value (base field or scalar).
@@ -157,3 +196,35 @@ impl elliptic_curve::sec1::ValidatePublicKey for Secp256k1 {}
 /// Bit representation of a secp256k1 (K-256) scalar field element.
 #[cfg(feature = "bits")]
 pub type ScalarBits = elliptic_curve::scalar::ScalarBits<Secp256k1>;
+
+#[cfg(feature = "voprf")]
+impl elliptic_curve::VoprfParameters for Secp256k1 {
+    /// RFC 9497 does not register a secp256k1 ciphersuite: this identifier
+    /// follows the naming of the registered `P256-SHA256` suite.
+    const ID: &'static str = "secp256k1-SHA256";
+
+    /// Hash function used by the suite, matching the one used for
+    /// hash-to-curve with `secp256k1_XMD:SHA-256_SSWU_RO_`.
+    type Hash = sha2::Sha256;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Secp256k1, U256};
+
+    #[test]
+    fn curve_constants() {
+        assert_eq!(
+            Secp256k1::ORDER,
+            U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")
+        );
+        assert_eq!(
+            Secp256k1::ORDER,
+            <Secp256k1 as elliptic_curve::Curve>::ORDER
+        );
+        assert_eq!(
+            Secp256k1::FIELD_MODULUS,
+            U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F")
+        );
+    }
+}
@@ -65,12 +65,23 @@
 mod signing;
 mod verifying;
 
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod musig;
+
 pub use self::{signing::SigningKey, verifying::VerifyingKey};
+
+#[cfg(feature = "alloc")]
+pub use self::{
+    batch::batch_verify,
+    musig::{key_sort, KeyAggContext},
+};
 pub use signature::{self, rand_core::CryptoRngCore, Error};
 
 use crate::{arithmetic::FieldElement, FieldBytes, NonZeroScalar};
 use core::fmt;
-use elliptic_curve::subtle::ConstantTimeEq;
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use sha2::{Digest, Sha256};
 use signature::Result;
 
@@ -146,6 +157,15 @@ impl Signature {
     }
 }
 
+impl ConditionallySelectable for Signature {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            r: FieldElement::conditional_select(&a.r, &b.r, choice),
+            s: NonZeroScalar::conditional_select(&a.s, &b.s, choice),
+        }
+    }
+}
+
 impl Eq for Signature {}
 
 impl From<Signature> for SignatureBytes {
@@ -540,6 +560,108 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip340_batch_verify() {
+        use super::batch_verify;
+        use alloc::vec::Vec;
+
+        let mut items = BIP340_SIGN_VECTORS
+            .iter()
+            .map(|vector| {
+                (
+                    VerifyingKey::from_bytes(&vector.public_key).unwrap(),
+                    &vector.message[..],
+                    Signature::try_from(&vector.signature[..]).unwrap(),
+                )
+            })
+            .chain(
+                BIP340_VERIFY_VECTORS
+                    .iter()
+                    .filter(|vector| vector.valid)
+                    .map(|vector| {
+                        (
+                            VerifyingKey::from_bytes(&vector.public_key).unwrap(),
+                            &vector.message[..],
+                            Signature::try_from(&vector.signature[..]).unwrap(),
+                        )
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+        assert!(items.len() > 4);
+        assert_eq!(batch_verify(&items), Ok(()));
+        assert_eq!(batch_verify(&[]), Ok(()));
+
+        // Corrupt one entry by pairing its signature with another message
+        items[2].1 = items[1].1;
+        assert_eq!(batch_verify(&items), Err(2));
+
+        assert_eq!(batch_verify(&items[..2]), Ok(()));
+    }
+
+    /// Key aggregation test vectors from BIP327 (`key_agg_vectors.json`).
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip327_key_agg() {
+        use super::{key_sort, KeyAggContext};
+        use crate::PublicKey;
+        use alloc::vec::Vec;
+
+        let keys = [
+            hex!("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+            hex!("023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66"),
+        ]
+        .map(|key| PublicKey::from_sec1_bytes(&key).unwrap());
+
+        let vectors: [(&[usize], [u8; 32]); 4] = [
+            (
+                &[0, 1, 2],
+                hex!("90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C"),
+            ),
+            (
+                &[2, 1, 0],
+                hex!("6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B"),
+            ),
+            (
+                &[0, 0, 0],
+                hex!("B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935"),
+            ),
+            (
+                &[0, 0, 1, 1],
+                hex!("69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E"),
+            ),
+        ];
+
+        for (indices, expected) in vectors {
+            let list = indices.iter().map(|&i| keys[i]).collect::<Vec<_>>();
+            let ctx = KeyAggContext::new(&list).unwrap();
+            assert_eq!(ctx.aggregated_key().to_bytes().as_slice(), &expected);
+        }
+
+        // Sorting makes the aggregate independent of the order of the keys
+        let mut forward = keys;
+        let mut backward = [keys[2], keys[1], keys[0]];
+        key_sort(&mut forward);
+        key_sort(&mut backward);
+        assert_eq!(forward, backward);
+        assert_eq!(
+            KeyAggContext::new(&forward).unwrap(),
+            KeyAggContext::new(&backward).unwrap()
+        );
+
+        // A single key is weighted by its coefficient, so it aggregates to a different key
+        let single = KeyAggContext::new(&keys[..1]).unwrap();
+        assert_ne!(single.aggregated_public_key(), keys[0]);
+        assert_eq!(
+            single.aggregated_key().to_bytes().as_slice(),
+            &hex!("74108CA6D5ED40B37C4A441E96438D144BD7E95CD515B996CA4F70F78342F0AD")
+        );
+
+        assert!(KeyAggContext::new(&[]).is_err());
+    }
+
     #[test]
     fn try_from() {
         // Pass an invalid signature (shorter than Self::BYTES / 2) and make sure
@@ -547,4 +669,24 @@ mod tests {
         let invalid_signature = [111; 24];
         assert_eq!(Signature::try_from(&invalid_signature[..]).is_err(), true);
     }
+
+    #[test]
+    fn conditional_select() {
+        use elliptic_curve::subtle::{Choice, ConditionallySelectable};
+        use signature::Signer;
+
+        let signing_key = SigningKey::from_bytes(&[0x42; 32]).unwrap();
+        let sig_a: Signature = signing_key.sign(b"message a");
+        let sig_b: Signature = signing_key.sign(b"message b");
+        assert_ne!(sig_a, sig_b);
+
+        assert_eq!(
+            Signature::conditional_select(&sig_a, &sig_b, Choice::from(0)),
+            sig_a
+        );
+        assert_eq!(
+            Signature::conditional_select(&sig_a, &sig_b, Choice::from(1)),
+            sig_b
+        );
+    }
 }
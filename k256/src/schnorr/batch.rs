@@ -0,0 +1,80 @@
+//! Taproot Schnorr batch verification.
+
+use super::{tagged_hash, Signature, VerifyingKey};
+use crate::{AffinePoint, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{bigint::U256, group::Group, ops::Reduce, point::DecompactPoint};
+use sha2::Digest;
+
+const BATCH_TAG: &[u8] = b"BIP0340/batch";
+
+/// Verify a batch of BIP340 signatures at once.
+///
+/// Each item is a [`VerifyingKey`], the message it signed, and the claimed
+/// [`Signature`]. Messages are used as-is, as with [`VerifyingKey::verify_raw`]
+/// and the `PrehashVerifier` impl.
+///
+/// This implements the batch verification algorithm from [BIP340]: the
+/// individual verification equations are combined using random coefficients
+/// into a single multi-scalar multiplication, which is much faster than
+/// verifying each signature separately. The coefficients are derived from a
+/// hash of the whole batch, so the result is deterministic.
+///
+/// If the batch does not verify, the signatures are checked individually and
+/// the index of the first invalid one is returned as the error.
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki#batch-verification
+pub fn batch_verify(items: &[(VerifyingKey, &[u8], Signature)]) -> Result<(), usize> {
+    if batch_equation_holds(items) {
+        return Ok(());
+    }
+
+    match items
+        .iter()
+        .position(|(vk, msg, sig)| vk.verify_raw(msg, sig).is_err())
+    {
+        Some(index) => Err(index),
+        None => Ok(()),
+    }
+}
+
+/// Check `(s₁ + a₂s₂ + ... + aᵤsᵤ)G = R₁ + a₂R₂ + ... + aᵤRᵤ + e₁P₁ + (a₂e₂)P₂ + ... + (aᵤeᵤ)Pᵤ`.
+fn batch_equation_holds(items: &[(VerifyingKey, &[u8], Signature)]) -> bool {
+    let mut seed = tagged_hash(BATCH_TAG);
+    for (vk, msg, sig) in items {
+        seed.update(vk.to_bytes());
+        seed.update((msg.len() as u64).to_be_bytes());
+        seed.update(msg);
+        seed.update(sig.to_bytes());
+    }
+    let seed = seed.finalize();
+
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_sum = Scalar::ZERO;
+
+    for (i, (vk, msg, sig)) in items.iter().enumerate() {
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            <Scalar as Reduce<U256>>::reduce_bytes(
+                &sha2::Sha256::new()
+                    .chain_update(seed)
+                    .chain_update((i as u64).to_be_bytes())
+                    .finalize(),
+            )
+        };
+
+        let R = match Option::<AffinePoint>::from(AffinePoint::decompact(&sig.r.to_bytes())) {
+            Some(R) => R,
+            None => return false,
+        };
+        let e = vk.challenge(msg, sig);
+
+        s_sum += a * sig.s.as_ref();
+        terms.push((ProjectivePoint::from(R), -a));
+        terms.push((vk.inner.to_projective(), -(a * e)));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, s_sum));
+    ProjectivePoint::msm_vartime(&terms).is_identity().into()
+}
@@ -0,0 +1,111 @@
+//! MuSig2 key aggregation as defined in [BIP327].
+//!
+//! [BIP327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+
+use super::{tagged_hash, VerifyingKey};
+use crate::{ProjectivePoint, PublicKey, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{bigint::U256, group::GroupEncoding, ops::Reduce};
+use sha2::Digest;
+use signature::{Error, Result};
+
+const KEYAGG_LIST_TAG: &[u8] = b"KeyAgg list";
+const KEYAGG_COEFFICIENT_TAG: &[u8] = b"KeyAgg coefficient";
+
+/// Serialized compressed public key, i.e. the BIP327 "plain" encoding.
+type PlainKey = [u8; 33];
+
+/// MuSig2 key aggregation context.
+///
+/// This holds the aggregate of a list of individual public keys, which is a
+/// BIP340 key that signatures produced jointly by all of the participants
+/// verify under.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KeyAggContext {
+    /// Aggregate public key `Q`, including the parity of its y-coordinate.
+    aggregated: PublicKey,
+}
+
+impl KeyAggContext {
+    /// Aggregate the given individual public keys (BIP327 `KeyAgg`).
+    ///
+    /// The result depends on the order of `keys`. Sort them first with
+    /// [`key_sort`] to obtain an aggregate which is independent of the order
+    /// in which the participants are listed.
+    ///
+    /// Each key is weighted by a coefficient derived from a tagged hash of the
+    /// whole list, except for the first key which differs from `keys[0]`, whose
+    /// coefficient is 1. Note that this means the aggregate of a single key is
+    /// *not* that key.
+    ///
+    /// Returns an [`Error`] if `keys` is empty or the aggregate is the identity.
+    pub fn new(keys: &[PublicKey]) -> Result<Self> {
+        let plain_keys = keys.iter().map(plain_key).collect::<Vec<_>>();
+        let first = plain_keys.first().ok_or_else(Error::new)?;
+        let second = plain_keys.iter().find(|key| *key != first);
+
+        let mut list_hash = tagged_hash(KEYAGG_LIST_TAG);
+        for key in &plain_keys {
+            list_hash.update(key);
+        }
+        let list_hash = list_hash.finalize();
+
+        let terms = keys
+            .iter()
+            .zip(&plain_keys)
+            .map(|(key, plain)| {
+                let coefficient = if Some(plain) == second {
+                    Scalar::ONE
+                } else {
+                    <Scalar as Reduce<U256>>::reduce_bytes(
+                        &tagged_hash(KEYAGG_COEFFICIENT_TAG)
+                            .chain_update(list_hash)
+                            .chain_update(plain)
+                            .finalize(),
+                    )
+                };
+
+                (key.to_projective(), coefficient)
+            })
+            .collect::<Vec<_>>();
+
+        let aggregated = ProjectivePoint::msm_vartime(&terms).to_affine();
+        let aggregated = PublicKey::from_affine(aggregated).map_err(|_| Error::new())?;
+
+        Ok(Self { aggregated })
+    }
+
+    /// Get the x-only aggregate key, which BIP340 signatures by all of the
+    /// participants verify under.
+    pub fn aggregated_key(&self) -> VerifyingKey {
+        let point = self.aggregated.as_affine();
+        let even = if point.y.normalize().is_odd().into() {
+            -*point
+        } else {
+            *point
+        };
+
+        VerifyingKey {
+            inner: PublicKey::from_affine(even).expect("aggregate is not the identity"),
+        }
+    }
+
+    /// Get the full aggregate public key `Q`, including the parity of its
+    /// y-coordinate which is discarded by [`KeyAggContext::aggregated_key`].
+    pub fn aggregated_public_key(&self) -> PublicKey {
+        self.aggregated
+    }
+}
+
+/// Sort public keys into the canonical order for aggregation (BIP327
+/// `KeySort`): lexicographically by their compressed encoding.
+pub fn key_sort(keys: &mut [PublicKey]) {
+    keys.sort_unstable_by_key(plain_key);
+}
+
+/// Serialize `key` in compressed form.
+fn plain_key(key: &PublicKey) -> PlainKey {
+    let mut plain = [0u8; 33];
+    plain.copy_from_slice(&key.as_affine().to_bytes());
+    plain
+}
@@ -6,7 +6,7 @@ use crate::{
 };
 use elliptic_curve::{
     bigint::U256,
-    ops::Reduce,
+    ops::{MulByGenerator, Reduce},
     rand_core::CryptoRngCore,
     subtle::ConditionallySelectable,
     zeroize::{Zeroize, ZeroizeOnDrop},
@@ -79,6 +79,17 @@ impl SigningKey {
     ///
     /// The preferred interfaces are the [`Signer`] or [`RandomizedSigner`] traits.
     pub fn sign_raw(&self, msg: &[u8], aux_rand: &[u8; 32]) -> Result<Signature> {
+        self.sign_raw_with(msg, aux_rand, ProjectivePoint::mul_by_generator)
+    }
+
+    /// Compute Schnorr signature, using `mul_by_generator` to compute the
+    /// nonce commitment `R = k * G`.
+    fn sign_raw_with(
+        &self,
+        msg: &[u8],
+        aux_rand: &[u8; 32],
+        mul_by_generator: impl FnOnce(&Scalar) -> ProjectivePoint,
+    ) -> Result<Signature> {
         let mut t = tagged_hash(AUX_TAG).chain_update(aux_rand).finalize();
 
         for (a, b) in t.iter_mut().zip(self.secret_key.to_bytes().iter()) {
@@ -91,13 +102,10 @@ impl SigningKey {
             .chain_update(msg)
             .finalize();
 
-        let k = NonZeroScalar::try_from(&*rand)
-            .map(Self::from)
-            .map_err(|_| Error::new())?;
-
-        let secret_key = k.secret_key;
-        let verifying_point = AffinePoint::from(k.verifying_key);
-        let r = verifying_point.x.normalize();
+        let mut k = NonZeroScalar::try_from(&*rand).map_err(|_| Error::new())?;
+        let nonce_point = mul_by_generator(&*k).to_affine();
+        let r = nonce_point.x.normalize();
+        k.conditional_assign(&-k, nonce_point.y.normalize().is_odd());
 
         let e = <Scalar as Reduce<U256>>::reduce_bytes(
             &tagged_hash(CHALLENGE_TAG)
@@ -107,7 +115,8 @@ impl SigningKey {
                 .finalize(),
         );
 
-        let s = *secret_key + e * *self.secret_key;
+        let s = *k + e * *self.secret_key;
+        k.zeroize();
         let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
         let sig = Signature { r, s };
 
@@ -121,8 +130,7 @@ impl SigningKey {
 impl From<NonZeroScalar> for SigningKey {
     #[inline]
     fn from(mut secret_key: NonZeroScalar) -> SigningKey {
-        let odd = (ProjectivePoint::GENERATOR * *secret_key)
-            .to_affine()
+        let odd = AffinePoint::from_secret_scalar(&secret_key)
             .y
             .normalize()
             .is_odd();
@@ -183,7 +191,9 @@ where
     ) -> Result<Signature> {
         let mut aux_rand = [0u8; 32];
         rng.fill_bytes(&mut aux_rand);
-        self.sign_raw(&digest.finalize_fixed(), &aux_rand)
+        self.sign_raw_with(&digest.finalize_fixed(), &aux_rand, |k| {
+            ProjectivePoint::mul_by_generator_blinded(k, rng)
+        })
     }
 }
 
@@ -202,7 +212,9 @@ impl RandomizedPrehashSigner<Signature> for SigningKey {
         let mut aux_rand = [0u8; 32];
         rng.fill_bytes(&mut aux_rand);
 
-        self.sign_raw(prehash, &aux_rand)
+        self.sign_raw_with(prehash, &aux_rand, |k| {
+            ProjectivePoint::mul_by_generator_blinded(k, rng)
+        })
     }
 }
 
@@ -3,10 +3,7 @@
 use super::{tagged_hash, Signature, CHALLENGE_TAG};
 use crate::{AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar};
 use elliptic_curve::{
-    bigint::U256,
-    group::prime::PrimeCurveAffine,
-    ops::{LinearCombination, Reduce},
-    point::DecompactPoint,
+    bigint::U256, group::prime::PrimeCurveAffine, ops::Reduce, point::DecompactPoint,
 };
 use sha2::{
     digest::{consts::U32, FixedOutput},
@@ -51,20 +48,9 @@ impl VerifyingKey {
         signature: &Signature,
     ) -> core::result::Result<(), Error> {
         let (r, s) = signature.split();
+        let e = self.challenge(message, signature);
 
-        let e = <Scalar as Reduce<U256>>::reduce_bytes(
-            &tagged_hash(CHALLENGE_TAG)
-                .chain_update(signature.r.to_bytes())
-                .chain_update(self.to_bytes())
-                .chain_update(message)
-                .finalize(),
-        );
-
-        let R = ProjectivePoint::lincomb(&[
-            (ProjectivePoint::GENERATOR, **s),
-            (self.inner.to_projective(), -e),
-        ])
-        .to_affine();
+        let R = ProjectivePoint::mul_add(s, &self.inner.to_projective(), &-e).to_affine();
 
         if R.is_identity().into() || R.y.normalize().is_odd().into() || R.x.normalize() != *r {
             return Err(Error::new());
@@ -73,6 +59,17 @@ impl VerifyingKey {
         Ok(())
     }
 
+    /// Compute the BIP340 challenge `e` for `signature` over `message`.
+    pub(super) fn challenge(&self, message: &[u8], signature: &Signature) -> Scalar {
+        <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(signature.r.to_bytes())
+                .chain_update(self.to_bytes())
+                .chain_update(message)
+                .finalize(),
+        )
+    }
+
     /// Parse verifying key from big endian-encoded x-coordinate.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
         let field_bytes = FieldBytes::try_from(bytes).map_err(|_| Error::new())?;
@@ -0,0 +1,37 @@
+//! ECDH/secp256k1 test vectors
+
+use hex_literal::hex;
+
+/// ECDH test vector: a secret scalar, the peer's public key, and the
+/// resulting shared secret (the x-coordinate of the shared point).
+pub struct EcdhTestVector {
+    /// Secret scalar (big endian)
+    pub d: [u8; 32],
+    /// x-coordinate of the peer's public key
+    pub q_x: [u8; 32],
+    /// y-coordinate of the peer's public key
+    pub q_y: [u8; 32],
+    /// Shared secret
+    pub z: [u8; 32],
+}
+
+/// ECDH/secp256k1 test vectors.
+///
+/// There is no NIST CAVP data for secp256k1, so these were generated with an
+/// independent implementation and cross-checked against OpenSSL. The secret
+/// scalars are `SHA-256("k256 ecdh kat secret <i>")`, and the peer secret
+/// scalars (not included) are `SHA-256("k256 ecdh kat peer <i>")`.
+pub const ECDH_TEST_VECTORS: &[EcdhTestVector] = &[
+    EcdhTestVector {
+        d: hex!("28981f9b6064619f8efbe7649eaa2d6a5c1da09f761a4f64749622c95fea4b82"),
+        q_x: hex!("bea71e5657b160ced1c583c316b857d43ce541730a80be149321f06758fb9107"),
+        q_y: hex!("5fdabb77f7236fe2f951263ea9bd29d2821381a7abc60079c05b9d6d84ce0218"),
+        z: hex!("c2d3d76ec2ab49dd9c27772d775c56c64eec5077cfe6939214db0809a4de0f95"),
+    },
+    EcdhTestVector {
+        d: hex!("1e808203348e36cf8d001c382cd884224fbedef520384b53ef22f3959c6b9281"),
+        q_x: hex!("bf7aa252ce3d95a10e815b01a67a704f3da5767e60382da602b9d1d058ebb6c2"),
+        q_y: hex!("76c31b2dc89c543d420811c08fb65c295e56a86ee4f38be1434ce899b54dc895"),
+        z: hex!("4e9c6ff6a74bd04c74104803ef8a7cf364d2a6f32af97d39634ed4a383452bfd"),
+    },
+];
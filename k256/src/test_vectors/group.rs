@@ -220,3 +220,266 @@ pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
         hex!("B7C52588D95C3B9AA25B0403F1EEF75702E84BB7597AABE663B82F6F04EF2777"),
     ),
 ];
+
+/// Repeated doubling of the generator: `2^i * G` for `i` in `0..64`.
+///
+/// Generated independently from the curve equation using Python's arbitrary
+/// precision integers.
+pub const DOUBLE_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
+    (
+        hex!("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+        hex!("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"),
+    ),
+    (
+        hex!("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"),
+        hex!("1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"),
+    ),
+    (
+        hex!("E493DBF1C10D80F3581E4904930B1404CC6C13900EE0758474FA94ABE8C4CD13"),
+        hex!("51ED993EA0D455B75642E2098EA51448D967AE33BFBDFE40CFE97BDC47739922"),
+    ),
+    (
+        hex!("2F01E5E15CCA351DAFF3843FB70F3C2F0A1BDD05E5AF888A67784EF3E10A2A01"),
+        hex!("5C4DA8A741539949293D082A132D13B4C2E213D6BA5B7617B5DA2CB76CBDE904"),
+    ),
+    (
+        hex!("E60FCE93B59E9EC53011AABC21C23E97B2A31369B87A5AE9C44EE89E2A6DEC0A"),
+        hex!("F7E3507399E595929DB99F34F57937101296891E44D23F0BE1F32CCE69616821"),
+    ),
+    (
+        hex!("D30199D74FB5A22D47B6E054E2F378CEDACFFCB89904A61D75D0DBD407143E65"),
+        hex!("95038D9D0AE3D5C3B3D6DEC9E98380651F760CC364ED819605B3FF1F24106AB9"),
+    ),
+    (
+        hex!("BF23C1542D16EAB70B1051EAF832823CFC4C6F1DCDBAFD81E37918E6F874EF8B"),
+        hex!("5CB3866FC33003737AD928A0BA5392E4C522FC54811E2F784DC37EFE66831D9F"),
+    ),
+    (
+        hex!("34FF3BE4033F7A06696C3D09F7D1671CBCF55CD700535655647077456769A24E"),
+        hex!("5D9D11623A236C553F6619D89832098C55DF16C3E8F8B6818491067A73CC2F1A"),
+    ),
+    (
+        hex!("8282263212C609D9EA2A6E3E172DE238D8C39CABD5AC1CA10646E23FD5F51508"),
+        hex!("11F8A8098557DFE45E8256E830B60ACE62D613AC2F7B17BED31B6EAFF6E26CAF"),
+    ),
+    (
+        hex!("465370B287A79FF3905A857A9CF918D50ADBC968D9E159D0926E2C00EF34A24D"),
+        hex!("35E531B38368C082A4AF8BDAFDEEC2C1588E09B215D37A10A2F8FB20B33887F4"),
+    ),
+    (
+        hex!("241FEBB8E23CBD77D664A18F66AD6240AAEC6ECDC813B088D5B901B2E285131F"),
+        hex!("513378D9FF94F8D3D6C420BD13981DF8CD50FD0FBD0CB5AFABB3E66F2750026D"),
+    ),
+    (
+        hex!("5D1BDB4EA172FA79FCE4CC2983D8F8D9FC318B85F423DE0DEDCB63069B920471"),
+        hex!("2843826779379E2E794BB99438A2265679EB1E9996C56E7B70330666F7B83103"),
+    ),
+    (
+        hex!("175E159F728B865A72F99CC6C6FC846DE0B93833FD2222ED73FCE5B551E5B739"),
+        hex!("D3506E0D9E3C79EBA4EF97A51FF71F5EACB5955ADD24345C6EFA6FFEE9FED695"),
+    ),
+    (
+        hex!("423A013F03FF32D7A5FFBCC8E139C62130FDFEB5C6DA121BCE78049E46BC47D6"),
+        hex!("B91AE00FE1E1D970A1179F7BBAF6B3C7720D8EC3524F009ED1236E6D8B548A34"),
+    ),
+    (
+        hex!("111D6A45AC1FB90508907A7ABCD6877649DF662F3B3E2741302DF6F78416824A"),
+        hex!("0696911C478EAFFBB90D48DBFF065952F070008996DACA4CA9A111D42108E9D0"),
+    ),
+    (
+        hex!("4A4A6DC97AC7C8B8AD795DBEBCB9DCFF7290B68A5EF74E56AB5EDDE01BCED775"),
+        hex!("529911B016631E72943EF9F739C0F4571DE90CDB424742ACB2BF8F68A78DD66D"),
+    ),
+    (
+        hex!("363D90D447B00C9C99CEAC05B6262EE053441C7E55552FFE526BAD8F83FF4640"),
+        hex!("04E273ADFC732221953B445397F3363145B9A89008199ECB62003C7F3BEE9DE9"),
+    ),
+    (
+        hex!("4C1B9866ED9A7E9B553973C6C93B02BF0B62FB012EDFB59DD2712A5CAF92C541"),
+        hex!("C1F792D320BE8A0F7FBCB753CE56E69CC652EAD7E43EB1AD72C4F3FDC68FE020"),
+    ),
+    (
+        hex!("A4083877BA83B12B529A2F3C0780B54E3233EDBC1A28F135E0C8F28CBEAAF3D1"),
+        hex!("40E9F612FEEFBC79B8BF83D69361B3E22001E7576ED1EF90B12B534DF0B254B9"),
+    ),
+    (
+        hex!("A804C641D28CC0B53A4E3E1A2F56C86F6E0D880A454203B98CD3DB5A7940D33A"),
+        hex!("95BE83252B2FA6D03DEC2842C16047E81AF18CA89CF736A943CE95FA6D46967A"),
+    ),
+    (
+        hex!("8B4B5F165DF3C2BE8C6244B5B745638843E4A781A15BCD1B69F79A55DFFDF80C"),
+        hex!("4AAD0A6F68D308B4B3FBD7813AB0DA04F9E336546162EE56B3EFF0C65FD4FD36"),
+    ),
+    (
+        hex!("ED0C5CE4E13291718CE17C7EC83C611071AF64EE417C997ABB3F26714755E4BE"),
+        hex!("221A9FC7BC2345BDBF3DAD7F5A7EA68049D93925763DDAB163F9FA6EA07BF42F"),
+    ),
+    (
+        hex!("FAECB013C44CE694B3B15C3F83F1FAE8E53254566E0552CED4B6E6C807CEC8AB"),
+        hex!("CC09B5E90E9ECB57FC2E02C6EC2FB13D9C32B286B85E2E2E8981DFD9AB155070"),
+    ),
+    (
+        hex!("09BB8A132DCAD2F2C8731A0B37CBCAFDB3B2DD824F23CD3E07F64EAE9AD1B1F7"),
+        hex!("945BB2B2AFEEE3B9B6F9DD284F863E850F54A840F4752D5364130627C3811C80"),
+    ),
+    (
+        hex!("723CBAA6E5DB996D6BF771C00BD548C7B700DBFFA6C0E77BCB6115925232FCDA"),
+        hex!("96E867B5595CC498A921137488824D6E2660A0653779494801DC069D9EB39F5F"),
+    ),
+    (
+        hex!("57EFA786437B744D343D7DC45773A3C62D240A43079849071FD383D60CA030D5"),
+        hex!("D712DB0BD1B48518893627C928DE03EC689B6D2AE5E9974AB07AB44274B02F9E"),
+    ),
+    (
+        hex!("264BBD436A28BC42A2DF7E9CD5226CB91080577E327B012A7FAFC7770C584DD5"),
+        hex!("D87C6FA94EE093B4D4F75CE24C33BE226A118243717B8D8DE61227937704AB11"),
+    ),
+    (
+        hex!("A94C6524BD40D2BBDAC85C056236A79DA78BC61FD5BDEC9D2BF26BD84B2438E8"),
+        hex!("B5201FD992F96280FD79219505019E3A7E5D3C60A0E39B2BC2E2C8DBF18661F4"),
+    ),
+    (
+        hex!("EEBFA4D493BEBF98BA5FEEC812C2D3B50947961237A919839A533ECA0E7DD7FA"),
+        hex!("5D9A8CA3970EF0F269EE7EDAF178089D9AE4CDC3A711F712DDFD4FDAE1DE8999"),
+    ),
+    (
+        hex!("381C4AD7A7A97BFDA61C6031C118495FC4EA4BC08F6766D676BEE90847D297FD"),
+        hex!("936AF53B238EEEE48F3E5FA709915ECCF0451032DB939C0093ACE3187D493FC5"),
+    ),
+    (
+        hex!("E1EFB9CD05ADC63BCCE10831D9538C479CF1D05FEFDD08B2448D70422EDE454C"),
+        hex!("0ECB4530D8AF9BE7B0154C1FFE477123464E3244A7A2D4C6AD9FD233A8913797"),
+    ),
+    (
+        hex!("5318F9B1A2697010C5AC235E9AF475A8C7E5419F33D47B18D33FEEB329EB99A4"),
+        hex!("F44CCFEB4BEDA4195772D93AEBB405E8A41F2B40D1E3EC652C726EEEFE91F92D"),
+    ),
+    (
+        hex!("100F44DA696E71672791D0A09B7BDE459F1215A29B3C03BFEFD7835B39A48DB0"),
+        hex!("CDD9E13192A00B772EC8F3300C090666B7FF4A18FF5195AC0FBD5CD62BC65A09"),
+    ),
+    (
+        hex!("8C0989F2CEB5C771A8415DFF2B4C4199D8D9C8F9237D08084B05284F1E4DF706"),
+        hex!("FB4DBD044F432034FFD2172CB9DC966C60DE6BF5156511AA736AC5A35D72FA98"),
+    ),
+    (
+        hex!("FB8F153C5E266704C4A481743262C0259C528539BC95BC1BB1E63C33DC47BFFD"),
+        hex!("6CA27A9DC5E0621816FA11D9B4BCCD531DDE1389AC542613090A45DDD949B095"),
+    ),
+    (
+        hex!("E747333FD75D51755A0CC9F0A728708465A02C587737A7B8B8FA1B8B4BB2629A"),
+        hex!("F2AFFE0145070C114CC43603804C2581C88376AA6E1A969A9F8D961A6946F6D6"),
+    ),
+    (
+        hex!("E1031BE262C7ED1B1DC9227A4A04C017A77F8D4464F3B3852C8ACDE6E534FD2D"),
+        hex!("9D7061928940405E6BB6A4176597535AF292DD419E1CED79A44F18F29456A00D"),
+    ),
+    (
+        hex!("F4B93F224C8089EAB9F95DCD0F29B2C9028A6AC5DE94D85784E27E36A95C8356"),
+        hex!("A67A92EC062962DFB0E5F6A7A40EEE90C37EF1344915609ABD5861B9BE001FD3"),
+    ),
+    (
+        hex!("09D1ACA1FCE55236B19622EA025B08B0D51E8512F97E696C20D62FE17B160E8A"),
+        hex!("1153188F5101F0C63E56692CE0D8C27E6FE9E0EE9212B5E534E050C57CA04C44"),
+    ),
+    (
+        hex!("C66C59CC454C2B9E18A2AD793821CDE7518B3A93BFC39562E97D7D0475BA7FC2"),
+        hex!("D9592FE2BFB30FCFBEA4F3CEAAC10CB2F00A60DDB15955977EC3C69CF75F5956"),
+    ),
+    (
+        hex!("FEEA6CAE46D55B530AC2839F143BD7EC5CF8B266A41D6AF52D5E688D9094696D"),
+        hex!("E57C6B6C97DCE1BAB06E4E12BF3ECD5C981C8957CC41442D3155DEBF18090088"),
+    ),
+    (
+        hex!("4D000B621ADB87E1C53261AF9DB2E179141ECAE0B331A1870AA4040AEE752B08"),
+        hex!("6A0D5B8F18E0D255CB6D825582D972CCCB7DF5F119C7293A3E72851F48302CEA"),
+    ),
+    (
+        hex!("71F570CA203DA05DD6AA262114717128D657A0403E1F1B77F89962FD475C58EF"),
+        hex!("EB42415B95DC880DD25557345BC95B8DF2445D00C3363E7DF8649A72D35D420E"),
+    ),
+    (
+        hex!("A2B7B3629F7BD253B7D282B5C21DA01446B4821DC65E76516048B06043FF8359"),
+        hex!("693038941695122D57A937A3F71E29C910D10835046F3835A2397FECFE86FEC2"),
+    ),
+    (
+        hex!("DA67A91D91049CDCB367BE4BE6FFCA3CFEED657D808583DE33FA978BC1EC6CB1"),
+        hex!("9BACAA35481642BC41F463F7EC9780E5DEC7ADC508F740A17E9EA8E27A68BE1D"),
+    ),
+    (
+        hex!("4DBACD365FA1EF587C0C0CFAAF00D8718BBD9F35CCEA5A835EE3CC821FE741C9"),
+        hex!("16C3540E8A51892E7FDCFD59E838299D0CC384A09FC0535F60BE10F8338EB623"),
+    ),
+    (
+        hex!("13D1FFC481509BEEE68F17D8FF41C2590F4C85F15268605087EDA8BAB4E218DA"),
+        hex!("6008391FA991961DCECB9337B1B758BDA4AD01206D5BD127E0DB419DDB191C19"),
+    ),
+    (
+        hex!("219B4F9CEF6C60007659C79C45B0533B3CC9D916CE29DBFF133B40CAA2E96DB8"),
+        hex!("24D9C605D959EFEAF5A44180C0372A6E394F8AC53E90576527DF01A78D3B6BC7"),
+    ),
+    (
+        hex!("53904FAA0B334CDDA6E000935EF22151EC08D0F7BB11069F57545CCC1A37B7C0"),
+        hex!("5BC087D0BC80106D88C9ECCAC20D3C1C13999981E14434699DCB096B022771C8"),
+    ),
+    (
+        hex!("01A575AF9D4146753CF991196316995D2A6EE7AAAD0F85AD57CD0F1F38A47CA9"),
+        hex!("3038F1CB8AB20DC3CC55FC52E1BB8698BDB93C5D9F4D7EA667C5DF2E77EBCDB7"),
+    ),
+    (
+        hex!("F5F0E0437621D439CA71F5C1B76155D6D3A61A83D3C20C6EE309D755E315565B"),
+        hex!("6B9F4E62BE5A052BF62189160DF7101AA5BF61BF3ED7E40A678430AFDD2ECC82"),
+    ),
+    (
+        hex!("8F506F0B6C0B6E9A57A7F36D970CA4E347CBC92146227642CBE781D9F5362D33"),
+        hex!("469F955D2AFA61719530C5424F1C336848CF925D43BB8EAF30487D0C87FA243F"),
+    ),
+    (
+        hex!("8E7BCD0BD35983A7719CCA7764CA906779B53A043A9B8BCAEFF959F43AD86047"),
+        hex!("10B7770B2A3DA4B3940310420CA9514579E88E2E47FD68B3EA10047E8460372A"),
+    ),
+    (
+        hex!("33B35BAA195E729DC350F319996950DF3BC15B8D3D0389E777D2808BF13F0351"),
+        hex!("A58A0185640ABF87F9464036248D52BCAA6560EFBC889B702BC503CCCB8D7418"),
+    ),
+    (
+        hex!("374DEEAE22C93F955CB83AD2071F7E2256F6E109CAD7BCA6D71DC7B24414BB36"),
+        hex!("171165B64FCD4F9916032C06F806F7293828D66300E543217875BEA98DAF734A"),
+    ),
+    (
+        hex!("2380C09C7F3AEAE57C46E07395AEB0DC944DBAF2B62A9F0C5E8A64AD6AE7D616"),
+        hex!("6F8E86193464956AF1598AEFD509B09A93AF92148F8467560099BE48161BBC1A"),
+    ),
+    (
+        hex!("385EED34C1CDFF21E6D0818689B81BDE71A7F4F18397E6690A841E1599C43862"),
+        hex!("283BEBC3E8EA23F56701DE19E9EBF4576B304EEC2086DC8CC0458FE5542E5453"),
+    ),
+    (
+        hex!("F6F622083DAF54800456BE134D5F67D147C82642BEFC1CE2DC83A27078F2827C"),
+        hex!("1BCD4E817DE73A0FAF2C5715B367CEE7E657CA7448321BF6D15B20B520AAA102"),
+    ),
+    (
+        hex!("FB26E5188F953DE2BD70CB3C3D1FC255CD91C3CE7D8C6F369D893209715ADCB6"),
+        hex!("F3E128811012A34D58E846A719D0176916D2CB31B8B7AB5449DBCA3B58BA68F3"),
+    ),
+    (
+        hex!("8991225911B9132D28F5C6BC763CEAB7D18C37060E8BD1D7ED44DB7560788C1E"),
+        hex!("DA8B4D987CC9AC9B27B8763559B136FA36969C84FDEF9E11635C42228E8F0EF1"),
+    ),
+    (
+        hex!("06F9D9B803ECF191637C73A4413DFA180FDDF84A5947FBC9C606ED86C3FAC3A7"),
+        hex!("7C80C68E603059BA69B8E2A30E45C4D47EA4DD2F5C281002D86890603A842160"),
+    ),
+    (
+        hex!("AE86EEEA252B411C1CDC36C284482939DA1745E5A7E4DA175C9D22744B7FD72D"),
+        hex!("19E993C9707302F962AB0ACE589FF0E98D9211551472F7282334CB7A4EEE38BC"),
+    ),
+    (
+        hex!("2248C9F90BBFFF55E61D2F8C56DC2C488718BE75CF36F2EE7A1474267C169290"),
+        hex!("FA0594692D21EED7A506BB55B435BA18E163750235DA2BE2369D8A12883EA257"),
+    ),
+    (
+        hex!("E11A6E16E05C44074AC11B48D94085D0A99F0877DD1C6F76FD0DAC4BB50964E3"),
+        hex!("87D6065B87A2D430E1AD5E2596F0AF2417ADC6E138318C6F767FBF8B0682BFC8"),
+    ),
+];
@@ -53,6 +53,15 @@ fn bench_scalar_negate<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
 fn bench_scalar_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     group.bench_function("invert", |b| b.iter(|| x.invert()));
+
+    // Fermat inversion: x^(n - 2)
+    let n_minus_2 = [
+        0xf3b9_cac2_fc63_254f,
+        0xbce6_faad_a717_9e84,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_0000_0000,
+    ];
+    group.bench_function("invert (Fermat)", |b| b.iter(|| x.pow_vartime(&n_minus_2)));
 }
 
 fn bench_point(c: &mut Criterion) {
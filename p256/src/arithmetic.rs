@@ -18,6 +18,26 @@ use primeorder::{point_arithmetic, PrimeCurveParams};
 pub type AffinePoint = primeorder::AffinePoint<NistP256>;
 
 /// Elliptic curve point in projective coordinates.
+///
+/// Unlike secp256k1, P-256 has no efficiently computable endomorphism, so
+/// variable-base scalar multiplication (i.e. `ProjectivePoint * Scalar`) uses a
+/// constant-time fixed-window method rather than a GLV decomposition.
+///
+/// The following example checks equivalence only: variable-base multiplication
+/// of the generator gives the same point as the fixed-base
+/// [`MulByGenerator`](elliptic_curve::ops::MulByGenerator) method.
+///
+/// ```
+/// use p256::{elliptic_curve::{ops::MulByGenerator, Field}, ProjectivePoint, Scalar};
+/// use rand_core::OsRng; // requires 'getrandom' feature
+///
+/// let k = Scalar::random(&mut OsRng);
+/// assert_eq!(ProjectivePoint::GENERATOR * k, ProjectivePoint::mul_by_generator(&k));
+/// ```
+///
+/// Performance is measured by the `point-scalar mul` benchmark in
+/// `benches/scalar.rs`, which can be compared against the same benchmark for
+/// the GLV-based implementation in the `k256` crate.
 pub type ProjectivePoint = primeorder::ProjectivePoint<NistP256>;
 
 impl CurveArithmetic for NistP256 {
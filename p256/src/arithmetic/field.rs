@@ -6,22 +6,20 @@
 #[cfg_attr(target_pointer_width = "64", path = "field/field64.rs")]
 mod field_impl;
 
-use crate::FieldBytes;
+use crate::{FieldBytes, FIELD_MODULUS_HEX as MODULUS_HEX};
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use elliptic_curve::ops::Invert;
 use elliptic_curve::{
-    bigint::{ArrayEncoding, U256, U512},
+    bigint::{ArrayEncoding, U256},
     ff::{Field, PrimeField},
     rand_core::RngCore,
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption},
     zeroize::DefaultIsZeroes,
 };
 
-const MODULUS_HEX: &str = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
-
 /// Constant representing the modulus
 /// p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1
 pub const MODULUS: FieldElement = FieldElement(U256::from_be_hex(MODULUS_HEX));
@@ -93,7 +91,25 @@ impl FieldElement {
     ///
     /// Used incorrectly this can lead to invalid results!
     pub(crate) const fn from_uint_unchecked(w: U256) -> Self {
-        Self(w).to_montgomery()
+        Self::multiply(&Self(w), &R2)
+    }
+
+    /// Returns the internal Montgomery form representation of this element,
+    /// i.e. `aR mod p`.
+    ///
+    /// This is intended for precomputing constants which can later be loaded
+    /// with [`FieldElement::from_montgomery`] without a conversion step. Use
+    /// [`FieldElement::to_bytes`] for the canonical encoding.
+    pub const fn to_montgomery(&self) -> U256 {
+        self.0
+    }
+
+    /// Load a [`FieldElement`] from its Montgomery form `aR mod p`, as returned
+    /// by [`FieldElement::to_montgomery`].
+    ///
+    /// Returns None if `w` is not in the range [0, p).
+    pub fn from_montgomery(w: U256) -> CtOption<Self> {
+        CtOption::new(Self(w), w.ct_lt(&MODULUS.0))
     }
 
     /// Determine if this `FieldElement` is zero.
@@ -141,12 +157,6 @@ impl FieldElement {
         Self(field_impl::to_canonical(self.0))
     }
 
-    /// Translate a field element into the Montgomery domain.
-    #[inline]
-    pub(crate) const fn to_montgomery(self) -> Self {
-        Self::multiply(&self, &R2)
-    }
-
     /// Returns self * rhs mod p
     pub const fn multiply(&self, rhs: &Self) -> Self {
         let (lo, hi): (U256, U256) = self.0.split_mul(&rhs.0);
@@ -251,12 +261,16 @@ impl Field for FieldElement {
     const ONE: Self = Self::ONE;
 
     fn random(mut rng: impl RngCore) -> Self {
-        // We reduce a random 512-bit value into a 256-bit field, which results in a
-        // negligible bias from the uniform distribution.
-        let mut buf = [0; 64];
-        rng.fill_bytes(&mut buf);
-        let buf = U512::from_be_slice(&buf);
-        Self(field_impl::from_bytes_wide(buf))
+        // Rejection sampling over the canonical range, so the result is uniform
+        // over [0, p). The modulus is close to 2^256, so rejections are rare.
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_bytes(bytes).into() {
+                return fe;
+            }
+        }
     }
 
     #[must_use]
@@ -506,8 +520,8 @@ mod tests {
     use crate::{test_vectors::field::DBL_TEST_VECTORS, FieldBytes};
     use core::ops::Mul;
 
-    #[cfg(target_pointer_width = "64")]
     use crate::U256;
+    use elliptic_curve::bigint::ArrayEncoding;
     #[cfg(target_pointer_width = "64")]
     use proptest::{num::u64::ANY, prelude::*};
 
@@ -525,6 +539,35 @@ mod tests {
         assert_eq!(one.mul(&one), one);
     }
 
+    #[test]
+    fn ct_eq() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let x = FieldElement::from_u64(0x1234_5678_9abc_def0).square();
+        let y = x + FieldElement::ONE;
+        assert!(bool::from(x.ct_eq(&x)));
+        assert!(!bool::from(x.ct_eq(&y)));
+        assert!(bool::from((y - FieldElement::ONE).ct_eq(&x)));
+    }
+
+    #[test]
+    fn random_is_canonical_and_distinct() {
+        use elliptic_curve::ff::Field;
+        use rand_core::OsRng;
+
+        let modulus = U256::from_be_hex(crate::FIELD_MODULUS_HEX);
+        let mut samples = [[0u8; 32]; 1000];
+
+        for sample in samples.iter_mut() {
+            let bytes = FieldElement::random(&mut OsRng).to_bytes();
+            assert!(U256::from_be_byte_array(bytes) < modulus);
+            *sample = bytes.into();
+        }
+
+        samples.sort_unstable();
+        assert!(samples.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
     #[test]
     fn from_bytes() {
         assert_eq!(
@@ -559,6 +602,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn montgomery_round_trip() {
+        use super::{MODULUS, R};
+
+        // Gₓ, defined in normal form
+        const GX: &str = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        let gx = FieldElement::from_hex(GX);
+
+        let mont = gx.to_montgomery();
+        assert_ne!(mont, U256::from_be_hex(GX));
+
+        let loaded = FieldElement::from_montgomery(mont).unwrap();
+        assert_eq!(loaded, gx);
+        assert_eq!(loaded.to_bytes(), U256::from_be_hex(GX).to_be_byte_array());
+
+        assert_eq!(FieldElement::ONE.to_montgomery(), R.0);
+        assert_eq!(FieldElement::ZERO.to_montgomery(), U256::ZERO);
+        assert!(bool::from(
+            FieldElement::from_montgomery(MODULUS.0).is_none()
+        ));
+    }
+
     #[test]
     fn repeated_add() {
         let mut r = FieldElement::ONE;
@@ -12,7 +12,7 @@ use core::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Shr, ShrAssign, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{prelude::*, Limb, U256},
+    bigint::{prelude::*, Limb, Odd, U256},
     group::ff::{self, Field, PrimeField},
     ops::{Invert, Reduce, ReduceNonZero},
     rand_core::RngCore,
@@ -35,6 +35,9 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 /// n = FFFFFFFF 00000000 FFFFFFFF FFFFFFFF BCE6FAAD A7179E84 F3B9CAC2 FC632551
 pub(crate) const MODULUS: U256 = NistP256::ORDER;
 
+/// Modulus as an [`Odd`] integer, as required by safegcd inversion.
+const ODD_MODULUS: Odd<U256> = Odd::<U256>::from_be_hex(ORDER_HEX);
+
 /// `MODULUS / 2`
 const FRAC_MODULUS_2: Scalar = Scalar(MODULUS.shr_vartime(1));
 
@@ -79,11 +82,67 @@ impl Scalar {
     /// Multiplicative identity.
     pub const ONE: Self = Self(U256::ONE);
 
+    /// Checks if the scalar is zero, in constant time.
+    pub fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Checks if the scalar is one, in constant time.
+    pub fn is_one(&self) -> Choice {
+        self.ct_eq(&Self::ONE)
+    }
+
     /// Returns the SEC1 encoding of this scalar.
     pub fn to_bytes(&self) -> FieldBytes {
         self.0.to_be_byte_array()
     }
 
+    /// Returns the SEC1 encoding of this scalar as a plain byte array.
+    ///
+    /// This is the same as [`Scalar::to_bytes`]. Scalars are stored as
+    /// little endian limbs rather than as bytes, so the encoding can't be
+    /// borrowed and is always computed.
+    pub fn to_be_array(&self) -> [u8; 32] {
+        self.to_bytes().into()
+    }
+
+    /// Interpret `bytes` as a big endian integer and reduce it modulo the
+    /// P-256 curve order `n`.
+    ///
+    /// This is the explicit way to convert a scalar encoded for another curve,
+    /// such as a secp256k1 scalar, since the two curves have different orders.
+    /// Values encoded for the other curve which are not less than `n` do not
+    /// survive the round trip unchanged.
+    pub fn reduce_from_field_bytes(bytes: &FieldBytes) -> Self {
+        <Self as Reduce<U256>>::reduce_bytes(bytes)
+    }
+
+    /// Interpret `bytes` as a big endian integer and reduce it modulo the
+    /// P-256 curve order `n`.
+    ///
+    /// This is an alias of [`Scalar::reduce_from_field_bytes`], under the name
+    /// used for the same operation by other ecosystems, e.g. arkworks'
+    /// `PrimeField::from_be_bytes_mod_order`.
+    pub fn from_be_bytes_mod_order(bytes: &FieldBytes) -> Self {
+        Self::reduce_from_field_bytes(bytes)
+    }
+
+    /// Is `w` less than the curve order `n`, i.e. a canonical scalar encoding?
+    ///
+    /// This is the check behind [`PrimeField::from_repr`], as well as the
+    /// `r < n` and `s < n` range checks on signature components.
+    pub fn ct_lt_order(w: &U256) -> Choice {
+        w.ct_lt(&NistP256::ORDER)
+    }
+
+    /// Is this scalar in the upper half of the field, i.e. greater than `n / 2`?
+    ///
+    /// This is the check behind [`IsHigh::is_high`] and low-S signature
+    /// normalization.
+    pub fn ct_is_high(&self) -> Choice {
+        self.0.ct_gt(&FRAC_MODULUS_2.0)
+    }
+
     /// Returns self + rhs mod n
     pub const fn add(&self, rhs: &Self) -> Self {
         Self(self.0.add_mod(&rhs.0, &NistP256::ORDER))
@@ -94,11 +153,21 @@ impl Scalar {
         self.add(self)
     }
 
+    /// Returns 3*self.
+    pub const fn triple(&self) -> Self {
+        self.double().add(self)
+    }
+
     /// Returns self - rhs mod n.
     pub const fn sub(&self, rhs: &Self) -> Self {
         Self(self.0.sub_mod(&rhs.0, &NistP256::ORDER))
     }
 
+    /// Returns -self mod n.
+    pub const fn negate(&self) -> Self {
+        Self(self.0.neg_mod(&NistP256::ORDER))
+    }
+
     /// Returns self * rhs mod n
     pub const fn multiply(&self, rhs: &Self) -> Self {
         let (lo, hi) = self.0.split_mul(&rhs.0);
@@ -118,14 +187,69 @@ impl Scalar {
         Self(self.0.wrapping_shr_vartime(shift))
     }
 
-    /// Returns the multiplicative inverse of self, if self is non-zero
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    ///
+    /// Uses the constant-time Bernstein-Yang "safegcd" algorithm:
+    /// <https://eprint.iacr.org/2019/266>
     pub fn invert(&self) -> CtOption<Self> {
-        CtOption::new(self.invert_unchecked(), !self.is_zero())
+        let inv = CtOption::from(self.0.inv_odd_mod(&ODD_MODULUS));
+        CtOption::new(
+            Self(inv.unwrap_or(U256::ZERO)),
+            inv.is_some() & !self.is_zero(),
+        )
     }
 
-    /// Returns the multiplicative inverse of self.
+    /// Returns the square root of self in the scalar field, if it exists.
     ///
-    /// Does not check that self is non-zero.
+    /// The group order `n` satisfies `n ≡ 17 mod 32` (i.e. `n - 1 = 2^4 * t` with
+    /// `t` odd, hence [`PrimeField::S`] is 4), so the simpler `(n + 1) / 4`
+    /// exponentiation doesn't apply. This uses constant-time Tonelli-Shanks for
+    /// `q mod 16 = 1`:
+    /// <https://eprint.iacr.org/2012/685.pdf> (page 12, algorithm 5)
+    #[allow(clippy::many_single_char_names)]
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // Note: `pow_vartime` is constant-time with respect to `self`
+        let w = self.pow_vartime(&[
+            0x279dce5617e3192a,
+            0xfde737d56d38bcf4,
+            0x07ffffffffffffff,
+            0x07fffffff8000000,
+        ]);
+
+        let mut v = Self::S;
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = Self::ROOT_OF_UNITY;
+
+        for max_v in (1..=Self::S).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v = Choice::from(1);
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.is_one();
+                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.is_one());
+            z = z.square();
+            b *= z;
+            v = k;
+        }
+
+        CtOption::new(x, x.square().ct_eq(self))
+    }
+
+    /// Returns the multiplicative inverse of self using Fermat's Little Theorem.
+    ///
+    /// Does not check that self is non-zero. This is slower than [`Scalar::invert`]
+    /// but usable in `const` contexts.
     const fn invert_unchecked(&self) -> Self {
         // We need to find b such that b * a ≡ 1 mod p. As we are in a prime
         // field, we can apply Fermat's Little Theorem:
@@ -223,46 +347,8 @@ impl Field for Scalar {
         Scalar::invert(self)
     }
 
-    /// Tonelli-Shank's algorithm for q mod 16 = 1
-    /// <https://eprint.iacr.org/2012/685.pdf> (page 12, algorithm 5)
-    #[allow(clippy::many_single_char_names)]
     fn sqrt(&self) -> CtOption<Self> {
-        // Note: `pow_vartime` is constant-time with respect to `self`
-        let w = self.pow_vartime(&[
-            0x279dce5617e3192a,
-            0xfde737d56d38bcf4,
-            0x07ffffffffffffff,
-            0x07fffffff8000000,
-        ]);
-
-        let mut v = Self::S;
-        let mut x = *self * w;
-        let mut b = x * w;
-        let mut z = Self::ROOT_OF_UNITY;
-
-        for max_v in (1..=Self::S).rev() {
-            let mut k = 1;
-            let mut tmp = b.square();
-            let mut j_less_than_v = Choice::from(1);
-
-            for j in 2..max_v {
-                let tmp_is_one = tmp.ct_eq(&Self::ONE);
-                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
-                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
-                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
-                j_less_than_v &= !j.ct_eq(&v);
-                k = u32::conditional_select(&j, &k, tmp_is_one);
-                z = Self::conditional_select(&z, &new_z, j_less_than_v);
-            }
-
-            let result = x * z;
-            x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
-            z = z.square();
-            b *= z;
-            v = k;
-        }
-
-        CtOption::new(x, x.square().ct_eq(self))
+        self.sqrt()
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -291,7 +377,7 @@ impl PrimeField for Scalar {
     /// [0, p).
     fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
         let inner = U256::from_be_byte_array(bytes);
-        CtOption::new(Self(inner), inner.ct_lt(&NistP256::ORDER))
+        CtOption::new(Self(inner), Self::ct_lt_order(&inner))
     }
 
     fn to_repr(&self) -> FieldBytes {
@@ -400,7 +486,7 @@ impl Invert for Scalar {
 
 impl IsHigh for Scalar {
     fn is_high(&self) -> Choice {
-        self.0.ct_gt(&FRAC_MODULUS_2.0)
+        self.ct_is_high()
     }
 }
 
@@ -611,6 +697,14 @@ impl Mul<&Scalar> for &Scalar {
     }
 }
 
+impl Mul<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        Scalar::multiply(self, &other)
+    }
+}
+
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
@@ -635,7 +729,7 @@ impl Neg for Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar::ZERO - self
+        self.negate()
     }
 }
 
@@ -643,7 +737,7 @@ impl<'a> Neg for &'a Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar::ZERO - self
+        self.negate()
     }
 }
 
@@ -759,6 +853,78 @@ mod tests {
     impl_field_sqrt_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
 
+    /// Square roots of known residues, and rejection of known non-residues.
+    #[test]
+    fn sqrt_known_residues() {
+        for n in [0u64, 4, 9, 1 << 32] {
+            let scalar = Scalar::from(n);
+            let sqrt = scalar.sqrt().unwrap();
+            assert_eq!(sqrt.square(), scalar);
+        }
+
+        // `2` is a square root of 4, so the result is either 2 or -2
+        let two = Scalar::from(2u64);
+        let sqrt = Scalar::from(4u64).sqrt().unwrap();
+        assert!(sqrt == two || sqrt == -two);
+
+        // n ≡ 1 mod 4, so -1 is a quadratic residue
+        let minus_one = -Scalar::ONE;
+        assert_eq!(minus_one.sqrt().unwrap().square(), minus_one);
+
+        // Small known non-residues, and the multiplicative generator
+        for n in [7u64, 11, 13] {
+            assert!(bool::from(Scalar::from(n).sqrt().is_none()));
+        }
+        assert!(bool::from(
+            Scalar::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+    }
+
+    #[test]
+    fn is_zero_and_is_one() {
+        assert!(bool::from(Scalar::ZERO.is_zero()));
+        assert!(!bool::from(Scalar::ZERO.is_one()));
+        assert!(bool::from(Scalar::ONE.is_one()));
+        assert!(!bool::from(Scalar::ONE.is_zero()));
+
+        for scalar in [
+            Scalar::from(2u64),
+            -Scalar::ONE,
+            Scalar::ONE + Scalar::ONE - Scalar::ONE,
+        ] {
+            assert_eq!(bool::from(scalar.is_zero()), scalar == Scalar::ZERO);
+            assert_eq!(bool::from(scalar.is_one()), scalar == Scalar::ONE);
+        }
+
+        // Arithmetic results which wrap around the order
+        assert!(bool::from((-Scalar::ONE + Scalar::ONE).is_zero()));
+        assert!(bool::from((-Scalar::ONE * -Scalar::ONE).is_one()));
+    }
+
+    #[test]
+    fn negation_identities() {
+        use elliptic_curve::subtle::{Choice, ConditionallyNegatable};
+        use rand_core::OsRng;
+
+        for x in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut OsRng),
+        ] {
+            assert_eq!(-x + x, Scalar::ZERO);
+            assert_eq!(-(-x), x);
+            assert_eq!(x.negate(), -x);
+            assert_eq!(-&x, -x);
+
+            let mut y = x;
+            y.conditional_negate(Choice::from(0));
+            assert_eq!(y, x);
+            y.conditional_negate(Choice::from(1));
+            assert_eq!(y, -x);
+        }
+    }
+
     #[test]
     fn from_to_bytes_roundtrip() {
         let k: u64 = 42;
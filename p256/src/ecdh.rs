@@ -45,3 +45,26 @@ pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP256>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP256>;
+
+#[cfg(test)]
+mod tests {
+    use super::diffie_hellman;
+    use crate::{test_vectors::ecdh::ECDH_TEST_VECTORS, EncodedPoint, NonZeroScalar, PublicKey};
+    use elliptic_curve::sec1::FromEncodedPoint;
+
+    #[test]
+    fn ecdh_test_vectors() {
+        for vector in ECDH_TEST_VECTORS {
+            let secret = NonZeroScalar::try_from(&vector.d[..]).unwrap();
+            let point = EncodedPoint::from_affine_coordinates(
+                &vector.q_x.into(),
+                &vector.q_y.into(),
+                false,
+            );
+            let public = PublicKey::from_encoded_point(&point).unwrap();
+
+            let shared = diffie_hellman(secret, public.as_affine());
+            assert_eq!(shared.raw_secret_bytes().as_slice(), &vector.z);
+        }
+    }
+}
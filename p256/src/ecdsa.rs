@@ -1,7 +1,7 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
 //!
 //! This module contains support for computing and verifying ECDSA signatures.
-//! To use it, you will need to enable one of the two following Cargo features:
+//! To use it, you will need to enable one of the following Cargo features:
 //!
 //! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
 //!   ECDSA/P-256 signature). Does not require the `arithmetic` feature.
@@ -13,6 +13,11 @@
 //! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
 //!   [`VerifyingKey`] types which natively implement ECDSA/P-256 signing and
 //!   verification.
+//! - `signing`: provides only the [`SigningKey`] type.
+//! - `verifying`: provides only the [`VerifyingKey`] type. This omits the
+//!   RFC6979 deterministic nonce generation (and its HMAC-DRBG) which is only
+//!   needed for signing, and is intended for verify-only targets such as
+//!   secure boot firmware.
 //!
 //! ## Signing/Verification Example
 //!
@@ -41,6 +46,9 @@
 
 pub use ecdsa_core::signature::{self, Error};
 
+#[cfg(feature = "signing")]
+pub mod hazmat;
+
 use super::NistP256;
 use ecdsa_core::EcdsaCurve;
 
@@ -55,17 +63,48 @@ impl EcdsaCurve for NistP256 {
 }
 
 /// ECDSA/P-256 signing key
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "signing")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP256>;
 
 /// ECDSA/P-256 verification key (i.e. public key)
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "verifying")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP256>;
 
 #[cfg(feature = "sha256")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
     type Digest = sha2::Sha256;
 }
+
+#[cfg(all(feature = "signing", feature = "sha256"))]
+primeorder::impl_streaming_signer!(SigningKey, Signature, sha2::Sha256, "SHA-256");
+
+/// Verify an ASN.1 DER encoded signature over the given message.
+///
+/// This is a convenience function for protocols such as X.509 and TLS where
+/// signatures are transmitted DER-encoded: it parses `der_sig` as a
+/// [`DerSignature`] and verifies it using [`VerifyingKey`]'s
+/// [`signature::Verifier`] impl, returning an [`Error`] if either step fails.
+#[cfg(all(feature = "verifying", feature = "sha256"))]
+pub fn verify_der(verifying_key: &VerifyingKey, msg: &[u8], der_sig: &[u8]) -> Result<(), Error> {
+    use signature::Verifier;
+
+    let signature = DerSignature::try_from(der_sig)?;
+    verifying_key.verify(msg, &signature)
+}
+
+/// Check that `bytes` is a SEC1-encoded P-256 point on the curve and not the
+/// identity, which is all a verifying key needs to satisfy.
+///
+/// Curves with a cofactor also require a prime order subgroup check, and code
+/// ported from them can call this in its place: P-256 has prime order, so
+/// every valid point is in the subgroup.
+#[cfg(feature = "verifying")]
+pub fn validate_verifying_key(bytes: &[u8]) -> Result<(), Error> {
+    primeorder::validate_public_key::<NistP256>(bytes)
+        .map(|_| ())
+        .map_err(|_| Error::new())
+}
+
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
     use crate::{
@@ -80,6 +119,25 @@ mod tests {
     use hex_literal::hex;
     use sha2::Digest;
 
+    #[test]
+    fn validate_verifying_key() {
+        use super::validate_verifying_key;
+        use elliptic_curve::sec1::ToEncodedPoint;
+
+        let encoded = SigningKey::from_slice(&[0x42; 32])
+            .unwrap()
+            .verifying_key()
+            .to_encoded_point(true);
+        assert!(validate_verifying_key(encoded.as_bytes()).is_ok());
+        assert!(validate_verifying_key(&[0x00]).is_err());
+
+        // x = 1 has no corresponding y on P-256
+        let mut not_on_curve = [0u8; 33];
+        not_on_curve[0] = 0x03;
+        not_on_curve[32] = 0x01;
+        assert!(validate_verifying_key(&not_on_curve).is_err());
+    }
+
     // Test vector from RFC 6979 Appendix 2.5 (NIST P-256 + SHA-256)
     // <https://tools.ietf.org/html/rfc6979#appendix-A.2.5>
     #[test]
@@ -147,6 +205,48 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // Signature produced with OpenSSL:
+    // `openssl dgst -sha256 -sign key.pem -out sig.der msg.txt`
+    #[test]
+    fn verify_der() {
+        let verifying_key = VerifyingKey::from_sec1_bytes(&hex!(
+            "0484c7793f158ed92871a9288a5759761890daabdedccdfb8f047613a4b2bbd9
+             6d214f4824d75f4df42340b2000562f79eb4622305a0207a20daafa4c6c2e82246"
+        ))
+        .unwrap();
+        let msg = b"DER-encoded ECDSA/P-256 signature test";
+        let der_sig = hex!(
+            "30460221008d3cb3932b5c677c95b9805a46283bcd33252c1e38afe34ba6b94212b9a5dab5
+             022100f69ec18b69f45b339bfe21842c0c74318f30ebacfb6d04654c33b4f82de15be1"
+        );
+
+        assert!(super::verify_der(&verifying_key, msg, &der_sig).is_ok());
+        assert!(super::verify_der(&verifying_key, b"wrong message", &der_sig).is_err());
+        assert!(super::verify_der(&verifying_key, msg, &der_sig[..der_sig.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn streaming_signer() {
+        use crate::ecdsa::{NewSigner, StreamingSigner};
+
+        let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let msg = b"streaming signers hash the message incrementally before signing it";
+
+        let mut signer = StreamingSigner::new(&signing_key);
+        for chunk in msg.chunks(7) {
+            signer.update(chunk);
+        }
+
+        let expected: Signature = signing_key.sign(msg);
+        assert_eq!(signer.finalize_sign(), expected);
+
+        let mut signer = signing_key.new_signer();
+        for chunk in msg.chunks(13) {
+            signer.update(chunk);
+        }
+        assert_eq!(signer.finalize_sign(), expected);
+    }
+
     mod sign {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP256};
         ecdsa_core::new_signing_test!(NistP256, ECDSA_TEST_VECTORS);
@@ -161,4 +261,38 @@ mod tests {
         use crate::NistP256;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP256);
     }
+
+    mod randomized {
+        use crate::ecdsa::{signature, Signature, SigningKey};
+
+        primeorder::impl_randomized_signer_tests!(SigningKey, Signature);
+
+        #[test]
+        fn randomized_signatures_keep_high_s() {
+            // P-256 signatures aren't normalized to low-S, so across enough
+            // nonces both halves of the range of `s` show up
+            let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let high_s = (0..64)
+                .map(|seed| sign_seeded(&signing_key, seed, b"randomized high-S signatures"))
+                .filter(|sig| sig.normalize_s() != *sig)
+                .count();
+            assert!(high_s > 0 && high_s < 64);
+        }
+    }
+
+    mod der {
+        use crate::ecdsa::{DerSignature, Signature};
+        use hex_literal::hex;
+
+        // The OpenSSL-generated signature from `verify_der`, whose `r` and `s`
+        // both have their high bit set and so need a leading zero byte
+        primeorder::impl_der_signature_tests!(
+            Signature,
+            DerSignature,
+            hex!(
+                "30460221008d3cb3932b5c677c95b9805a46283bcd33252c1e38afe34ba6b94212b9a5dab5
+                 022100f69ec18b69f45b339bfe21842c0c74318f30ebacfb6d04654c33b4f82de15be1"
+            )
+        );
+    }
 }
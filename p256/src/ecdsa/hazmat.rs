@@ -0,0 +1,62 @@
+//! Low-level ECDSA/P-256 primitives.
+//!
+//! # ⚠️ Warning: Hazmat!
+//!
+//! YOU PROBABLY DON'T WANT TO USE THESE!
+//!
+//! These functions bypass the RFC6979 deterministic nonce generation used by
+//! [`SigningKey`]'s [`signature::Signer`] impl. The security of ECDSA depends
+//! entirely on the ephemeral scalar `k` being uniformly random, secret, and
+//! never reused: signing two different messages with the same `k`, or leaking
+//! even a few bits of several `k` values, reveals the private key.
+//!
+//! Only use them if a protocol requires deriving `k` externally.
+
+use super::{Error, Signature, SigningKey};
+use crate::{NonZeroScalar, Scalar};
+
+/// Sign the prehashed message scalar `z` using the caller-supplied ephemeral
+/// scalar `k`.
+///
+/// See the [module-level documentation](self) for why this is dangerous:
+/// **reusing `k` across messages leaks the private key.**
+pub fn sign_prehashed_with_k(
+    signing_key: &SigningKey,
+    z: &Scalar,
+    k: &NonZeroScalar,
+) -> Result<Signature, Error> {
+    let (signature, _) =
+        ecdsa_core::hazmat::sign_prehashed(signing_key.as_nonzero_scalar(), k, &z.to_bytes())?;
+
+    Ok(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sign_prehashed_with_k;
+    use crate::{ecdsa::SigningKey, NonZeroScalar, Scalar, U256};
+    use elliptic_curve::ops::Reduce;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    // Test vector from RFC 6979 Appendix 2.5 (NIST P-256 + SHA-256), which
+    // lists the `k` derived for the message "sample".
+    // <https://tools.ietf.org/html/rfc6979#appendix-A.2.5>
+    #[test]
+    fn rfc6979_sample_with_fixed_k() {
+        let x = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let k = hex!("a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60");
+        let signing_key = SigningKey::from_bytes(&x.into()).unwrap();
+        let k = NonZeroScalar::from_repr(k.into()).unwrap();
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&Sha256::digest(b"sample"));
+
+        let signature = sign_prehashed_with_k(&signing_key, &z, &k).unwrap();
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716
+                 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+            )
+        );
+    }
+}
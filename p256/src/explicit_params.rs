@@ -0,0 +1,290 @@
+//! Import of private keys which spell out the P-256 domain parameters.
+//!
+//! [RFC5915] and [RFC5480] allow `ECParameters` to be given either as the
+//! OID of a named curve or as the explicit curve parameters. The latter is
+//! what OpenSSL emits for `openssl ecparam -param_enc explicit` (and the
+//! same option of `openssl ec`). The [`SecretKey`] decoders from
+//! `elliptic-curve` only accept named curves, and reject such keys.
+//!
+//! The functions in this module accept both forms. Explicit parameters are
+//! checked to be exactly those of P-256: the field, curve coefficients, base
+//! point, order and (if present) cofactor must all match. The optional seed
+//! the curve was generated from is not checked.
+//!
+//! [RFC5915]: https://www.rfc-editor.org/rfc/rfc5915
+//! [RFC5480]: https://www.rfc-editor.org/rfc/rfc5480
+
+use crate::{
+    pkcs8::{
+        der::{
+            self,
+            asn1::{
+                AnyRef, BitStringRef, ContextSpecific, ObjectIdentifier, OctetStringRef, UintRef,
+            },
+            Decode, Reader, SliceReader, Tag, TagNumber, Tagged,
+        },
+        AssociatedOid, PrivateKeyInfoRef,
+    },
+    NistP256, SecretKey,
+};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, U256},
+    Error, Result,
+};
+
+/// The `prime-field` OID: 1.2.840.10045.1.1.
+const PRIME_FIELD: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.1.1");
+
+/// Coefficient `a` of the curve equation.
+const CURVE_A: U256 =
+    U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc");
+
+/// Coefficient `b` of the curve equation.
+const CURVE_B: U256 =
+    U256::from_be_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+
+/// Affine `x`-coordinate of the base point.
+const GENERATOR_X: U256 =
+    U256::from_be_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+
+/// Affine `y`-coordinate of the base point.
+const GENERATOR_Y: U256 =
+    U256::from_be_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
+/// Decode a SEC1 `ECPrivateKey` ([RFC5915]), accepting explicit P-256
+/// parameters as well as the `prime256v1` OID.
+///
+/// If the encoding includes the public key, it must match the secret key.
+///
+/// [RFC5915]: https://www.rfc-editor.org/rfc/rfc5915
+pub fn secret_key_from_sec1_der(der: &[u8]) -> Result<SecretKey> {
+    let mut reader = SliceReader::new(der).map_err(|_| Error)?;
+    let secret_key = reader.sequence(decode_ec_private_key).map_err(|_| Error)?;
+    reader.finish(secret_key).map_err(|_| Error)
+}
+
+/// Decode a PKCS#8 `PrivateKeyInfo` ([RFC5208]) holding a P-256 key,
+/// accepting explicit P-256 parameters as well as the `prime256v1` OID, both
+/// in the algorithm identifier and in the inner `ECPrivateKey`.
+///
+/// [RFC5208]: https://www.rfc-editor.org/rfc/rfc5208
+pub fn secret_key_from_pkcs8_der(der: &[u8]) -> Result<SecretKey> {
+    // Any attributes and public key which follow the private key are ignored,
+    // as they are by the `elliptic-curve` decoder
+    let private_key_info = PrivateKeyInfoRef::try_from(der)?;
+
+    let algorithm = private_key_info.algorithm;
+    algorithm
+        .assert_algorithm_oid(elliptic_curve::ALGORITHM_OID)
+        .map_err(|_| Error)?;
+    validate_parameters(algorithm.parameters.ok_or(Error)?).map_err(|_| Error)?;
+
+    secret_key_from_sec1_der(private_key_info.private_key.as_bytes())
+}
+
+/// Decode the body of an `ECPrivateKey` `SEQUENCE`.
+fn decode_ec_private_key<'a>(reader: &mut SliceReader<'a>) -> der::Result<SecretKey> {
+    if u8::decode(reader)? != 1 {
+        return Err(Tag::Integer.value_error());
+    }
+
+    let private_key = OctetStringRef::decode(reader)?.as_bytes();
+    if private_key.len() != 32 {
+        return Err(Tag::OctetString.length_error());
+    }
+    let secret_key =
+        SecretKey::from_slice(private_key).map_err(|_| Tag::OctetString.value_error())?;
+
+    if let Some(parameters) = ContextSpecific::<AnyRef<'a>>::decode_explicit(reader, TagNumber::N0)?
+    {
+        validate_parameters(parameters.value)?;
+    }
+
+    if let Some(public_key) =
+        ContextSpecific::<BitStringRef<'a>>::decode_explicit(reader, TagNumber::N1)?
+    {
+        // The bit string must have no unused bits
+        let sec1 = public_key
+            .value
+            .as_bytes()
+            .ok_or_else(|| Tag::BitString.value_error())?;
+        check_public_key(&secret_key, sec1)?;
+    }
+
+    Ok(secret_key)
+}
+
+/// Check that `ECParameters` identify P-256, either by name or explicitly.
+fn validate_parameters(parameters: AnyRef<'_>) -> der::Result<()> {
+    if parameters.tag() == Tag::ObjectIdentifier {
+        return if ObjectIdentifier::try_from(parameters)? == NistP256::OID {
+            Ok(())
+        } else {
+            Err(Tag::ObjectIdentifier.value_error())
+        };
+    }
+
+    parameters.sequence(|reader| {
+        if u8::decode(reader)? != 1 {
+            return Err(Tag::Integer.value_error());
+        }
+
+        reader.sequence(|field| {
+            if ObjectIdentifier::decode(field)? != PRIME_FIELD {
+                return Err(Tag::ObjectIdentifier.value_error());
+            }
+            expect_integer(UintRef::decode(field)?, &NistP256::FIELD_MODULUS)
+        })?;
+
+        reader.sequence(|curve| {
+            expect_field_element(OctetStringRef::decode(curve)?, &CURVE_A)?;
+            expect_field_element(OctetStringRef::decode(curve)?, &CURVE_B)?;
+
+            // The optional seed isn't checked
+            if !curve.is_finished() {
+                BitStringRef::decode(curve)?;
+            }
+            Ok(())
+        })?;
+
+        // The base point may be compressed or uncompressed. The generator's `y`
+        // is odd, so its compressed tag is `0x03`.
+        let base = OctetStringRef::decode(reader)?;
+        match base.as_bytes().split_first() {
+            Some((0x04, coordinates)) if coordinates.len() == 64 => {
+                expect_coordinate(&coordinates[..32], &GENERATOR_X)?;
+                expect_coordinate(&coordinates[32..], &GENERATOR_Y)?;
+            }
+            Some((0x03, x)) => expect_coordinate(x, &GENERATOR_X)?,
+            _ => return Err(Tag::OctetString.value_error()),
+        }
+
+        expect_integer(UintRef::decode(reader)?, &NistP256::ORDER)?;
+        if !reader.is_finished() {
+            expect_integer(UintRef::decode(reader)?, &U256::ONE)?;
+        }
+        Ok(())
+    })
+}
+
+/// Check that a DER `INTEGER` is the positive integer `expected`.
+///
+/// [`UintRef`] already rejects negative and non-minimal encodings.
+fn expect_integer(value: UintRef<'_>, expected: &U256) -> der::Result<()> {
+    let expected = expected.to_be_byte_array();
+    let start = expected.iter().position(|&b| b != 0).unwrap_or(31);
+
+    if value.as_bytes() == &expected[start..] {
+        Ok(())
+    } else {
+        Err(Tag::Integer.value_error())
+    }
+}
+
+/// Check that an `OCTET STRING` holds the fixed-width big endian encoding of
+/// the field element `expected`.
+fn expect_field_element(value: OctetStringRef<'_>, expected: &U256) -> der::Result<()> {
+    expect_coordinate(value.as_bytes(), expected)
+}
+
+/// Check that `bytes` is the fixed-width big endian encoding of the field
+/// element `expected`.
+fn expect_coordinate(bytes: &[u8], expected: &U256) -> der::Result<()> {
+    if bytes == expected.to_be_byte_array().as_slice() {
+        Ok(())
+    } else {
+        Err(Tag::OctetString.value_error())
+    }
+}
+
+/// Check the SEC1-encoded `publicKey` field of an `ECPrivateKey`.
+///
+/// Without the `arithmetic` feature, the public key can't be derived from the
+/// secret key, and is only checked to be a well-formed SEC1 encoding.
+fn check_public_key(secret_key: &SecretKey, sec1: &[u8]) -> der::Result<()> {
+    #[cfg(feature = "arithmetic")]
+    let valid = crate::PublicKey::from_sec1_bytes(sec1)
+        .is_ok_and(|public_key| public_key == secret_key.public_key());
+
+    #[cfg(not(feature = "arithmetic"))]
+    let valid = {
+        let _ = secret_key;
+        crate::EncodedPoint::from_bytes(sec1).is_ok()
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Tag::BitString.value_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{secret_key_from_pkcs8_der, secret_key_from_sec1_der};
+    use hex_literal::hex;
+
+    /// `openssl ec -param_enc explicit -outform DER` output for the key in
+    /// `tests/examples/pkcs8-private-key.der`.
+    const SEC1_EXPLICIT_DER: &[u8; 364] =
+        include_bytes!("../tests/examples/sec1-explicit-params.der");
+
+    /// The same key as PKCS#8, via `openssl pkcs8 -topk8 -nocrypt`.
+    const PKCS8_EXPLICIT_DER: &[u8; 381] =
+        include_bytes!("../tests/examples/pkcs8-explicit-params.der");
+
+    /// The same key with a named curve.
+    const PKCS8_NAMED_DER: &[u8; 138] = include_bytes!("../tests/examples/pkcs8-private-key.der");
+
+    const SECRET_SCALAR: [u8; 32] =
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+
+    #[test]
+    fn decode_explicit_params() {
+        let sec1 = secret_key_from_sec1_der(SEC1_EXPLICIT_DER).unwrap();
+        assert_eq!(sec1.to_bytes().as_slice(), &SECRET_SCALAR);
+
+        let pkcs8 = secret_key_from_pkcs8_der(PKCS8_EXPLICIT_DER).unwrap();
+        assert_eq!(pkcs8.to_bytes().as_slice(), &SECRET_SCALAR);
+
+        // Keys with a named curve are accepted as well
+        let named = secret_key_from_pkcs8_der(PKCS8_NAMED_DER).unwrap();
+        assert_eq!(named.to_bytes().as_slice(), &SECRET_SCALAR);
+    }
+
+    #[test]
+    fn reject_other_parameters() {
+        // Offset of the last byte of the curve coefficient `b`
+        const B_LAST: usize = 165;
+        assert_eq!(SEC1_EXPLICIT_DER[B_LAST], 0x4b);
+
+        let mut wrong_b = *SEC1_EXPLICIT_DER;
+        wrong_b[B_LAST] ^= 1;
+        assert!(secret_key_from_sec1_der(&wrong_b).is_err());
+
+        // Offset of the last byte of the base point's `y`-coordinate
+        const GY_LAST: usize = 255;
+        assert_eq!(SEC1_EXPLICIT_DER[GY_LAST], 0xf5);
+
+        let mut wrong_generator = *SEC1_EXPLICIT_DER;
+        wrong_generator[GY_LAST] ^= 1;
+        assert!(secret_key_from_sec1_der(&wrong_generator).is_err());
+
+        // Truncated input and trailing data
+        let len = SEC1_EXPLICIT_DER.len();
+        assert!(secret_key_from_sec1_der(&SEC1_EXPLICIT_DER[..len - 1]).is_err());
+        let mut trailing = [0u8; 365];
+        trailing[..len].copy_from_slice(SEC1_EXPLICIT_DER);
+        assert!(secret_key_from_sec1_der(&trailing).is_err());
+    }
+
+    #[cfg(feature = "arithmetic")]
+    #[test]
+    fn reject_mismatched_public_key() {
+        // The public key comes last: flip a bit of its `y`-coordinate
+        let mut wrong_public_key = *SEC1_EXPLICIT_DER;
+        let last = wrong_public_key.len() - 1;
+        wrong_public_key[last] ^= 1;
+        assert!(secret_key_from_sec1_der(&wrong_public_key).is_err());
+    }
+}
@@ -35,6 +35,9 @@ pub mod ecdh;
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
+#[cfg(feature = "pkcs8")]
+pub mod explicit_params;
+
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
@@ -71,6 +74,13 @@ use elliptic_curve::{array::Array, bigint::ArrayEncoding, consts::U33, FieldByte
 /// ```
 const ORDER_HEX: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
 
+/// Modulus of NIST P-256's base field serialized as hexadecimal.
+///
+/// ```text
+/// p = FFFFFFFF 00000001 00000000 00000000 00000000 FFFFFFFF FFFFFFFF FFFFFFFF
+/// ```
+const FIELD_MODULUS_HEX: &str = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+
 /// NIST P-256 elliptic curve.
 ///
 /// This curve is also known as prime256v1 (ANSI X9.62) and secp256r1 (SECG)
@@ -95,6 +105,17 @@ const ORDER_HEX: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct NistP256;
 
+impl NistP256 {
+    /// Modulus of the base field: `p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1`.
+    pub const FIELD_MODULUS: U256 = U256::from_be_hex(FIELD_MODULUS_HEX);
+
+    /// Order of the elliptic curve group (i.e. scalar modulus).
+    ///
+    /// This is the same value as [`elliptic_curve::Curve::ORDER`], provided so
+    /// it can be used without importing the trait.
+    pub const ORDER: U256 = U256::from_be_hex(ORDER_HEX);
+}
+
 impl elliptic_curve::Curve for NistP256 {
     /// 32-byte serialized field elements.
     type FieldBytesSize = U32;
@@ -138,6 +159,13 @@ pub type CompressedPoint = Array<u8, U33>;
 /// NIST P-256 SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP256>;
 
+/// Coordinates of a NIST P-256 SEC1 encoded point, as returned by
+/// [`EncodedPoint::coordinates`].
+///
+/// These are obtained by parsing the encoding only: the coordinates are not
+/// checked to be a point on the curve.
+pub type Coordinates<'a> = elliptic_curve::sec1::Coordinates<'a, U32>;
+
 /// NIST P-256 field element serialized as bytes.
 ///
 /// Byte array containing a serialized field element value (base field or scalar).
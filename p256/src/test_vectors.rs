@@ -1,5 +1,6 @@
 //! secp256r1 test vectors.
 
+pub mod ecdh;
 #[cfg(test)]
 pub mod ecdsa;
 pub mod field;
@@ -0,0 +1,44 @@
+//! ECDH/secp256r1 test vectors
+
+use hex_literal::hex;
+
+/// ECDH test vector: a secret scalar, the peer's public key, and the
+/// resulting shared secret (the x-coordinate of the shared point).
+pub struct EcdhTestVector {
+    /// Secret scalar (big endian)
+    pub d: [u8; 32],
+    /// x-coordinate of the peer's public key
+    pub q_x: [u8; 32],
+    /// y-coordinate of the peer's public key
+    pub q_y: [u8; 32],
+    /// Shared secret
+    pub z: [u8; 32],
+}
+
+/// ECDH/P-256 test vectors.
+///
+/// The first vectors (`COUNT = 0` to `2`) for P-256 from `KAS_ECC_CDH_PrimitiveTest.txt`
+/// in the NIST CAVP ECC CDH primitive test vectors (`ecccdhtestvectors.zip`)
+/// <https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/component-testing>
+///
+/// `d` is `dIUT`, `q_x`/`q_y` are `QCAVSx`/`QCAVSy`, and `z` is `ZIUT`.
+pub const ECDH_TEST_VECTORS: &[EcdhTestVector] = &[
+    EcdhTestVector {
+        d: hex!("7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534"),
+        q_x: hex!("700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287"),
+        q_y: hex!("db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac"),
+        z: hex!("46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b"),
+    },
+    EcdhTestVector {
+        d: hex!("38f65d6dce47676044d58ce5139582d568f64bb16098d179dbab07741dd5caf5"),
+        q_x: hex!("809f04289c64348c01515eb03d5ce7ac1a8cb9498f5caa50197e58d43a86a7ae"),
+        q_y: hex!("b29d84e811197f25eba8f5194092cb6ff440e26d4421011372461f579271cda3"),
+        z: hex!("057d636096cb80b67a8c038c890e887d1adfa4195e9b3ce241c8a778c59cda67"),
+    },
+    EcdhTestVector {
+        d: hex!("1accfaf1b97712b85a6f54b148985a1bdc4c9bec0bd258cad4b3d603f49f32c8"),
+        q_x: hex!("a2339c12d4a03c33546de533268b4ad667debf458b464d77443636440ee7fec3"),
+        q_y: hex!("ef48a3ab26e20220bcda2c1851076839dae88eae962869a497bf73cb66faf536"),
+        z: hex!("2d457b78b4614132477618a5b077965ec90730a8c81a1c75d6d4ec68005d67ec"),
+    },
+];
@@ -4,10 +4,12 @@
 
 use elliptic_curve::{
     group::{prime::PrimeCurveAffine, GroupEncoding},
+    point::{AffineCoordinates, DecompressPoint},
     sec1::{FromEncodedPoint, ToCompactEncodedPoint, ToEncodedPoint},
+    subtle::Choice,
 };
 use hex_literal::hex;
-use p256::{AffinePoint, EncodedPoint};
+use p256::{AffinePoint, Coordinates, EncodedPoint};
 
 const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
     "04 6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296
@@ -134,3 +136,50 @@ fn noncompatible_is_none() {
         .unwrap_u8();
     assert_eq!(is_compactable, 0);
 }
+
+#[test]
+fn decompress_selects_root_by_parity() {
+    let x = AffinePoint::generator().x();
+
+    let even = AffinePoint::decompress(&x, Choice::from(0)).unwrap();
+    let odd = AffinePoint::decompress(&x, Choice::from(1)).unwrap();
+
+    assert!(!bool::from(even.y_is_odd()));
+    assert!(bool::from(odd.y_is_odd()));
+    assert_eq!(even, -odd);
+
+    // The P-256 generator's y-coordinate is odd (compressed tag `03`)
+    assert_eq!(odd, AffinePoint::generator());
+}
+
+#[test]
+fn encoded_point_coordinates() {
+    assert!(matches!(
+        EncodedPoint::identity().coordinates(),
+        Coordinates::Identity
+    ));
+
+    let compressed = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    match compressed.coordinates() {
+        Coordinates::Compressed { x, y_is_odd } => {
+            assert_eq!(&x[..], &COMPRESSED_BASEPOINT[1..]);
+            assert!(y_is_odd);
+        }
+        other => panic!("unexpected coordinates: {:?}", other),
+    }
+
+    let uncompressed = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    match uncompressed.coordinates() {
+        Coordinates::Uncompressed { x, y } => {
+            assert_eq!(&x[..], &UNCOMPRESSED_BASEPOINT[1..33]);
+            assert_eq!(&y[..], &UNCOMPRESSED_BASEPOINT[33..]);
+        }
+        other => panic!("unexpected coordinates: {:?}", other),
+    }
+
+    let compact = EncodedPoint::from_bytes(COMPACT_BASEPOINT).unwrap();
+    match compact.coordinates() {
+        Coordinates::Compact { x } => assert_eq!(&x[..], &COMPACT_BASEPOINT[1..]),
+        other => panic!("unexpected coordinates: {:?}", other),
+    }
+}
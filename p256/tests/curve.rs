@@ -0,0 +1,41 @@
+//! Curve parameter tests.
+
+use p256::{NistP256, U256};
+
+#[test]
+fn curve_constants() {
+    assert_eq!(
+        NistP256::ORDER,
+        U256::from_be_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551")
+    );
+    assert_eq!(NistP256::ORDER, <NistP256 as elliptic_curve::Curve>::ORDER);
+    assert_eq!(
+        NistP256::FIELD_MODULUS,
+        U256::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF")
+    );
+}
+
+/// Exercise the group through generic `PrimeCurveArithmetic` bounds only.
+fn prime_curve_arithmetic<C: elliptic_curve::PrimeCurveArithmetic>() {
+    use elliptic_curve::{
+        group::{
+            prime::{PrimeCurve, PrimeCurveAffine},
+            Curve, Group,
+        },
+        Field,
+    };
+
+    let g = C::CurveGroup::generator();
+    assert_eq!(
+        g.to_affine(),
+        <C::CurveGroup as PrimeCurve>::Affine::generator()
+    );
+    assert_eq!(g * -C::Scalar::ONE + g, C::CurveGroup::identity());
+    assert_eq!((g + g).to_affine(), g.double().to_affine());
+    assert!(bool::from((g * C::Scalar::ZERO).is_identity()));
+}
+
+#[test]
+fn nistp256_prime_curve_arithmetic() {
+    prime_curve_arithmetic::<NistP256>();
+}
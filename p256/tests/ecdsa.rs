@@ -5,7 +5,7 @@
 use elliptic_curve::ops::Reduce;
 use p256::{
     ecdsa::{SigningKey, VerifyingKey},
-    NonZeroScalar, U256,
+    NonZeroScalar, ProjectivePoint, U256,
 };
 use proptest::prelude::*;
 
@@ -23,4 +23,14 @@ proptest! {
         let recovered_vk = VerifyingKey::recover_from_msg(msg, &signature, v).unwrap();
         prop_assert_eq!(sk.verifying_key(), &recovered_vk);
     }
+
+    #[test]
+    fn verifying_key_from_signing_key(sk in signing_key()) {
+        let from_ref = VerifyingKey::from(&sk);
+        prop_assert_eq!(sk.verifying_key(), &from_ref);
+        prop_assert_eq!(
+            from_ref.as_affine(),
+            &(ProjectivePoint::GENERATOR * *sk.as_nonzero_scalar()).to_affine()
+        );
+    }
 }
@@ -5,12 +5,14 @@
 use elliptic_curve::{
     group::{ff::PrimeField, GroupEncoding},
     sec1::{self, ToEncodedPoint},
+    Field,
 };
 use p256::{
     test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
     AffinePoint, ProjectivePoint, Scalar,
 };
 use primeorder::{impl_projective_arithmetic_tests, Double};
+use rand_core::OsRng;
 
 impl_projective_arithmetic_tests!(
     AffinePoint,
@@ -25,3 +27,40 @@ fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
     assert_eq!([0; 33], ProjectivePoint::IDENTITY.to_bytes().as_slice());
 }
+
+#[test]
+fn projective_group_encoding_round_trip() {
+    let identity = ProjectivePoint::IDENTITY.to_bytes();
+    assert_eq!(
+        ProjectivePoint::from_bytes(&identity).unwrap(),
+        ProjectivePoint::IDENTITY
+    );
+
+    for _ in 0..100 {
+        let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+        let bytes = point.to_bytes();
+        assert!(bytes[0] == 0x02 || bytes[0] == 0x03);
+        assert_eq!(ProjectivePoint::from_bytes(&bytes).unwrap(), point);
+        assert_eq!(
+            ProjectivePoint::from_bytes_unchecked(&bytes).unwrap(),
+            point
+        );
+        assert_eq!((-point).to_bytes()[1..], bytes[1..]);
+    }
+}
+
+#[test]
+fn projective_group_encoding_rejects_invalid() {
+    // x = 1 has no corresponding y on P-256
+    let mut bytes = [0u8; 33];
+    bytes[0] = 0x02;
+    bytes[32] = 0x01;
+    assert!(bool::from(
+        ProjectivePoint::from_bytes(&bytes.into()).is_none()
+    ));
+
+    // Invalid tag
+    let mut bytes = ProjectivePoint::GENERATOR.to_bytes();
+    bytes[0] = 0x06;
+    assert!(bool::from(ProjectivePoint::from_bytes(&bytes).is_none()));
+}
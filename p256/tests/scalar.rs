@@ -2,8 +2,12 @@
 
 #![cfg(feature = "arithmetic")]
 
-use elliptic_curve::ops::{Invert, Reduce};
-use p256::{Scalar, U256};
+use elliptic_curve::{
+    ops::{Invert, Reduce},
+    scalar::IsHigh,
+    Curve, Field,
+};
+use p256::{NistP256, NonZeroScalar, Scalar, U256};
 use proptest::prelude::*;
 
 prop_compose! {
@@ -12,7 +16,110 @@ prop_compose! {
     }
 }
 
+/// `n - 2`, the Fermat inversion exponent.
+const N_MINUS_2: [u64; 4] = [
+    0xf3b9_cac2_fc63_254f,
+    0xbce6_faad_a717_9e84,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_0000_0000,
+];
+
+#[test]
+fn invert_edge_cases() {
+    assert_eq!(Scalar::ONE.invert().unwrap(), Scalar::ONE);
+    assert_eq!((-Scalar::ONE).invert().unwrap(), -Scalar::ONE);
+    assert!(bool::from(Scalar::ZERO.invert().is_none()));
+}
+
+#[test]
+fn non_zero_scalar_invert() {
+    let r = NonZeroScalar::random(&mut rand_core::OsRng);
+    let inv: NonZeroScalar = r.invert();
+    let inv_vartime: NonZeroScalar = r.invert_vartime();
+
+    assert_eq!(*inv * *r, Scalar::ONE);
+    assert_eq!(*inv_vartime, *inv);
+}
+
+#[test]
+fn ct_comparison_boundaries() {
+    let order = NistP256::ORDER;
+    let half = order.shr_vartime(1);
+    let order_minus_one = order.wrapping_sub(&U256::ONE);
+
+    assert!(bool::from(Scalar::ct_lt_order(&U256::ZERO)));
+    assert!(bool::from(Scalar::ct_lt_order(&half)));
+    assert!(bool::from(Scalar::ct_lt_order(&order_minus_one)));
+    assert!(!bool::from(Scalar::ct_lt_order(&order)));
+    assert!(!bool::from(Scalar::ct_lt_order(&U256::MAX)));
+
+    let scalar = |w: U256| <Scalar as Reduce<U256>>::reduce(w);
+    assert!(!bool::from(Scalar::ZERO.ct_is_high()));
+    assert!(!bool::from(scalar(half).ct_is_high()));
+    assert!(bool::from(
+        scalar(half.wrapping_add(&U256::ONE)).ct_is_high()
+    ));
+    assert!(bool::from(scalar(order_minus_one).ct_is_high()));
+    assert!(bool::from(scalar(order_minus_one).is_high()));
+}
+
+#[test]
+fn ct_eq() {
+    use elliptic_curve::subtle::ConstantTimeEq;
+
+    let x = Scalar::random(&mut rand_core::OsRng);
+    let y = x + Scalar::ONE;
+    assert!(bool::from(x.ct_eq(&x)));
+    assert!(!bool::from(x.ct_eq(&y)));
+    assert!(bool::from((y - Scalar::ONE).ct_eq(&x)));
+
+    let x = NonZeroScalar::random(&mut rand_core::OsRng);
+    let y = -x;
+    assert!(bool::from(x.ct_eq(&x)));
+    assert!(!bool::from(x.ct_eq(&y)));
+}
+
+#[test]
+fn mul_operator_forms() {
+    let a = Scalar::random(&mut rand_core::OsRng);
+    let b = Scalar::random(&mut rand_core::OsRng);
+    let expected = Scalar::multiply(&a, &b);
+
+    assert_eq!(a * b, expected);
+    assert_eq!(a * &b, expected);
+    assert_eq!(&a * b, expected);
+    assert_eq!(&a * &b, expected);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, expected);
+
+    let mut c = a;
+    c *= &b;
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn double_and_triple() {
+    let one = Scalar::ONE;
+    assert_eq!(one.double(), one + one);
+    assert_eq!(one.triple(), one.double() + one);
+    assert_eq!((-one).triple(), -Scalar::from(3u64));
+}
+
 proptest! {
+    #[test]
+    fn double_and_triple_match_addition(w in scalar()) {
+        prop_assert_eq!(w.double(), w + w);
+        prop_assert_eq!(w.triple(), w + w + w);
+    }
+
+    #[test]
+    fn invert_matches_fermat(w in scalar()) {
+        prop_assume!(!bool::from(w.is_zero()));
+        prop_assert_eq!(w.invert().unwrap(), w.pow_vartime(&N_MINUS_2));
+    }
+
     #[test]
     fn invert_and_invert_vartime_are_equivalent(w in scalar()) {
         let inv: Option<Scalar> = w.invert().into();
@@ -20,3 +127,88 @@ proptest! {
         prop_assert_eq!(inv, inv_vartime);
     }
 }
+
+#[test]
+fn reduce_from_field_bytes() {
+    use elliptic_curve::bigint::ArrayEncoding;
+
+    // The P-256 order plus one reduces to 1, while secp256k1 (whose order is
+    // larger) would leave it unchanged
+    let order_plus_one = NistP256::ORDER.wrapping_add(&U256::ONE);
+    assert_eq!(
+        Scalar::reduce_from_field_bytes(&order_plus_one.to_be_byte_array()),
+        Scalar::ONE
+    );
+
+    // The secp256k1 order `n'` reduces to `n' - n`
+    let k256_order =
+        U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+    assert_eq!(
+        Scalar::reduce_from_field_bytes(&k256_order.to_be_byte_array()).to_bytes(),
+        k256_order.wrapping_sub(&NistP256::ORDER).to_be_byte_array()
+    );
+}
+
+#[test]
+fn from_be_bytes_mod_order() {
+    use elliptic_curve::bigint::ArrayEncoding;
+
+    let order_plus_one = NistP256::ORDER.wrapping_add(&U256::ONE).to_be_byte_array();
+    for bytes in [[0u8; 32].into(), [0xffu8; 32].into(), order_plus_one] {
+        assert_eq!(
+            Scalar::from_be_bytes_mod_order(&bytes),
+            Scalar::reduce_from_field_bytes(&bytes)
+        );
+    }
+}
+
+#[test]
+fn to_be_array() {
+    use elliptic_curve::ff::PrimeField;
+
+    let scalar = Scalar::random(&mut rand_core::OsRng);
+    let array = scalar.to_be_array();
+    let bytes = scalar.to_bytes();
+    let slice: &[u8] = bytes.as_ref();
+
+    assert_eq!(slice.len(), 32);
+    assert_eq!(array, slice);
+    assert_eq!(Scalar::from_repr(array.into()).unwrap(), scalar);
+}
+
+#[test]
+fn reduce_boundary_vectors() {
+    use elliptic_curve::bigint::ArrayEncoding;
+
+    // (big endian input, expected value mod n). `2n - 1` doesn't fit in 256
+    // bits, so the largest input is `2^256 - 1`.
+    let vectors = [
+        (
+            "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550",
+            "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550",
+        ),
+        (
+            "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632552",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "00000000FFFFFFFF00000000000000004319055258E8617B0C46353D039CDAAE",
+        ),
+    ];
+
+    for (input, expected) in vectors {
+        let bytes = U256::from_be_hex(input).to_be_byte_array();
+        let expected = U256::from_be_hex(expected).to_be_byte_array();
+
+        assert_eq!(Scalar::reduce_from_field_bytes(&bytes).to_bytes(), expected);
+        assert_eq!(
+            <Scalar as Reduce<U256>>::reduce_bytes(&bytes).to_bytes(),
+            expected
+        );
+    }
+}
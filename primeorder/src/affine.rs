@@ -137,6 +137,8 @@ where
             let beta = alpha.sqrt();
 
             beta.map(|beta| {
+                // Select the root with the requested parity without branching, so
+                // decompression is constant-time with respect to `y_is_odd`.
                 let y = C::FieldElement::conditional_select(
                     &-beta,
                     &beta,
@@ -152,3 +152,159 @@ macro_rules! impl_projective_arithmetic_tests {
         }
     };
 }
+
+/// Implement tests which feed malformed DER to an ECDSA signature parser.
+///
+/// `$valid_der` is a well-formed DER signature for the curve which the
+/// truncation tests are derived from. The oversized `INTEGER` test assumes
+/// 32-byte scalars.
+#[macro_export]
+macro_rules! impl_der_signature_tests {
+    ($signature:ty, $der_signature:ty, $valid_der:expr) => {
+        /// Parse untrusted DER input both as a fixed-size and a DER signature.
+        fn parses(bytes: &[u8]) -> bool {
+            let parsed = <$signature>::from_der(bytes).is_ok();
+            assert_eq!(parsed, <$der_signature>::try_from(bytes).is_ok());
+            parsed
+        }
+
+        #[test]
+        fn reject_truncated() {
+            let der: &[u8] = &$valid_der;
+            assert!(parses(der));
+
+            for len in 0..der.len() {
+                assert!(!parses(&der[..len]));
+            }
+
+            // Trailing data after the `SEQUENCE`
+            let mut extended = [0u8; 80];
+            extended[..der.len()].copy_from_slice(der);
+            assert!(!parses(&extended[..der.len() + 1]));
+
+            // Claimed `SEQUENCE` length longer than the input
+            extended[1] += 1;
+            assert!(!parses(&extended[..der.len()]));
+        }
+
+        #[test]
+        fn reject_malformed_lengths() {
+            // r = 1, s = 1
+            assert!(parses(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01]));
+
+            // `SEQUENCE` length claims 4 GiB
+            assert!(!parses(&[
+                0x30, 0x84, 0xff, 0xff, 0xff, 0xff, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01
+            ]));
+
+            // Indefinite length, which DER forbids
+            assert!(!parses(&[
+                0x30, 0x80, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00, 0x00
+            ]));
+
+            // Non-minimal long form encoding of a short length
+            assert!(!parses(&[
+                0x30, 0x81, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01
+            ]));
+
+            // `INTEGER` length runs past the end of the `SEQUENCE`
+            assert!(!parses(&[0x30, 0x06, 0x02, 0x7f, 0x01, 0x02, 0x01, 0x01]));
+            assert!(!parses(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x05, 0x01]));
+
+            // `INTEGER` longer than a scalar
+            let mut oversized = [0u8; 40];
+            oversized[..5].copy_from_slice(&[0x30, 0x26, 0x02, 0x21, 0x01]);
+            oversized[37..].copy_from_slice(&[0x02, 0x01, 0x01]);
+            assert!(!parses(&oversized));
+        }
+
+        proptest::proptest! {
+            #[test]
+            fn fuzzy_der_headers(
+                seq_len in proptest::prelude::any::<u8>(),
+                int_len in proptest::prelude::any::<u8>(),
+                body in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..80)
+            ) {
+                // Arbitrary input must be rejected (or accepted) without panicking
+                let mut input = [0u8; 84];
+                input[..4].copy_from_slice(&[0x30, seq_len, 0x02, int_len]);
+                input[4..4 + body.len()].copy_from_slice(&body);
+
+                for len in 0..=4 + body.len() {
+                    parses(&input[..len]);
+                }
+            }
+        }
+    };
+}
+
+/// Implement tests for ECDSA signing with an RNG through `RandomizedSigner`.
+///
+/// Also defines a `sign_seeded` helper, producing a reproducible randomized
+/// signature for a given RNG seed, for curve-specific tests to build on.
+#[macro_export]
+macro_rules! impl_randomized_signer_tests {
+    ($signing_key:ty, $signature:ty) => {
+        /// Deterministic splitmix64 generator, so that failures are reproducible.
+        /// It is *not* a secure RNG, which only matters outside of tests.
+        struct SeededRng(u64);
+
+        impl rand_core::RngCore for SeededRng {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+                let mut z = self.0;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                z ^ (z >> 31)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        impl rand_core::CryptoRng for SeededRng {}
+
+        /// Sign `msg` through a generic `RandomizedSigner` bound, as downstream code
+        /// would: the trait is generic over the RNG and so isn't object safe.
+        fn sign_seeded<S>(signer: &S, seed: u64, msg: &[u8]) -> $signature
+        where
+            S: signature::RandomizedSigner<$signature>,
+        {
+            signer.sign_with_rng(&mut SeededRng(seed), msg)
+        }
+
+        #[test]
+        fn randomized_signer() {
+            use signature::{Signer, Verifier};
+
+            let signing_key = <$signing_key>::from_slice(&[0x42; 32]).unwrap();
+            let verifying_key = signing_key.verifying_key();
+            let msg = b"signing with an RNG through the trait";
+
+            // The same seed reproduces the same signature, and it differs from
+            // the purely deterministic RFC6979 one
+            let sig = sign_seeded(&signing_key, 1, msg);
+            assert_eq!(sign_seeded(&signing_key, 1, msg), sig);
+            assert_ne!(sig, Signer::<$signature>::sign(&signing_key, msg));
+
+            for seed in 2..10 {
+                let other = sign_seeded(&signing_key, seed, msg);
+                assert_ne!(other, sig);
+                assert!(verifying_key.verify(msg, &other).is_ok());
+            }
+
+            assert!(verifying_key.verify(msg, &sig).is_ok());
+            assert!(verifying_key.verify(b"another message", &sig).is_err());
+        }
+    };
+}
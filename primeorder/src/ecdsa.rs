@@ -0,0 +1,57 @@
+//! ECDSA support macros shared by the curve crates.
+
+/// Implement an incremental ECDSA signer for messages which are too large to
+/// buffer, as `StreamingSigner`, along with the `NewSigner` extension trait
+/// providing `SigningKey::new_signer`.
+///
+/// Must be invoked in a module where `signature` is the `signature` crate with
+/// its `digest` feature enabled, and `$signing_key` signs `$signature`s over
+/// `$digest` with RFC6979 deterministic nonces.
+#[macro_export]
+macro_rules! impl_streaming_signer {
+    ($signing_key:ty, $signature:ty, $digest:ty, $digest_name:expr) => {
+        /// Incremental ECDSA signer for messages which are too large to buffer.
+        ///
+        /// Message data is fed in chunks with [`StreamingSigner::update`] and hashed
+        #[doc = concat!("with ", $digest_name, ", then signed using RFC6979 deterministic nonces by")]
+        /// [`StreamingSigner::finalize_sign`]. The resulting signature is identical to
+        /// the one produced by [`signature::Signer::sign`] over the concatenated input.
+        #[derive(Clone)]
+        pub struct StreamingSigner<'a> {
+            signing_key: &'a $signing_key,
+            digest: $digest,
+        }
+
+        impl<'a> StreamingSigner<'a> {
+            /// Create a new streaming signer which signs with the given key.
+            pub fn new(signing_key: &'a $signing_key) -> Self {
+                Self {
+                    signing_key,
+                    digest: <$digest as signature::digest::Digest>::new(),
+                }
+            }
+
+            /// Feed a chunk of message data to the signer.
+            pub fn update(&mut self, data: &[u8]) {
+                signature::digest::Digest::update(&mut self.digest, data);
+            }
+
+            /// Sign the message data fed so far.
+            pub fn finalize_sign(self) -> $signature {
+                signature::DigestSigner::sign_digest(self.signing_key, self.digest)
+            }
+        }
+
+        /// Start a [`StreamingSigner`] from a signing key.
+        pub trait NewSigner {
+            /// Create a [`StreamingSigner`] which signs with this key.
+            fn new_signer(&self) -> StreamingSigner<'_>;
+        }
+
+        impl NewSigner for $signing_key {
+            fn new_signer(&self) -> StreamingSigner<'_> {
+                StreamingSigner::new(self)
+            }
+        }
+    };
+}
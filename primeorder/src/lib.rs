@@ -17,6 +17,7 @@ pub mod point_arithmetic;
 mod affine;
 #[cfg(feature = "dev")]
 mod dev;
+mod ecdsa;
 mod field;
 mod projective;
 
@@ -24,8 +25,9 @@ pub use crate::{affine::AffinePoint, projective::ProjectivePoint};
 pub use elliptic_curve::{self, array, point::Double, Field, FieldBytes, PrimeCurve, PrimeField};
 
 use elliptic_curve::ops::Invert;
+use elliptic_curve::sec1::{EncodedPoint, FromEncodedPoint, ModulusSize};
 use elliptic_curve::subtle::CtOption;
-use elliptic_curve::CurveArithmetic;
+use elliptic_curve::{CurveArithmetic, FieldBytesSize};
 
 /// Parameters for elliptic curves of prime order which can be described by the
 /// short Weierstrass equation.
@@ -51,3 +53,22 @@ pub trait PrimeCurveParams:
     /// Generator point's affine coordinates: (x, y).
     const GENERATOR: (Self::FieldElement, Self::FieldElement);
 }
+
+/// Decode a SEC1-encoded public key, checking that it is a point on the curve
+/// other than the identity.
+///
+/// On a curve of prime order every such point generates the whole group, so no
+/// separate subgroup check is needed.
+pub fn validate_public_key<C>(bytes: &[u8]) -> elliptic_curve::Result<C::AffinePoint>
+where
+    C: PrimeCurve + CurveArithmetic,
+    C::AffinePoint: FromEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let encoded = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| elliptic_curve::Error)?;
+    if encoded.is_identity() {
+        return Err(elliptic_curve::Error);
+    }
+
+    Option::from(C::AffinePoint::from_encoded_point(&encoded)).ok_or(elliptic_curve::Error)
+}